@@ -92,6 +92,7 @@ pub mod addressbook_proto {
             ::std::boxed::Box<self::super::google_protobuf_timestamp_proto::Timestamp>,
         >,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::Person {
         fn merge_from(
@@ -132,6 +133,7 @@ pub mod addressbook_proto {
                 size += ::protrust::io::sizes::message(last_updated);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -170,6 +172,9 @@ pub mod addressbook_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::Person {
         fn merge(&mut self, other: &Self) {
@@ -189,6 +194,14 @@ pub mod addressbook_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::Person {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::Person {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -300,6 +313,7 @@ pub mod addressbook_proto {
             number: ::std::string::String,
             r#type: ::protrust::EnumValue<self::PhoneType>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::PhoneNumber {
             fn merge_from(
@@ -326,6 +340,7 @@ pub mod addressbook_proto {
                     size += ::protrust::io::sizes::enum_value(*self.r#type());
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -346,6 +361,9 @@ pub mod addressbook_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::PhoneNumber {
             fn merge(&mut self, other: &Self) {
@@ -358,6 +376,14 @@ pub mod addressbook_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::PhoneNumber {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::PhoneNumber {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::Person as ::protrust::Message>::descriptor().messages()[0]
@@ -449,6 +475,7 @@ pub mod addressbook_proto {
     pub struct AddressBook {
         people: ::protrust::collections::RepeatedField<self::Person>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::AddressBook {
         fn merge_from(
@@ -471,6 +498,7 @@ pub mod addressbook_proto {
                 .people()
                 .calculate_size(&self::address_book::PEOPLE_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -488,6 +516,9 @@ pub mod addressbook_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::AddressBook {
         fn merge(&mut self, other: &Self) {
@@ -495,6 +526,14 @@ pub mod addressbook_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::AddressBook {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::AddressBook {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -0,0 +1,26 @@
+//! Prints arbitrary protobuf bytes without a schema, like `protoc --decode_raw`.
+//!
+//! Reads the file given as the only argument, or stdin if no file is given.
+//! Like protoc, length delimited values that can be read as messages are printed as messages, even if they're also text.
+
+use protrust::text_format;
+use std::error::Error;
+use std::io::{self, Read, Write};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args_os().skip(1);
+    let data =
+        match (args.next(), args.next()) {
+            (None, _) => {
+                let mut data = Vec::new();
+                io::stdin().read_to_end(&mut data)?;
+                data
+            }
+            (Some(path), None) => std::fs::read(path)?,
+            (Some(_), Some(_)) => return Err("usage: decode-raw [FILE]".into()),
+        };
+
+    let output = text_format::decode_raw(&data)?;
+    io::stdout().write_all(output.as_bytes())?;
+    Ok(())
+}
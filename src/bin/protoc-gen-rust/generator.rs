@@ -595,6 +595,7 @@ impl<'a, 'b> SourceGenerator<'a, MessageDescriptor<'b>> {
                 #(#fields,
                 )*
                 unknown_fields: #c::UnknownFieldSet,
+                cached_size: #c::CachedSize,
                 #extensions
             }
 
@@ -625,6 +626,9 @@ impl<'a, 'b> SourceGenerator<'a, MessageDescriptor<'b>> {
                 #calculator
                 #writer
                 #initialized
+                fn cached_size(&self) -> ::std::option::Option<i32> {
+                    ::std::option::Option::Some(self.cached_size.get())
+                }
             }
         })
     }
@@ -794,6 +798,7 @@ impl<'a, 'b> SourceGenerator<'a, MessageDescriptor<'b>> {
                     )*
                     #extensions
                     size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                    self.cached_size.set(size);
                     ::std::option::Option::Some(size)
                 }
             })
@@ -805,6 +810,7 @@ impl<'a, 'b> SourceGenerator<'a, MessageDescriptor<'b>> {
                     )*
                     #extensions
                     size += self.unknown_fields.calculate_size();
+                    self.cached_size.set(size);
                     size
                 }
             })
@@ -862,6 +868,14 @@ impl<'a, 'b> SourceGenerator<'a, MessageDescriptor<'b>> {
             impl #c::LiteMessage for #t {
                 #merge
             }
+            impl #c::UnknownFields for #t {
+                fn unknown_fields(&self) -> &#c::UnknownFieldSet {
+                    &self.unknown_fields
+                }
+                fn unknown_fields_mut(&mut self) -> &mut #c::UnknownFieldSet {
+                    &mut self.unknown_fields
+                }
+            }
         })
     }
 
@@ -53,11 +53,10 @@ impl<T: Clone> RepeatedField<T> {
         if let Some(tag) = input.last_tag() {
             if tag.wire_type() == WireType::LengthDelimited && codec.packable() {
                 let new_limit = input.read_length()?;
-                let old = input.push_limit(new_limit);
-                while !input.reached_limit() {
-                    self.push(codec.read_from(input)?);
-                }
+                let old = input.push_limit(new_limit)?;
+                let result = self.add_packed_entries(input, codec);
                 input.pop_limit(old);
+                result?;
             } else {
                 self.push(codec.read_from(input)?);
             }
@@ -65,6 +64,12 @@ impl<T: Clone> RepeatedField<T> {
 
         Ok(())
     }
+    fn add_packed_entries(&mut self, input: &mut CodedInput, codec: &Codec<T>) -> InputResult<()> {
+        while !input.reached_limit() {
+            self.push(codec.read_from(input)?);
+        }
+        Ok(())
+    }
     #[cfg(checked_size)]
     pub fn calculate_size(&self, codec: &Codec<T>) -> Option<i32> {
         if self.len() == 0 {
@@ -89,7 +94,7 @@ impl<T: Clone> RepeatedField<T> {
                 .len()
                 .checked_mul(s as usize)
                 .and_then(|m| m.try_into().ok()),
-            ValueSize::Func(fun) => self
+            ValueSize::Func(fun) | ValueSize::Message(fun, _) => self
                 .iter()
                 .fold(0, |last, value| last.checked_add(fun(value)?)?),
         }
@@ -115,7 +120,7 @@ impl<T: Clone> RepeatedField<T> {
     fn calculate_packed_size(&self, codec: &Codec<T>) -> i32 {
         match codec.size {
             ValueSize::Fixed(s) => (self.len() * s as usize) as i32,
-            ValueSize::Func(fun) => self.iter().fold(0, |last, value| last + fun(value)),
+            ValueSize::Func(fun) | ValueSize::Message(fun, _) => self.iter().fold(0, |last, value| last + fun(value)),
         }
     }
 
@@ -229,7 +234,9 @@ impl<
     ) -> InputResult<()> {
         let mut adapter = MapReadAdapter::new(codec);
         input.read_message(&mut adapter)?;
-        self.insert(adapter.key.unwrap_or_default(), adapter.value.unwrap_or_default());
+        if !adapter.invalid_key {
+            self.insert(adapter.key.unwrap_or_default(), adapter.value.unwrap_or_default());
+        }
         Ok(())
     }
 
@@ -267,9 +274,23 @@ impl<
         size
     }
 
-    pub fn write_to(&self, output: &mut CodedOutput, codec: &MapCodec<K, V>) -> OutputResult {
+    pub fn write_to(&self, output: &mut CodedOutput, codec: &MapCodec<K, V>) -> OutputResult
+    where
+        K: Ord,
+    {
         let mut adapter = MapWriteAdapter::new(codec);
-        for field in &self.0 {
+        let mut sorted;
+        let mut unsorted;
+        let fields: &mut dyn Iterator<Item = (&K, &V)> = if output.is_deterministic() {
+            let mut entries: Vec<_> = self.0.iter().collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            sorted = entries.into_iter();
+            &mut sorted
+        } else {
+            unsorted = self.0.iter();
+            &mut unsorted
+        };
+        for field in fields {
             adapter.key = Some(&field.0);
             adapter.value = Some(&field.1);
             output.write_raw_tag(codec.tag)?;
@@ -304,6 +325,7 @@ impl<K: Eq + Hash + Clone, V: LiteMessage> MapField<K, V> {
 
 struct MapReadAdapter<'a, K, V> {
     key: Option<K>,
+    invalid_key: bool,
     value: Option<V>,
     codec: &'a MapCodec<K, V>,
 }
@@ -313,6 +335,7 @@ impl<'a, K, V> MapReadAdapter<'a, K, V> {
         MapReadAdapter {
             codec,
             key: None,
+            invalid_key: false,
             value: None,
         }
     }
@@ -323,7 +346,10 @@ impl<K, V> crate::CodedMessage for MapReadAdapter<'_, K, V> {
         while let Some(tag) = input.read_tag()? {
             match tag {
                 tag if tag == self.codec.key.tag() => {
-                    self.codec.key.merge_from(input, &mut self.key)?
+                    // a key skipped in lenient mode drops the entry, since it can't be keyed by a default
+                    let result = self.codec.key.merge_from(input, &mut self.key);
+                    self.invalid_key = result.is_err();
+                    result?
                 }
                 tag if tag == self.codec.value.tag() => {
                     self.codec.value.merge_from(input, &mut self.value)?
@@ -393,6 +419,14 @@ impl<K, V> crate::CodedMessage
         self.codec.value.write_to(output, self.value.unwrap())?;
         Ok(())
     }
+    fn cached_size(&self) -> Option<i32> {
+        let mut size = 0i32;
+        size = size.checked_add(crate::io::sizes::uint32(self.codec.key.tag().get()))?;
+        size = size.checked_add(self.codec.key.cached_size(self.key.unwrap())?)?;
+        size = size.checked_add(crate::io::sizes::uint32(self.codec.value.tag().get()))?;
+        size = size.checked_add(self.codec.value.cached_size(self.value.unwrap())?)?;
+        Some(size)
+    }
 }
 
 #[doc(hidden)]
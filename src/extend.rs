@@ -316,7 +316,10 @@ impl ExtensionRegistry {
     pub fn has_extension<T: ExtensionIdentifier>(&'static self, extension: &'static T) -> bool {
         self.0
             .get(&(extension.message_type(), extension.tag()))
-            .map_or(false, |r| std::ptr::eq(*r, extension))
+            .map_or(false, |r| {
+                // compare the addresses alone since vtables aren't guaranteed to be unique
+                std::ptr::eq(*r as *const dyn ExtensionIdentifier as *const u8, extension as *const T as *const u8)
+            })
     }
 }
 
@@ -841,7 +844,18 @@ impl<T: ExtendableMessage> ExtensionSet<T> {
     }
 
     pub fn write_to(&self, output: &mut CodedOutput) -> OutputResult {
-        for (_, field) in self.values_by_tag.iter() {
+        let mut sorted;
+        let mut unsorted;
+        let fields: &mut dyn Iterator<Item = (&Tag, &Box<dyn AnyExtension>)> = if output.is_deterministic() {
+            let mut fields: Vec<_> = self.values_by_tag.iter().collect();
+            fields.sort_unstable_by_key(|(tag, _)| **tag);
+            sorted = fields.into_iter();
+            &mut sorted
+        } else {
+            unsorted = self.values_by_tag.iter();
+            &mut unsorted
+        };
+        for (_, field) in fields {
             field.write_to(output)?;
         }
         Ok(())
@@ -141,6 +141,7 @@ pub mod google_protobuf_any_proto {
         type_url: ::std::string::String,
         value: ::std::vec::Vec<u8>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Any {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -164,6 +165,7 @@ pub mod google_protobuf_any_proto {
                 size = size.checked_add(crate::io::sizes::bytes(self.value())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -181,6 +183,9 @@ pub mod google_protobuf_any_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Any {
         fn merge(&mut self, other: &Self) {
@@ -193,6 +198,14 @@ pub mod google_protobuf_any_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Any {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Any {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -297,6 +310,7 @@ pub mod google_protobuf_api_proto {
         mixins: crate::collections::RepeatedField<self::Mixin>,
         syntax: crate::EnumValue<self::super::google_protobuf_type_proto::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Api {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -336,6 +350,7 @@ pub mod google_protobuf_api_proto {
                 size = size.checked_add(crate::io::sizes::enum_value(*self.syntax()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -378,6 +393,9 @@ pub mod google_protobuf_api_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Api {
         fn merge(&mut self, other: &Self) {
@@ -399,6 +417,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Api {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Api {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -566,6 +592,7 @@ pub mod google_protobuf_api_proto {
         options: crate::collections::RepeatedField<self::super::google_protobuf_type_proto::Option>,
         syntax: crate::EnumValue<self::super::google_protobuf_type_proto::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Method {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -616,6 +643,7 @@ pub mod google_protobuf_api_proto {
                 size = size.checked_add(crate::io::sizes::enum_value(*self.syntax()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -654,6 +682,9 @@ pub mod google_protobuf_api_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Method {
         fn merge(&mut self, other: &Self) {
@@ -680,6 +711,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Method {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Method {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -809,6 +848,7 @@ pub mod google_protobuf_api_proto {
         name: ::std::string::String,
         root: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Mixin {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -832,6 +872,7 @@ pub mod google_protobuf_api_proto {
                 size = size.checked_add(crate::io::sizes::string(self.root())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -849,6 +890,9 @@ pub mod google_protobuf_api_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Mixin {
         fn merge(&mut self, other: &Self) {
@@ -861,6 +905,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Mixin {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Mixin {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -1273,6 +1325,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct FileDescriptorSet {
         file: crate::collections::RepeatedField<self::FileDescriptorProto>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FileDescriptorSet {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1293,6 +1346,7 @@ pub mod google_protobuf_descriptor_proto {
                     .calculate_size(&self::file_descriptor_set::FILE_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1307,6 +1361,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileDescriptorSet {
         fn merge(&mut self, other: &Self) {
@@ -1314,6 +1371,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileDescriptorSet {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FileDescriptorSet {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -1356,6 +1421,7 @@ pub mod google_protobuf_descriptor_proto {
         source_code_info: ::std::option::Option<::std::boxed::Box<self::SourceCodeInfo>>,
         syntax: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FileDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1444,6 +1510,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::string(syntax)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1511,6 +1578,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -1539,6 +1609,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FileDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -1861,6 +1939,7 @@ pub mod google_protobuf_descriptor_proto {
         reserved_range: crate::collections::RepeatedField<self::descriptor_proto::ReservedRange>,
         reserved_name: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::DescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1940,6 +2019,7 @@ pub mod google_protobuf_descriptor_proto {
                     .calculate_size(&self::descriptor_proto::RESERVED_NAME_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1999,6 +2079,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::DescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -2019,6 +2102,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::DescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::DescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -2157,6 +2248,7 @@ pub mod google_protobuf_descriptor_proto {
             end: ::std::option::Option<i32>,
             options: ::std::option::Option<::std::boxed::Box<self::super::ExtensionRangeOptions>>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::ExtensionRange {
             fn merge_from(
@@ -2188,6 +2280,7 @@ pub mod google_protobuf_descriptor_proto {
                     size = size.checked_add(crate::io::sizes::extension_message(options)?)?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2214,6 +2307,9 @@ pub mod google_protobuf_descriptor_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::ExtensionRange {
             fn merge(&mut self, other: &Self) {
@@ -2229,6 +2325,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::ExtensionRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::ExtensionRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::DescriptorProto as crate::Message>::descriptor().messages()[0]
@@ -2342,6 +2446,7 @@ pub mod google_protobuf_descriptor_proto {
             start: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::ReservedRange {
             fn merge_from(
@@ -2368,6 +2473,7 @@ pub mod google_protobuf_descriptor_proto {
                     size = size.checked_add(crate::io::sizes::int32(*end))?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2385,6 +2491,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::ReservedRange {
             fn merge(&mut self, other: &Self) {
@@ -2397,6 +2506,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::ReservedRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::ReservedRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::DescriptorProto as crate::Message>::descriptor().messages()[1]
@@ -2577,6 +2694,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct ExtensionRangeOptions {
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::ExtensionRangeOptions {
@@ -2604,6 +2722,7 @@ pub mod google_protobuf_descriptor_proto {
                 )?)?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2621,6 +2740,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ExtensionRangeOptions {
         fn merge(&mut self, other: &Self) {
@@ -2629,6 +2751,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ExtensionRangeOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::ExtensionRangeOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -2759,6 +2889,7 @@ pub mod google_protobuf_descriptor_proto {
         json_name: ::std::option::Option<::std::string::String>,
         options: ::std::option::Option<::std::boxed::Box<self::FieldOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FieldDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -2822,6 +2953,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::extension_message(options)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2876,6 +3008,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -2912,6 +3047,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FieldDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -3400,6 +3543,7 @@ pub mod google_protobuf_descriptor_proto {
         name: ::std::option::Option<::std::string::String>,
         options: ::std::option::Option<::std::boxed::Box<self::OneofOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::OneofDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3423,6 +3567,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::extension_message(options)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3445,6 +3590,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::OneofDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3457,6 +3605,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::OneofDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::OneofDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -3543,6 +3699,7 @@ pub mod google_protobuf_descriptor_proto {
             crate::collections::RepeatedField<self::enum_descriptor_proto::EnumReservedRange>,
         reserved_name: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3587,6 +3744,7 @@ pub mod google_protobuf_descriptor_proto {
                     .calculate_size(&self::enum_descriptor_proto::RESERVED_NAME_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3621,6 +3779,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3636,6 +3797,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[6]
@@ -3727,6 +3896,7 @@ pub mod google_protobuf_descriptor_proto {
             start: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::EnumReservedRange {
             fn merge_from(
@@ -3753,6 +3923,7 @@ pub mod google_protobuf_descriptor_proto {
                     size = size.checked_add(crate::io::sizes::int32(*end))?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3770,6 +3941,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::EnumReservedRange {
             fn merge(&mut self, other: &Self) {
@@ -3782,6 +3956,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::EnumReservedRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::EnumReservedRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::EnumDescriptorProto as crate::Message>::descriptor().messages()[0]
@@ -3914,6 +4096,7 @@ pub mod google_protobuf_descriptor_proto {
         number: ::std::option::Option<i32>,
         options: ::std::option::Option<::std::boxed::Box<self::EnumValueOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumValueDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3942,6 +4125,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::extension_message(options)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3968,6 +4152,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValueDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3983,6 +4170,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValueDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumValueDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[7]
@@ -4100,6 +4295,7 @@ pub mod google_protobuf_descriptor_proto {
         method: crate::collections::RepeatedField<self::MethodDescriptorProto>,
         options: ::std::option::Option<::std::boxed::Box<self::ServiceOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::ServiceDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -4130,6 +4326,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::extension_message(options)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4157,6 +4354,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ServiceDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -4170,6 +4370,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ServiceDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::ServiceDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[8]
@@ -4274,6 +4482,7 @@ pub mod google_protobuf_descriptor_proto {
         client_streaming: ::std::option::Option<bool>,
         server_streaming: ::std::option::Option<bool>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::MethodDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -4317,6 +4526,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::bool(*server_streaming))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4355,6 +4565,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MethodDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -4379,6 +4592,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MethodDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::MethodDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[9]
@@ -4624,6 +4845,7 @@ pub mod google_protobuf_descriptor_proto {
         ruby_package: ::std::option::Option<::std::string::String>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::FileOptions {
@@ -4769,6 +4991,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4883,6 +5106,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileOptions {
         fn merge(&mut self, other: &Self) {
@@ -4970,6 +5196,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::FileOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -5873,6 +6107,7 @@ pub mod google_protobuf_descriptor_proto {
         map_entry: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::MessageOptions {
@@ -5924,6 +6159,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -5959,6 +6195,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MessageOptions {
         fn merge(&mut self, other: &Self) {
@@ -5983,6 +6222,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MessageOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::MessageOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6246,6 +6493,7 @@ pub mod google_protobuf_descriptor_proto {
         weak: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::FieldOptions {
@@ -6302,6 +6550,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6341,6 +6590,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldOptions {
         fn merge(&mut self, other: &Self) {
@@ -6367,6 +6619,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::FieldOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6765,6 +7025,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct OneofOptions {
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::OneofOptions {
@@ -6791,6 +7052,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6806,6 +7068,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::OneofOptions {
         fn merge(&mut self, other: &Self) {
@@ -6814,6 +7079,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::OneofOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::OneofOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6937,6 +7210,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::EnumOptions {
@@ -6973,6 +7247,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6996,6 +7271,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumOptions {
         fn merge(&mut self, other: &Self) {
@@ -7010,6 +7288,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::EnumOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7198,6 +7484,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::EnumValueOptions {
@@ -7230,6 +7517,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7251,6 +7539,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValueOptions {
         fn merge(&mut self, other: &Self) {
@@ -7262,6 +7553,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValueOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::EnumValueOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7417,6 +7716,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::ServiceOptions {
@@ -7448,6 +7748,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7467,6 +7768,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ServiceOptions {
         fn merge(&mut self, other: &Self) {
@@ -7478,6 +7782,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ServiceOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::ServiceOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7635,6 +7947,7 @@ pub mod google_protobuf_descriptor_proto {
             ::std::option::Option<crate::EnumValue<self::method_options::IdempotencyLevel>>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::MethodOptions {
@@ -7672,6 +7985,7 @@ pub mod google_protobuf_descriptor_proto {
             )?;
             size = size.checked_add(self.extensions.calculate_size()?)?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7696,6 +8010,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MethodOptions {
         fn merge(&mut self, other: &Self) {
@@ -7711,6 +8028,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MethodOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::MethodOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7954,6 +8279,7 @@ pub mod google_protobuf_descriptor_proto {
         string_value: ::std::option::Option<::std::vec::Vec<u8>>,
         aggregate_value: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UninterpretedOption {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -8008,6 +8334,7 @@ pub mod google_protobuf_descriptor_proto {
                 size = size.checked_add(crate::io::sizes::string(aggregate_value)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8050,6 +8377,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UninterpretedOption {
         fn merge(&mut self, other: &Self) {
@@ -8079,6 +8409,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UninterpretedOption {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UninterpretedOption {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[18]
@@ -8259,6 +8597,7 @@ pub mod google_protobuf_descriptor_proto {
             name_part: ::std::option::Option<::std::string::String>,
             is_extension: ::std::option::Option<bool>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::NamePart {
             fn merge_from(
@@ -8285,6 +8624,7 @@ pub mod google_protobuf_descriptor_proto {
                     size = size.checked_add(crate::io::sizes::bool(*is_extension))?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8308,6 +8648,9 @@ pub mod google_protobuf_descriptor_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::NamePart {
             fn merge(&mut self, other: &Self) {
@@ -8320,6 +8663,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::NamePart {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::NamePart {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::UninterpretedOption as crate::Message>::descriptor().messages()[0]
@@ -8474,6 +8825,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct SourceCodeInfo {
         location: crate::collections::RepeatedField<self::source_code_info::Location>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::SourceCodeInfo {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -8494,6 +8846,7 @@ pub mod google_protobuf_descriptor_proto {
                     .calculate_size(&self::source_code_info::LOCATION_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8508,6 +8861,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::SourceCodeInfo {
         fn merge(&mut self, other: &Self) {
@@ -8515,6 +8871,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::SourceCodeInfo {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::SourceCodeInfo {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[19]
@@ -8542,6 +8906,7 @@ pub mod google_protobuf_descriptor_proto {
             trailing_comments: ::std::option::Option<::std::string::String>,
             leading_detached_comments: crate::collections::RepeatedField<::std::string::String>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::Location {
             fn merge_from(
@@ -8585,6 +8950,7 @@ pub mod google_protobuf_descriptor_proto {
                         .calculate_size(&self::location::LEADING_DETACHED_COMMENTS_CODEC)?,
                 )?;
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8610,6 +8976,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::Location {
             fn merge(&mut self, other: &Self) {
@@ -8630,6 +8999,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::Location {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::Location {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::SourceCodeInfo as crate::Message>::descriptor().messages()[0]
@@ -8790,6 +9167,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct GeneratedCodeInfo {
         annotation: crate::collections::RepeatedField<self::generated_code_info::Annotation>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::GeneratedCodeInfo {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -8810,6 +9188,7 @@ pub mod google_protobuf_descriptor_proto {
                     .calculate_size(&self::generated_code_info::ANNOTATION_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8824,6 +9203,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::GeneratedCodeInfo {
         fn merge(&mut self, other: &Self) {
@@ -8831,6 +9213,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::GeneratedCodeInfo {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::GeneratedCodeInfo {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[20]
@@ -8857,6 +9247,7 @@ pub mod google_protobuf_descriptor_proto {
             begin: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::Annotation {
             fn merge_from(
@@ -8893,6 +9284,7 @@ pub mod google_protobuf_descriptor_proto {
                     size = size.checked_add(crate::io::sizes::int32(*end))?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8916,6 +9308,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::Annotation {
             fn merge(&mut self, other: &Self) {
@@ -8932,6 +9327,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::Annotation {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::Annotation {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::GeneratedCodeInfo as crate::Message>::descriptor().messages()[0]
@@ -9098,6 +9501,7 @@ pub mod google_protobuf_duration_proto {
         seconds: i64,
         nanos: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Duration {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9121,6 +9525,7 @@ pub mod google_protobuf_duration_proto {
                 size = size.checked_add(crate::io::sizes::int32(*self.nanos()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9138,6 +9543,9 @@ pub mod google_protobuf_duration_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Duration {
         fn merge(&mut self, other: &Self) {
@@ -9150,6 +9558,14 @@ pub mod google_protobuf_duration_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Duration {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Duration {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9210,6 +9626,7 @@ pub mod google_protobuf_empty_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct Empty {
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Empty {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9223,6 +9640,7 @@ pub mod google_protobuf_empty_proto {
         fn calculate_size(&self) -> ::std::option::Option<i32> {
             let mut size = 0i32;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9232,12 +9650,23 @@ pub mod google_protobuf_empty_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Empty {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Empty {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Empty {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9269,6 +9698,7 @@ pub mod google_protobuf_field_mask_proto {
     pub struct FieldMask {
         paths: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FieldMask {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9289,6 +9719,7 @@ pub mod google_protobuf_field_mask_proto {
                     .calculate_size(&self::field_mask::PATHS_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9300,6 +9731,9 @@ pub mod google_protobuf_field_mask_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldMask {
         fn merge(&mut self, other: &Self) {
@@ -9307,6 +9741,14 @@ pub mod google_protobuf_field_mask_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldMask {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FieldMask {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9360,6 +9802,7 @@ pub mod google_protobuf_source_context_proto {
     pub struct SourceContext {
         file_name: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::SourceContext {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9378,6 +9821,7 @@ pub mod google_protobuf_source_context_proto {
                 size = size.checked_add(crate::io::sizes::string(self.file_name())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9391,6 +9835,9 @@ pub mod google_protobuf_source_context_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::SourceContext {
         fn merge(&mut self, other: &Self) {
@@ -9400,6 +9847,14 @@ pub mod google_protobuf_source_context_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::SourceContext {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::SourceContext {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9473,6 +9928,7 @@ pub mod google_protobuf_struct_proto {
     pub struct Struct {
         fields: crate::collections::MapField<::std::string::String, self::Value>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Struct {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9493,6 +9949,7 @@ pub mod google_protobuf_struct_proto {
                     .calculate_size(&self::r#struct::FIELDS_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9507,6 +9964,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Struct {
         fn merge(&mut self, other: &Self) {
@@ -9514,6 +9974,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Struct {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Struct {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9551,6 +10019,7 @@ pub mod google_protobuf_struct_proto {
     pub struct Value {
         kind: self::value::Kind,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9594,6 +10063,7 @@ pub mod google_protobuf_struct_proto {
                 size = size.checked_add(crate::io::sizes::message(list_value)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9637,6 +10107,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Value {
         fn merge(&mut self, other: &Self) {
@@ -9661,6 +10134,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -10016,6 +10497,7 @@ pub mod google_protobuf_struct_proto {
     pub struct ListValue {
         values: crate::collections::RepeatedField<self::Value>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::ListValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10036,6 +10518,7 @@ pub mod google_protobuf_struct_proto {
                     .calculate_size(&self::list_value::VALUES_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10050,6 +10533,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ListValue {
         fn merge(&mut self, other: &Self) {
@@ -10057,6 +10543,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ListValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::ListValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -10134,6 +10628,7 @@ pub mod google_protobuf_timestamp_proto {
         seconds: i64,
         nanos: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Timestamp {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10157,6 +10652,7 @@ pub mod google_protobuf_timestamp_proto {
                 size = size.checked_add(crate::io::sizes::int32(*self.nanos()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10174,6 +10670,9 @@ pub mod google_protobuf_timestamp_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Timestamp {
         fn merge(&mut self, other: &Self) {
@@ -10186,6 +10685,14 @@ pub mod google_protobuf_timestamp_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Timestamp {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Timestamp {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -10330,6 +10837,7 @@ pub mod google_protobuf_type_proto {
         >,
         syntax: crate::EnumValue<self::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Type {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10373,6 +10881,7 @@ pub mod google_protobuf_type_proto {
                 size = size.checked_add(crate::io::sizes::enum_value(*self.syntax()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10411,6 +10920,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Type {
         fn merge(&mut self, other: &Self) {
@@ -10429,6 +10941,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Type {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Type {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -10574,6 +11094,7 @@ pub mod google_protobuf_type_proto {
         json_name: ::std::string::String,
         default_value: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Field {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10636,6 +11157,7 @@ pub mod google_protobuf_type_proto {
                 size = size.checked_add(crate::io::sizes::string(self.default_value())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10686,6 +11208,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Field {
         fn merge(&mut self, other: &Self) {
@@ -10720,6 +11245,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Field {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Field {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -11004,6 +11537,7 @@ pub mod google_protobuf_type_proto {
         >,
         syntax: crate::EnumValue<self::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Enum {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11046,6 +11580,7 @@ pub mod google_protobuf_type_proto {
                 size = size.checked_add(crate::io::sizes::enum_value(*self.syntax()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11082,6 +11617,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Enum {
         fn merge(&mut self, other: &Self) {
@@ -11099,6 +11637,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Enum {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Enum {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -11220,6 +11766,7 @@ pub mod google_protobuf_type_proto {
         number: i32,
         options: crate::collections::RepeatedField<self::Option>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11250,6 +11797,7 @@ pub mod google_protobuf_type_proto {
                     .calculate_size(&self::enum_value::OPTIONS_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11272,6 +11820,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValue {
         fn merge(&mut self, other: &Self) {
@@ -11285,6 +11836,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -11346,6 +11905,7 @@ pub mod google_protobuf_type_proto {
         value:
             ::std::option::Option<::std::boxed::Box<self::super::google_protobuf_any_proto::Any>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Option {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11369,6 +11929,7 @@ pub mod google_protobuf_type_proto {
                 size = size.checked_add(crate::io::sizes::message(value)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11391,6 +11952,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Option {
         fn merge(&mut self, other: &Self) {
@@ -11403,6 +11967,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Option {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Option {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -11525,6 +12097,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct DoubleValue {
         value: f64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::DoubleValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11543,6 +12116,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::double(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11556,6 +12130,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::DoubleValue {
         fn merge(&mut self, other: &Self) {
@@ -11565,6 +12142,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::DoubleValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::DoubleValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -11593,6 +12178,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct FloatValue {
         value: f32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FloatValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11611,6 +12197,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::float(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11624,6 +12211,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FloatValue {
         fn merge(&mut self, other: &Self) {
@@ -11633,6 +12223,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FloatValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FloatValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -11661,6 +12259,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct Int64Value {
         value: i64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Int64Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11679,6 +12278,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::int64(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11692,6 +12292,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Int64Value {
         fn merge(&mut self, other: &Self) {
@@ -11701,6 +12304,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Int64Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Int64Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -11729,6 +12340,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct UInt64Value {
         value: u64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UInt64Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11747,6 +12359,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::uint64(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11760,6 +12373,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UInt64Value {
         fn merge(&mut self, other: &Self) {
@@ -11769,6 +12385,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UInt64Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UInt64Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -11797,6 +12421,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct Int32Value {
         value: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Int32Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11815,6 +12440,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::int32(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11828,6 +12454,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Int32Value {
         fn merge(&mut self, other: &Self) {
@@ -11837,6 +12466,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Int32Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Int32Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -11865,6 +12502,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct UInt32Value {
         value: u32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UInt32Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11883,6 +12521,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::uint32(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11896,6 +12535,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UInt32Value {
         fn merge(&mut self, other: &Self) {
@@ -11905,6 +12547,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UInt32Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UInt32Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -11933,6 +12583,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct BoolValue {
         value: bool,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::BoolValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11951,6 +12602,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::bool(*self.value()))?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11964,6 +12616,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::BoolValue {
         fn merge(&mut self, other: &Self) {
@@ -11973,6 +12628,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::BoolValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::BoolValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[6]
@@ -12001,6 +12664,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct StringValue {
         value: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::StringValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12019,6 +12683,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::string(self.value())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12032,6 +12697,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::StringValue {
         fn merge(&mut self, other: &Self) {
@@ -12041,6 +12709,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::StringValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::StringValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[7]
@@ -12069,6 +12745,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct BytesValue {
         value: ::std::vec::Vec<u8>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::BytesValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12087,6 +12764,7 @@ pub mod google_protobuf_wrappers_proto {
                 size = size.checked_add(crate::io::sizes::bytes(self.value())?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12100,6 +12778,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::BytesValue {
         fn merge(&mut self, other: &Self) {
@@ -12109,6 +12790,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::BytesValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::BytesValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[8]
@@ -12186,6 +12875,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         patch: ::std::option::Option<i32>,
         suffix: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Version {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12219,6 +12909,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                 size = size.checked_add(crate::io::sizes::string(suffix)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12244,6 +12935,9 @@ pub mod google_protobuf_compiler_plugin_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Version {
         fn merge(&mut self, other: &Self) {
@@ -12262,6 +12956,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Version {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Version {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -12419,6 +13121,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         >,
         compiler_version: ::std::option::Option<::std::boxed::Box<self::Version>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::CodeGeneratorRequest {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12457,6 +13160,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                 size = size.checked_add(crate::io::sizes::message(compiler_version)?)?;
             }
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12488,6 +13192,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::CodeGeneratorRequest {
         fn merge(&mut self, other: &Self) {
@@ -12502,6 +13209,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::CodeGeneratorRequest {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::CodeGeneratorRequest {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -12634,6 +13349,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         error: ::std::option::Option<::std::string::String>,
         file: crate::collections::RepeatedField<self::code_generator_response::File>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::CodeGeneratorResponse {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12659,6 +13375,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                     .calculate_size(&self::code_generator_response::FILE_CODEC)?,
             )?;
             size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+            self.cached_size.set(size);
             ::std::option::Option::Some(size)
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12677,6 +13394,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::CodeGeneratorResponse {
         fn merge(&mut self, other: &Self) {
@@ -12687,6 +13407,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::CodeGeneratorResponse {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::CodeGeneratorResponse {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -12739,6 +13467,7 @@ pub mod google_protobuf_compiler_plugin_proto {
             insertion_point: ::std::option::Option<::std::string::String>,
             content: ::std::option::Option<::std::string::String>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::File {
             fn merge_from(
@@ -12771,6 +13500,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                     size = size.checked_add(crate::io::sizes::string(content)?)?;
                 }
                 size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+                self.cached_size.set(size);
                 ::std::option::Option::Some(size)
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12793,6 +13523,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::File {
             fn merge(&mut self, other: &Self) {
@@ -12809,6 +13542,14 @@ pub mod google_protobuf_compiler_plugin_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::File {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::File {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::CodeGeneratorResponse as crate::Message>::descriptor().messages()[0]
@@ -141,6 +141,7 @@ pub mod google_protobuf_any_proto {
         type_url: ::std::string::String,
         value: ::std::vec::Vec<u8>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Any {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -164,6 +165,7 @@ pub mod google_protobuf_any_proto {
                 size += crate::io::sizes::bytes(self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -181,6 +183,9 @@ pub mod google_protobuf_any_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Any {
         fn merge(&mut self, other: &Self) {
@@ -193,6 +198,14 @@ pub mod google_protobuf_any_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Any {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Any {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -297,6 +310,7 @@ pub mod google_protobuf_api_proto {
         mixins: crate::collections::RepeatedField<self::Mixin>,
         syntax: crate::EnumValue<self::super::google_protobuf_type_proto::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Api {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -336,6 +350,7 @@ pub mod google_protobuf_api_proto {
                 size += crate::io::sizes::enum_value(*self.syntax());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -378,6 +393,9 @@ pub mod google_protobuf_api_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Api {
         fn merge(&mut self, other: &Self) {
@@ -399,6 +417,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Api {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Api {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -566,6 +592,7 @@ pub mod google_protobuf_api_proto {
         options: crate::collections::RepeatedField<self::super::google_protobuf_type_proto::Option>,
         syntax: crate::EnumValue<self::super::google_protobuf_type_proto::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Method {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -613,6 +640,7 @@ pub mod google_protobuf_api_proto {
                 size += crate::io::sizes::enum_value(*self.syntax());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -651,6 +679,9 @@ pub mod google_protobuf_api_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Method {
         fn merge(&mut self, other: &Self) {
@@ -677,6 +708,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Method {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Method {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -806,6 +845,7 @@ pub mod google_protobuf_api_proto {
         name: ::std::string::String,
         root: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Mixin {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -829,6 +869,7 @@ pub mod google_protobuf_api_proto {
                 size += crate::io::sizes::string(self.root());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -846,6 +887,9 @@ pub mod google_protobuf_api_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Mixin {
         fn merge(&mut self, other: &Self) {
@@ -858,6 +902,14 @@ pub mod google_protobuf_api_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Mixin {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Mixin {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -1270,6 +1322,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct FileDescriptorSet {
         file: crate::collections::RepeatedField<self::FileDescriptorProto>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FileDescriptorSet {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1289,6 +1342,7 @@ pub mod google_protobuf_descriptor_proto {
                 .file()
                 .calculate_size(&self::file_descriptor_set::FILE_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1303,6 +1357,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileDescriptorSet {
         fn merge(&mut self, other: &Self) {
@@ -1310,6 +1367,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileDescriptorSet {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FileDescriptorSet {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -1352,6 +1417,7 @@ pub mod google_protobuf_descriptor_proto {
         source_code_info: ::std::option::Option<::std::boxed::Box<self::SourceCodeInfo>>,
         syntax: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FileDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1433,6 +1499,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::string(syntax);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1500,6 +1567,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -1528,6 +1598,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FileDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -1850,6 +1928,7 @@ pub mod google_protobuf_descriptor_proto {
         reserved_range: crate::collections::RepeatedField<self::descriptor_proto::ReservedRange>,
         reserved_name: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::DescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -1921,6 +2000,7 @@ pub mod google_protobuf_descriptor_proto {
                 .reserved_name()
                 .calculate_size(&self::descriptor_proto::RESERVED_NAME_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -1980,6 +2060,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::DescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -2000,6 +2083,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::DescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::DescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -2138,6 +2229,7 @@ pub mod google_protobuf_descriptor_proto {
             end: ::std::option::Option<i32>,
             options: ::std::option::Option<::std::boxed::Box<self::super::ExtensionRangeOptions>>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::ExtensionRange {
             fn merge_from(
@@ -2169,6 +2261,7 @@ pub mod google_protobuf_descriptor_proto {
                     size += crate::io::sizes::extension_message(options);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2195,6 +2288,9 @@ pub mod google_protobuf_descriptor_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::ExtensionRange {
             fn merge(&mut self, other: &Self) {
@@ -2210,6 +2306,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::ExtensionRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::ExtensionRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::DescriptorProto as crate::Message>::descriptor().messages()[0]
@@ -2323,6 +2427,7 @@ pub mod google_protobuf_descriptor_proto {
             start: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::ReservedRange {
             fn merge_from(
@@ -2349,6 +2454,7 @@ pub mod google_protobuf_descriptor_proto {
                     size += crate::io::sizes::int32(*end);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2366,6 +2472,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::ReservedRange {
             fn merge(&mut self, other: &Self) {
@@ -2378,6 +2487,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::ReservedRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::ReservedRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::DescriptorProto as crate::Message>::descriptor().messages()[1]
@@ -2558,6 +2675,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct ExtensionRangeOptions {
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::ExtensionRangeOptions {
@@ -2584,6 +2702,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::extension_range_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2601,6 +2720,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ExtensionRangeOptions {
         fn merge(&mut self, other: &Self) {
@@ -2609,6 +2731,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ExtensionRangeOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::ExtensionRangeOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -2739,6 +2869,7 @@ pub mod google_protobuf_descriptor_proto {
         json_name: ::std::option::Option<::std::string::String>,
         options: ::std::option::Option<::std::boxed::Box<self::FieldOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FieldDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -2802,6 +2933,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::extension_message(options);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -2856,6 +2988,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -2892,6 +3027,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FieldDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -3380,6 +3523,7 @@ pub mod google_protobuf_descriptor_proto {
         name: ::std::option::Option<::std::string::String>,
         options: ::std::option::Option<::std::boxed::Box<self::OneofOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::OneofDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3403,6 +3547,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::extension_message(options);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3425,6 +3570,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::OneofDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3437,6 +3585,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::OneofDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::OneofDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -3523,6 +3679,7 @@ pub mod google_protobuf_descriptor_proto {
             crate::collections::RepeatedField<self::enum_descriptor_proto::EnumReservedRange>,
         reserved_name: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3564,6 +3721,7 @@ pub mod google_protobuf_descriptor_proto {
                 .reserved_name()
                 .calculate_size(&self::enum_descriptor_proto::RESERVED_NAME_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3598,6 +3756,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3613,6 +3774,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[6]
@@ -3704,6 +3873,7 @@ pub mod google_protobuf_descriptor_proto {
             start: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::EnumReservedRange {
             fn merge_from(
@@ -3730,6 +3900,7 @@ pub mod google_protobuf_descriptor_proto {
                     size += crate::io::sizes::int32(*end);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3747,6 +3918,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::EnumReservedRange {
             fn merge(&mut self, other: &Self) {
@@ -3759,6 +3933,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::EnumReservedRange {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::EnumReservedRange {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::EnumDescriptorProto as crate::Message>::descriptor().messages()[0]
@@ -3891,6 +4073,7 @@ pub mod google_protobuf_descriptor_proto {
         number: ::std::option::Option<i32>,
         options: ::std::option::Option<::std::boxed::Box<self::EnumValueOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumValueDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -3919,6 +4102,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::extension_message(options);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -3945,6 +4129,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValueDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -3960,6 +4147,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValueDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumValueDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[7]
@@ -4077,6 +4272,7 @@ pub mod google_protobuf_descriptor_proto {
         method: crate::collections::RepeatedField<self::MethodDescriptorProto>,
         options: ::std::option::Option<::std::boxed::Box<self::ServiceOptions>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::ServiceDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -4106,6 +4302,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::extension_message(options);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4133,6 +4330,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ServiceDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -4146,6 +4346,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ServiceDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::ServiceDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[8]
@@ -4250,6 +4458,7 @@ pub mod google_protobuf_descriptor_proto {
         client_streaming: ::std::option::Option<bool>,
         server_streaming: ::std::option::Option<bool>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::MethodDescriptorProto {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -4293,6 +4502,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::bool(*server_streaming);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4331,6 +4541,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MethodDescriptorProto {
         fn merge(&mut self, other: &Self) {
@@ -4355,6 +4568,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MethodDescriptorProto {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::MethodDescriptorProto {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[9]
@@ -4600,6 +4821,7 @@ pub mod google_protobuf_descriptor_proto {
         ruby_package: ::std::option::Option<::std::string::String>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::FileOptions {
@@ -4744,6 +4966,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::file_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -4858,6 +5081,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FileOptions {
         fn merge(&mut self, other: &Self) {
@@ -4945,6 +5171,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FileOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::FileOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -5848,6 +6082,7 @@ pub mod google_protobuf_descriptor_proto {
         map_entry: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::MessageOptions {
@@ -5897,6 +6132,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::message_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -5932,6 +6168,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MessageOptions {
         fn merge(&mut self, other: &Self) {
@@ -5956,6 +6195,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MessageOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::MessageOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6219,6 +6466,7 @@ pub mod google_protobuf_descriptor_proto {
         weak: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::FieldOptions {
@@ -6274,6 +6522,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::field_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6313,6 +6562,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldOptions {
         fn merge(&mut self, other: &Self) {
@@ -6339,6 +6591,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::FieldOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6737,6 +6997,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct OneofOptions {
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::OneofOptions {
@@ -6762,6 +7023,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::oneof_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6777,6 +7039,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::OneofOptions {
         fn merge(&mut self, other: &Self) {
@@ -6785,6 +7050,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::OneofOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::OneofOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -6908,6 +7181,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::EnumOptions {
@@ -6943,6 +7217,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::enum_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -6966,6 +7241,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumOptions {
         fn merge(&mut self, other: &Self) {
@@ -6980,6 +7258,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::EnumOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7168,6 +7454,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::EnumValueOptions {
@@ -7199,6 +7486,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::enum_value_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7220,6 +7508,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValueOptions {
         fn merge(&mut self, other: &Self) {
@@ -7231,6 +7522,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValueOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::EnumValueOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7386,6 +7685,7 @@ pub mod google_protobuf_descriptor_proto {
         deprecated: ::std::option::Option<bool>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::ServiceOptions {
@@ -7416,6 +7716,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::service_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7435,6 +7736,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ServiceOptions {
         fn merge(&mut self, other: &Self) {
@@ -7446,6 +7750,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ServiceOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::ServiceOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7603,6 +7915,7 @@ pub mod google_protobuf_descriptor_proto {
             ::std::option::Option<crate::EnumValue<self::method_options::IdempotencyLevel>>,
         uninterpreted_option: crate::collections::RepeatedField<self::UninterpretedOption>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
         extensions: crate::ExtensionSet<Self>,
     }
     impl crate::CodedMessage for self::MethodOptions {
@@ -7639,6 +7952,7 @@ pub mod google_protobuf_descriptor_proto {
                 .calculate_size(&self::method_options::UNINTERPRETED_OPTION_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -7663,6 +7977,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::MethodOptions {
         fn merge(&mut self, other: &Self) {
@@ -7678,6 +7995,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::MethodOptions {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::ExtendableMessage for self::MethodOptions {
         fn registry(&self) -> ::std::option::Option<&'static crate::ExtensionRegistry> {
             self.extensions.registry()
@@ -7921,6 +8246,7 @@ pub mod google_protobuf_descriptor_proto {
         string_value: ::std::option::Option<::std::vec::Vec<u8>>,
         aggregate_value: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UninterpretedOption {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -7974,6 +8300,7 @@ pub mod google_protobuf_descriptor_proto {
                 size += crate::io::sizes::string(aggregate_value);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8016,6 +8343,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UninterpretedOption {
         fn merge(&mut self, other: &Self) {
@@ -8045,6 +8375,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UninterpretedOption {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UninterpretedOption {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[18]
@@ -8225,6 +8563,7 @@ pub mod google_protobuf_descriptor_proto {
             name_part: ::std::option::Option<::std::string::String>,
             is_extension: ::std::option::Option<bool>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::NamePart {
             fn merge_from(
@@ -8251,6 +8590,7 @@ pub mod google_protobuf_descriptor_proto {
                     size += crate::io::sizes::bool(*is_extension);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8274,6 +8614,9 @@ pub mod google_protobuf_descriptor_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::NamePart {
             fn merge(&mut self, other: &Self) {
@@ -8286,6 +8629,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::NamePart {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::NamePart {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::UninterpretedOption as crate::Message>::descriptor().messages()[0]
@@ -8440,6 +8791,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct SourceCodeInfo {
         location: crate::collections::RepeatedField<self::source_code_info::Location>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::SourceCodeInfo {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -8459,6 +8811,7 @@ pub mod google_protobuf_descriptor_proto {
                 .location()
                 .calculate_size(&self::source_code_info::LOCATION_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8473,6 +8826,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::SourceCodeInfo {
         fn merge(&mut self, other: &Self) {
@@ -8480,6 +8836,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::SourceCodeInfo {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::SourceCodeInfo {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[19]
@@ -8507,6 +8871,7 @@ pub mod google_protobuf_descriptor_proto {
             trailing_comments: ::std::option::Option<::std::string::String>,
             leading_detached_comments: crate::collections::RepeatedField<::std::string::String>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::Location {
             fn merge_from(
@@ -8547,6 +8912,7 @@ pub mod google_protobuf_descriptor_proto {
                     .leading_detached_comments()
                     .calculate_size(&self::location::LEADING_DETACHED_COMMENTS_CODEC);
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8572,6 +8938,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::Location {
             fn merge(&mut self, other: &Self) {
@@ -8592,6 +8961,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::Location {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::Location {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::SourceCodeInfo as crate::Message>::descriptor().messages()[0]
@@ -8752,6 +9129,7 @@ pub mod google_protobuf_descriptor_proto {
     pub struct GeneratedCodeInfo {
         annotation: crate::collections::RepeatedField<self::generated_code_info::Annotation>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::GeneratedCodeInfo {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -8771,6 +9149,7 @@ pub mod google_protobuf_descriptor_proto {
                 .annotation()
                 .calculate_size(&self::generated_code_info::ANNOTATION_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8785,6 +9164,9 @@ pub mod google_protobuf_descriptor_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::GeneratedCodeInfo {
         fn merge(&mut self, other: &Self) {
@@ -8792,6 +9174,14 @@ pub mod google_protobuf_descriptor_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::GeneratedCodeInfo {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::GeneratedCodeInfo {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[20]
@@ -8818,6 +9208,7 @@ pub mod google_protobuf_descriptor_proto {
             begin: ::std::option::Option<i32>,
             end: ::std::option::Option<i32>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::Annotation {
             fn merge_from(
@@ -8853,6 +9244,7 @@ pub mod google_protobuf_descriptor_proto {
                     size += crate::io::sizes::int32(*end);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -8876,6 +9268,9 @@ pub mod google_protobuf_descriptor_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::Annotation {
             fn merge(&mut self, other: &Self) {
@@ -8892,6 +9287,14 @@ pub mod google_protobuf_descriptor_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::Annotation {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::Annotation {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::GeneratedCodeInfo as crate::Message>::descriptor().messages()[0]
@@ -9058,6 +9461,7 @@ pub mod google_protobuf_duration_proto {
         seconds: i64,
         nanos: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Duration {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9081,6 +9485,7 @@ pub mod google_protobuf_duration_proto {
                 size += crate::io::sizes::int32(*self.nanos());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9098,6 +9503,9 @@ pub mod google_protobuf_duration_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Duration {
         fn merge(&mut self, other: &Self) {
@@ -9110,6 +9518,14 @@ pub mod google_protobuf_duration_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Duration {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Duration {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9170,6 +9586,7 @@ pub mod google_protobuf_empty_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct Empty {
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Empty {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9183,6 +9600,7 @@ pub mod google_protobuf_empty_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9192,12 +9610,23 @@ pub mod google_protobuf_empty_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Empty {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Empty {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Empty {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9229,6 +9658,7 @@ pub mod google_protobuf_field_mask_proto {
     pub struct FieldMask {
         paths: crate::collections::RepeatedField<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FieldMask {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9246,6 +9676,7 @@ pub mod google_protobuf_field_mask_proto {
             let mut size = 0i32;
             size += self.paths().calculate_size(&self::field_mask::PATHS_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9257,6 +9688,9 @@ pub mod google_protobuf_field_mask_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FieldMask {
         fn merge(&mut self, other: &Self) {
@@ -9264,6 +9698,14 @@ pub mod google_protobuf_field_mask_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FieldMask {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FieldMask {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9317,6 +9759,7 @@ pub mod google_protobuf_source_context_proto {
     pub struct SourceContext {
         file_name: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::SourceContext {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9335,6 +9778,7 @@ pub mod google_protobuf_source_context_proto {
                 size += crate::io::sizes::string(self.file_name());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9348,6 +9792,9 @@ pub mod google_protobuf_source_context_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::SourceContext {
         fn merge(&mut self, other: &Self) {
@@ -9357,6 +9804,14 @@ pub mod google_protobuf_source_context_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::SourceContext {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::SourceContext {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9430,6 +9885,7 @@ pub mod google_protobuf_struct_proto {
     pub struct Struct {
         fields: crate::collections::MapField<::std::string::String, self::Value>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Struct {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9447,6 +9903,7 @@ pub mod google_protobuf_struct_proto {
             let mut size = 0i32;
             size += self.fields().calculate_size(&self::r#struct::FIELDS_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9461,6 +9918,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Struct {
         fn merge(&mut self, other: &Self) {
@@ -9468,6 +9928,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Struct {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Struct {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -9505,6 +9973,7 @@ pub mod google_protobuf_struct_proto {
     pub struct Value {
         kind: self::value::Kind,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9548,6 +10017,7 @@ pub mod google_protobuf_struct_proto {
                 size += crate::io::sizes::message(list_value);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -9591,6 +10061,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Value {
         fn merge(&mut self, other: &Self) {
@@ -9615,6 +10088,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -9970,6 +10451,7 @@ pub mod google_protobuf_struct_proto {
     pub struct ListValue {
         values: crate::collections::RepeatedField<self::Value>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::ListValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -9989,6 +10471,7 @@ pub mod google_protobuf_struct_proto {
                 .values()
                 .calculate_size(&self::list_value::VALUES_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10003,6 +10486,9 @@ pub mod google_protobuf_struct_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::ListValue {
         fn merge(&mut self, other: &Self) {
@@ -10010,6 +10496,14 @@ pub mod google_protobuf_struct_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::ListValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::ListValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -10087,6 +10581,7 @@ pub mod google_protobuf_timestamp_proto {
         seconds: i64,
         nanos: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Timestamp {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10110,6 +10605,7 @@ pub mod google_protobuf_timestamp_proto {
                 size += crate::io::sizes::int32(*self.nanos());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10127,6 +10623,9 @@ pub mod google_protobuf_timestamp_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Timestamp {
         fn merge(&mut self, other: &Self) {
@@ -10139,6 +10638,14 @@ pub mod google_protobuf_timestamp_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Timestamp {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Timestamp {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -10283,6 +10790,7 @@ pub mod google_protobuf_type_proto {
         >,
         syntax: crate::EnumValue<self::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Type {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10323,6 +10831,7 @@ pub mod google_protobuf_type_proto {
                 size += crate::io::sizes::enum_value(*self.syntax());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10361,6 +10870,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Type {
         fn merge(&mut self, other: &Self) {
@@ -10379,6 +10891,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Type {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Type {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -10524,6 +11044,7 @@ pub mod google_protobuf_type_proto {
         json_name: ::std::string::String,
         default_value: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Field {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10586,6 +11107,7 @@ pub mod google_protobuf_type_proto {
                 size += crate::io::sizes::string(self.default_value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -10636,6 +11158,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Field {
         fn merge(&mut self, other: &Self) {
@@ -10670,6 +11195,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Field {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Field {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -10954,6 +11487,7 @@ pub mod google_protobuf_type_proto {
         >,
         syntax: crate::EnumValue<self::Syntax>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Enum {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -10992,6 +11526,7 @@ pub mod google_protobuf_type_proto {
                 size += crate::io::sizes::enum_value(*self.syntax());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11028,6 +11563,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Enum {
         fn merge(&mut self, other: &Self) {
@@ -11045,6 +11583,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Enum {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Enum {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -11166,6 +11712,7 @@ pub mod google_protobuf_type_proto {
         number: i32,
         options: crate::collections::RepeatedField<self::Option>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::EnumValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11195,6 +11742,7 @@ pub mod google_protobuf_type_proto {
                 .options()
                 .calculate_size(&self::enum_value::OPTIONS_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11217,6 +11765,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::EnumValue {
         fn merge(&mut self, other: &Self) {
@@ -11230,6 +11781,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::EnumValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::EnumValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -11291,6 +11850,7 @@ pub mod google_protobuf_type_proto {
         value:
             ::std::option::Option<::std::boxed::Box<self::super::google_protobuf_any_proto::Any>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Option {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11314,6 +11874,7 @@ pub mod google_protobuf_type_proto {
                 size += crate::io::sizes::message(value);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11336,6 +11897,9 @@ pub mod google_protobuf_type_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Option {
         fn merge(&mut self, other: &Self) {
@@ -11348,6 +11912,14 @@ pub mod google_protobuf_type_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Option {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Option {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -11470,6 +12042,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct DoubleValue {
         value: f64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::DoubleValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11488,6 +12061,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::double(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11501,6 +12075,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::DoubleValue {
         fn merge(&mut self, other: &Self) {
@@ -11510,6 +12087,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::DoubleValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::DoubleValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -11538,6 +12123,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct FloatValue {
         value: f32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::FloatValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11556,6 +12142,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::float(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11569,6 +12156,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::FloatValue {
         fn merge(&mut self, other: &Self) {
@@ -11578,6 +12168,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::FloatValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::FloatValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -11606,6 +12204,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct Int64Value {
         value: i64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Int64Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11624,6 +12223,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::int64(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11637,6 +12237,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Int64Value {
         fn merge(&mut self, other: &Self) {
@@ -11646,6 +12249,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Int64Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Int64Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -11674,6 +12285,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct UInt64Value {
         value: u64,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UInt64Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11692,6 +12304,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::uint64(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11705,6 +12318,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UInt64Value {
         fn merge(&mut self, other: &Self) {
@@ -11714,6 +12330,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UInt64Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UInt64Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -11742,6 +12366,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct Int32Value {
         value: i32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Int32Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11760,6 +12385,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::int32(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11773,6 +12399,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Int32Value {
         fn merge(&mut self, other: &Self) {
@@ -11782,6 +12411,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Int32Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Int32Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -11810,6 +12447,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct UInt32Value {
         value: u32,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::UInt32Value {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11828,6 +12466,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::uint32(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11841,6 +12480,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::UInt32Value {
         fn merge(&mut self, other: &Self) {
@@ -11850,6 +12492,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::UInt32Value {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::UInt32Value {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -11878,6 +12528,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct BoolValue {
         value: bool,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::BoolValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11896,6 +12547,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::bool(*self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11909,6 +12561,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::BoolValue {
         fn merge(&mut self, other: &Self) {
@@ -11918,6 +12573,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::BoolValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::BoolValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[6]
@@ -11946,6 +12609,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct StringValue {
         value: ::std::string::String,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::StringValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -11964,6 +12628,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::string(self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -11977,6 +12642,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::StringValue {
         fn merge(&mut self, other: &Self) {
@@ -11986,6 +12654,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::StringValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::StringValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[7]
@@ -12014,6 +12690,7 @@ pub mod google_protobuf_wrappers_proto {
     pub struct BytesValue {
         value: ::std::vec::Vec<u8>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::BytesValue {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12032,6 +12709,7 @@ pub mod google_protobuf_wrappers_proto {
                 size += crate::io::sizes::bytes(self.value());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12045,6 +12723,9 @@ pub mod google_protobuf_wrappers_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::BytesValue {
         fn merge(&mut self, other: &Self) {
@@ -12054,6 +12735,14 @@ pub mod google_protobuf_wrappers_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::BytesValue {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::BytesValue {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[8]
@@ -12131,6 +12820,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         patch: ::std::option::Option<i32>,
         suffix: ::std::option::Option<::std::string::String>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::Version {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12164,6 +12854,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                 size += crate::io::sizes::string(suffix);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12189,6 +12880,9 @@ pub mod google_protobuf_compiler_plugin_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::Version {
         fn merge(&mut self, other: &Self) {
@@ -12207,6 +12901,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::Version {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::Version {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -12364,6 +13066,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         >,
         compiler_version: ::std::option::Option<::std::boxed::Box<self::Version>>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::CodeGeneratorRequest {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12400,6 +13103,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                 size += crate::io::sizes::message(compiler_version);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12431,6 +13135,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::CodeGeneratorRequest {
         fn merge(&mut self, other: &Self) {
@@ -12445,6 +13152,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::CodeGeneratorRequest {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::CodeGeneratorRequest {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -12577,6 +13292,7 @@ pub mod google_protobuf_compiler_plugin_proto {
         error: ::std::option::Option<::std::string::String>,
         file: crate::collections::RepeatedField<self::code_generator_response::File>,
         unknown_fields: crate::UnknownFieldSet,
+        cached_size: crate::CachedSize,
     }
     impl crate::CodedMessage for self::CodeGeneratorResponse {
         fn merge_from(&mut self, input: &mut crate::io::CodedInput) -> crate::io::InputResult<()> {
@@ -12601,6 +13317,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                 .file()
                 .calculate_size(&self::code_generator_response::FILE_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12619,6 +13336,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl crate::LiteMessage for self::CodeGeneratorResponse {
         fn merge(&mut self, other: &Self) {
@@ -12629,6 +13349,14 @@ pub mod google_protobuf_compiler_plugin_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl crate::UnknownFields for self::CodeGeneratorResponse {
+        fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl crate::Message for self::CodeGeneratorResponse {
         fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -12681,6 +13409,7 @@ pub mod google_protobuf_compiler_plugin_proto {
             insertion_point: ::std::option::Option<::std::string::String>,
             content: ::std::option::Option<::std::string::String>,
             unknown_fields: crate::UnknownFieldSet,
+            cached_size: crate::CachedSize,
         }
         impl crate::CodedMessage for self::File {
             fn merge_from(
@@ -12713,6 +13442,7 @@ pub mod google_protobuf_compiler_plugin_proto {
                     size += crate::io::sizes::string(content);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(&self, output: &mut crate::io::CodedOutput) -> crate::io::OutputResult {
@@ -12735,6 +13465,9 @@ pub mod google_protobuf_compiler_plugin_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl crate::LiteMessage for self::File {
             fn merge(&mut self, other: &Self) {
@@ -12751,6 +13484,14 @@ pub mod google_protobuf_compiler_plugin_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl crate::UnknownFields for self::File {
+            fn unknown_fields(&self) -> &crate::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut crate::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl crate::Message for self::File {
             fn descriptor() -> &'static crate::reflect::MessageDescriptor<'static> {
                 &<self::super::CodeGeneratorResponse as crate::Message>::descriptor().messages()[0]
@@ -1,7 +1,11 @@
 //! Contains types for reading and writing Protocol Buffer streams
 
 use crate::CodedMessage;
+#[cfg(feature = "reflection")]
+use crate::reflect::{FieldType, MessageDescriptor};
+use std::borrow::Cow;
 use std::cmp::min;
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
@@ -301,6 +305,12 @@ pub mod sizes {
         length + int32(length)
     }
 
+    /// Gets the size of a message from its cached size, or None if the message doesn't cache its size
+    #[inline]
+    pub(crate) fn cached_message(value: &dyn CodedMessage) -> Option<i32> {
+        value.cached_size().map(|length| length + int32(length))
+    }
+
     pub use message as extension_message; // for the compiler plugin
 
     #[inline]
@@ -381,52 +391,166 @@ pub mod sizes {
     }
 }
 
-/// The error type for [`CodedInput`](struct.CodedInput.html) and associated read operations
+/// The error type for [`CodedInput`](struct.CodedInput.html) and associated read operations.
+///
+/// The error contains the kind of error that occured along with the offset in the input where it occured
+/// and the path of the field that was being read.
 #[derive(Debug)]
-pub enum InputError {
-    /// The input contained a malformed variable length integer
-    MalformedVarint,
-    /// The input contained a length delimited value which reported it had a negative size
-    NegativeSize,
-    /// The input contained an invalid tag (zero or the tag had an invalid wire format)
-    InvalidTag(u32),
-    /// An error occured while reading from the underlying `Read` object
-    IoError(std::io::Error),
-    /// The input contained an invalid UTF8 string
-    InvalidString(std::string::FromUtf8Error),
+pub struct InputError {
+    kind: InputErrorKind,
+    offset: Option<usize>,
+    field_path: Vec<FieldNumber>,
+}
+
+impl InputError {
+    /// Creates a new error of the specified kind without an offset or field path
+    pub fn new(kind: InputErrorKind) -> InputError {
+        InputError { kind, offset: None, field_path: Vec::new() }
+    }
+
+    /// Gets the kind of error that occured
+    pub fn kind(&self) -> &InputErrorKind {
+        &self.kind
+    }
+
+    /// Converts the error into the kind of error that occured
+    pub fn into_kind(self) -> InputErrorKind {
+        self.kind
+    }
+
+    /// Gets the number of bytes the input had read when the error occured,
+    /// or None if the error didn't occur in a [`CodedInput`](struct.CodedInput.html)
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Gets the numbers of the fields that were being read when the error occured, starting with the field of the outermost message.
+    /// This is empty if the error occured outside of any field, like while reading a tag in the outermost message
+    pub fn field_path(&self) -> &[FieldNumber] {
+        &self.field_path
+    }
+
+    /// Gets the names of the fields that were being read when the error occured, resolved using the descriptor
+    /// of the outermost message and separated by dots. Fields that can't be resolved are named by their number
+    #[cfg(feature = "reflection")]
+    pub fn field_path_names<'a>(&self, descriptor: &MessageDescriptor<'a>) -> String {
+        let mut names = Vec::with_capacity(self.field_path.len());
+        let mut message = Some(descriptor);
+        for number in &self.field_path {
+            let field = message.and_then(|m| m.fields().iter().find(|f| f.number() == *number));
+            match field {
+                Some(field) => {
+                    names.push(field.name().to_string());
+                    message =
+                        match field.field_type() {
+                            FieldType::Message(m) | FieldType::Group(m) => Some(m),
+                            _ => None,
+                        };
+                }
+                None => {
+                    names.push(number.to_string());
+                    message = None;
+                }
+            }
+        }
+        names.join(".")
+    }
+
+    /// Sets the offset and field path of an error that wasn't created by an input
+    fn located(mut self, field_path: &[FieldNumber], offset: usize) -> InputError {
+        if self.offset.is_none() {
+            self.offset = Some(offset);
+            self.field_path = field_path.to_vec();
+        }
+        self
+    }
+}
+
+impl From<InputErrorKind> for InputError {
+    fn from(value: InputErrorKind) -> InputError {
+        InputError::new(value)
+    }
 }
 
 impl From<std::io::Error> for InputError {
     fn from(value: std::io::Error) -> InputError {
-        InputError::IoError(value)
+        InputError::new(InputErrorKind::IoError(value))
     }
 }
 
 impl From<std::string::FromUtf8Error> for InputError {
     fn from(value: std::string::FromUtf8Error) -> InputError {
-        InputError::InvalidString(value)
+        InputError::new(InputErrorKind::InvalidString(value))
     }
 }
 
 impl Display for InputError {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
-        use self::InputError::*;
+        write!(fmt, "{}", self.kind)?;
+        if let Some(offset) = self.offset {
+            write!(fmt, " at offset {}", offset)?;
+        }
+        if let Some((first, rest)) = self.field_path.split_first() {
+            write!(fmt, " in field {}", first)?;
+            for number in rest {
+                write!(fmt, ".{}", number)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            InputErrorKind::IoError(ref e) => Some(e),
+            InputErrorKind::InvalidString(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of error that occured while reading from a [`CodedInput`](struct.CodedInput.html)
+#[derive(Debug)]
+pub enum InputErrorKind {
+    /// The input contained a malformed variable length integer
+    MalformedVarint,
+    /// The input contained a length delimited value which reported it had a negative size
+    NegativeSize,
+    /// The input contained an invalid tag (zero or the tag had an invalid wire format)
+    InvalidTag(u32),
+    /// An error occured while reading from the underlying `Read` object
+    IoError(std::io::Error),
+    /// The input contained an invalid UTF8 string
+    InvalidString(std::string::FromUtf8Error),
+    /// The input contained messages or groups nested deeper than the input's recursion limit
+    RecursionLimitExceeded,
+    /// The input contained more bytes than the input's size limit
+    SizeLimitExceeded,
+}
+
+impl Display for InputErrorKind {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        use self::InputErrorKind::*;
         match self {
             MalformedVarint => write!(fmt, "the input contained an invalid variable length integer"),
             NegativeSize => write!(fmt, "the input contained a length delimited value which reported it had a negative size"),
             InvalidTag(val) => write!(fmt, "the input contained an tag that was either invalid or was unexpected at this point in the input: {}", val),
             IoError(_) => write!(fmt, "an error occured in the underlying input"),
-            InvalidString(_) => write!(fmt, "the input contained an invalid UTF8 string")
+            InvalidString(_) => write!(fmt, "the input contained an invalid UTF8 string"),
+            RecursionLimitExceeded => write!(fmt, "the input contained messages or groups nested deeper than the recursion limit"),
+            SizeLimitExceeded => write!(fmt, "the input contained more bytes than the size limit"),
         }
     }
 }
 
-impl Error for InputError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
+impl InputErrorKind {
+    /// Returns whether an input in lenient mode can recover from this kind of error
+    fn is_recoverable(&self) -> bool {
         match self {
-            InputError::IoError(ref e) => Some(e),
-            InputError::InvalidString(ref e) => Some(e),
-            _ => None,
+            InputErrorKind::IoError(e) => e.kind() == std::io::ErrorKind::UnexpectedEof,
+            InputErrorKind::SizeLimitExceeded => false,
+            _ => true,
         }
     }
 }
@@ -434,22 +558,114 @@ impl Error for InputError {
 /// The result of a read from a CodedInput
 pub type InputResult<T> = Result<T, InputError>;
 
-/// A protocol buffers input stream
+/// The source of the data read by a CodedInput
+enum Source<'a> {
+    /// A `Read` instance, read from one value at a time
+    Reader(&'a mut dyn Read),
+    /// A slice containing the rest of the input, which values can be read from directly
+    Slice(&'a [u8]),
+}
+
+fn unexpected_eof() -> InputErrorKind {
+    InputErrorKind::IoError(std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        "the input ended in the middle of a field",
+    ))
+}
+
+/// A protocol buffers input stream.
+///
+/// An input can read from any [`Read`] instance using [`new`](#method.new), or directly from a
+/// byte slice using [`from_slice`](#method.from_slice). Slice inputs read values without going through
+/// `Read` and can borrow length delimited values from the slice without copying them.
+///
+/// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
 pub struct CodedInput<'a> {
-    inner: &'a mut dyn Read,
+    inner: Source<'a>,
     limit: Option<i32>,
     last_tag: Option<Tag>,
     registry: Option<&'static crate::ExtensionRegistry>,
+    recursion_depth: u32,
+    recursion_limit: u32,
+    position: usize,
+    size_limit: usize,
+    discard_unknown_fields: bool,
+    field_filter: Option<&'a FieldFilter>,
+    field_path: Vec<FieldNumber>,
+    lenient: bool,
+    recovery_stopped: bool,
+    diagnostics: Vec<InputError>,
 }
 
 impl<'a> CodedInput<'a> {
-    /// Creates a new CodedInput from the specified Read instance
+    /// The default maximum depth of nested messages and groups an input will read
+    pub const DEFAULT_RECURSION_LIMIT: u32 = 100;
+
+    /// The default maximum number of bytes an input will read, 64 MiB
+    pub const DEFAULT_SIZE_LIMIT: usize = 64 << 20;
+
+    /// The size of the chunks length delimited values are read in when the input can't tell how many bytes remain,
+    /// so a value that reports a large length only gets a large allocation if the input actually contains it
+    const READ_CHUNK_SIZE: usize = 64 << 10;
+
+    /// Creates a new CodedInput from the specified Read instance.
+    ///
+    /// The input reads at most [`DEFAULT_SIZE_LIMIT`](#associatedconstant.DEFAULT_SIZE_LIMIT) bytes, 64 MiB,
+    /// unless a different limit is set with [`with_size_limit`](#method.with_size_limit)
     pub fn new(inner: &'a mut dyn Read) -> Self {
         CodedInput {
-            inner,
+            inner: Source::Reader(inner),
+            limit: None,
+            last_tag: None,
+            registry: None,
+            recursion_depth: 0,
+            recursion_limit: Self::DEFAULT_RECURSION_LIMIT,
+            position: 0,
+            size_limit: Self::DEFAULT_SIZE_LIMIT,
+            discard_unknown_fields: false,
+            field_filter: None,
+            field_path: Vec::new(),
+            lenient: false,
+            recovery_stopped: false,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Creates a new CodedInput that reads directly from the specified slice.
+    ///
+    /// The input reads at most [`DEFAULT_SIZE_LIMIT`](#associatedconstant.DEFAULT_SIZE_LIMIT) bytes, 64 MiB,
+    /// unless a different limit is set with [`with_size_limit`](#method.with_size_limit)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::io::CodedInput;
+    ///
+    /// let data = [8, 150, 1, 18, 2, b'h', b'i'];
+    /// let mut input = CodedInput::from_slice(&data);
+    ///
+    /// assert!(input.read_tag()?.is_some());
+    /// assert_eq!(input.read_int32()?, 150);
+    /// assert!(input.read_tag()?.is_some());
+    /// assert_eq!(input.read_str_ref()?, "hi");
+    /// # Ok::<(), protrust::io::InputError>(())
+    /// ```
+    pub fn from_slice(inner: &'a [u8]) -> Self {
+        CodedInput {
+            inner: Source::Slice(inner),
             limit: None,
             last_tag: None,
             registry: None,
+            recursion_depth: 0,
+            recursion_limit: Self::DEFAULT_RECURSION_LIMIT,
+            position: 0,
+            size_limit: Self::DEFAULT_SIZE_LIMIT,
+            discard_unknown_fields: false,
+            field_filter: None,
+            field_path: Vec::new(),
+            lenient: false,
+            recovery_stopped: false,
+            diagnostics: Vec::new(),
         }
     }
 
@@ -459,46 +675,410 @@ impl<'a> CodedInput<'a> {
         self
     }
 
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        if let Some(limit) = self.limit {
-            if limit == 0 {
-                return Ok(0);
+    /// Sets the maximum depth of nested messages and groups this input will read.
+    /// Reading a message or group nested deeper than this returns an error of kind `InputErrorKind::RecursionLimitExceeded`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::{CodedMessage, LiteMessage};
+    /// use protrust::io::{CodedInput, InputErrorKind};
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// // an unknown group containing a group containing an empty group
+    /// let data = [27u8, 27, 27, 28, 28, 28];
+    ///
+    /// let mut timestamp = Timestamp::new();
+    /// assert!(timestamp.merge_from(&mut CodedInput::from_slice(&data).with_recursion_limit(3)).is_ok());
+    ///
+    /// match timestamp.merge_from(&mut CodedInput::from_slice(&data).with_recursion_limit(2)).map_err(|e| e.into_kind()) {
+    ///     Err(InputErrorKind::RecursionLimitExceeded) => { },
+    ///     _ => panic!("expected the recursion limit to be exceeded"),
+    /// }
+    /// ```
+    pub fn with_recursion_limit(mut self, limit: u32) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
+    /// Gets the maximum depth of nested messages and groups this input will read
+    pub fn recursion_limit(&self) -> u32 {
+        self.recursion_limit
+    }
+
+    /// Sets the maximum number of bytes this input will read in total.
+    /// Reading past this returns an error of kind `InputErrorKind::SizeLimitExceeded`.
+    ///
+    /// The limit defaults to [`DEFAULT_SIZE_LIMIT`](#associatedconstant.DEFAULT_SIZE_LIMIT), 64 MiB,
+    /// and covers everything read from the input, including the fields returned by [`events`](#method.events).
+    /// Inputs for larger messages must raise it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::io::{CodedInput, InputErrorKind};
+    ///
+    /// let data = [1u8, 2, 3];
+    ///
+    /// let mut input = CodedInput::from_slice(&data).with_size_limit(2);
+    /// assert_eq!(input.read_uint32().unwrap(), 1);
+    /// assert_eq!(input.read_uint32().unwrap(), 2);
+    /// match input.read_uint32().map_err(|e| e.into_kind()) {
+    ///     Err(InputErrorKind::SizeLimitExceeded) => { },
+    ///     _ => panic!("expected the size limit to be exceeded"),
+    /// }
+    /// ```
+    pub fn with_size_limit(mut self, limit: usize) -> Self {
+        self.size_limit = limit;
+        self
+    }
+
+    /// Gets the maximum number of bytes this input will read in total
+    pub fn size_limit(&self) -> usize {
+        self.size_limit
+    }
+
+    /// Gets the number of bytes this input has read
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Sets whether messages reading from this input skip fields they don't know
+    /// instead of storing them in their unknown fields
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::{CodedMessage, LiteMessage, UnknownFields};
+    /// use protrust::io::CodedInput;
+    /// use protrust::wkt::empty::Empty;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data = [8u8, 1, 18, 1, 2];
+    ///
+    /// let mut empty = Empty::new();
+    /// empty.merge_from(&mut CodedInput::from_slice(&data).with_discard_unknown_fields(true))?;
+    /// assert!(empty.unknown_fields().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_discard_unknown_fields(mut self, discard: bool) -> Self {
+        self.discard_unknown_fields = discard;
+        self
+    }
+
+    /// Gets whether messages reading from this input skip fields they don't know
+    pub fn discard_unknown_fields(&self) -> bool {
+        self.discard_unknown_fields
+    }
+
+    /// Sets the filter of the fields messages read from this input.
+    ///
+    /// Fields that aren't included in the filter are skipped without being read into the message,
+    /// including whole nested messages that aren't included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::{CodedMessage, LiteMessage, Message, UnknownFields};
+    /// use protrust::io::{CodedInput, FieldFilter};
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data = [8, 3, 16, 5];
+    /// let filter = FieldFilter::new(Timestamp::descriptor(), &["seconds"])?;
+    ///
+    /// let timestamp = Timestamp::read_new_from_input(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+    /// assert_eq!(*timestamp.seconds(), 3);
+    /// assert_eq!(*timestamp.nanos(), 0);
+    /// assert!(timestamp.unknown_fields().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_field_filter(mut self, filter: &'a FieldFilter) -> Self {
+        self.field_filter = Some(filter);
+        self
+    }
+
+    /// Gets the filter of the fields messages read from this input, or None if all fields are read
+    pub fn field_filter(&self) -> Option<&'a FieldFilter> {
+        self.field_filter
+    }
+
+    /// Merges a message from the input, recovering from problems in the input where possible instead of returning an error.
+    /// Returns the errors that were recovered from, in the order they occured.
+    ///
+    /// A string field containing invalid UTF8 is skipped and reading continues with the next field.
+    /// A map entry with a key containing invalid UTF8 is dropped.
+    /// Any other recoverable problem in a nested message ends the nested message with the fields read before the problem,
+    /// and reading continues after the nested message. A problem outside of any nested message,
+    /// like a trailing field cut off by the end of the input, ends the message with the fields read before it.
+    ///
+    /// Errors from the underlying `Read` instance and exceeding the size limit aren't recovered from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::{CodedMessage, LiteMessage};
+    /// use protrust::io::CodedInput;
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// // seconds = 5, followed by nanos cut off by the end of the input
+    /// let data = [8, 5, 16, 128];
+    ///
+    /// let mut timestamp = Timestamp::new();
+    /// let diagnostics = CodedInput::from_slice(&data).merge_lenient(&mut timestamp)?;
+    ///
+    /// assert_eq!(*timestamp.seconds(), 5);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].offset(), Some(3));
+    /// # Ok::<(), protrust::io::InputError>(())
+    /// ```
+    pub fn merge_lenient(&mut self, message: &mut dyn CodedMessage) -> InputResult<Vec<InputError>> {
+        let lenient = mem::replace(&mut self.lenient, true);
+        self.recovery_stopped = false;
+        let result =
+            match self.merge_resuming(message) {
+                Err(_) if self.recovery_stopped => Ok(()),
+                Err(e) => self.recover(e),
+                Ok(()) => Ok(()),
+            };
+        self.lenient = lenient;
+        let diagnostics = mem::replace(&mut self.diagnostics, Vec::new());
+        result.map(|()| diagnostics)
+    }
+
+    /// Merges a message from the input. In lenient mode, invalid strings are recorded and reading resumes after them
+    fn merge_resuming(&mut self, message: &mut dyn CodedMessage) -> InputResult<()> {
+        loop {
+            match message.merge_from(self) {
+                Err(e) => {
+                    let resumable =
+                        match e.kind {
+                            InputErrorKind::InvalidString(_) => self.lenient && !self.recovery_stopped,
+                            _ => false,
+                        };
+                    if !resumable {
+                        return Err(e);
+                    }
+                    // the invalid string has been consumed, so merging again continues with the next field
+                    self.diagnostics.push(e);
+                }
+                Ok(()) => return Ok(()),
+            }
+        }
+    }
+
+    /// Records a recoverable error as a diagnostic in lenient mode, otherwise returns the error
+    fn recover(&mut self, error: InputError) -> InputResult<()> {
+        if self.lenient && !self.recovery_stopped && error.kind.is_recoverable() {
+            self.diagnostics.push(error);
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Skips the rest of the input up to the current limit
+    fn skip_to_limit(&mut self) -> InputResult<()> {
+        let mut buf = [0; 256];
+        while let Some(limit) = self.limit.filter(|&l| l > 0) {
+            let len = min(limit as usize, buf.len());
+            self.read_exact(&mut buf[..len])?;
+        }
+        Ok(())
+    }
+
+    /// Creates an error of the specified kind at the current position and field of the input
+    pub(crate) fn error(&self, kind: InputErrorKind) -> InputError {
+        InputError {
+            kind,
+            offset: Some(self.position),
+            field_path: self.field_path.iter().cloned().chain(self.last_tag.map(Tag::number)).collect(),
+        }
+    }
+
+    /// Returns an error if reading the specified number of bytes would exceed the size limit
+    #[inline]
+    fn check_size_limit(&self, len: usize) -> InputResult<()> {
+        if len > self.size_limit - self.position {
+            Err(self.error(InputErrorKind::SizeLimitExceeded))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the rest of the slice up to the current limit if this input reads from a slice
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'a [u8]> {
+        match self.inner {
+            Source::Slice(slice) => {
+                match self.limit {
+                    Some(limit) => Some(&slice[..min(limit as usize, slice.len())]),
+                    None => Some(slice),
+                }
             }
+            Source::Reader(_) => None,
+        }
+    }
 
-            let max = min(buf.len() as i32, limit) as usize;
-            let n = self.inner.read(&mut buf[..max])?;
-            self.limit = Some(limit - n as i32);
-            Ok(n)
+    /// Advances a slice input past the specified number of bytes, which must be in the remaining slice
+    #[inline]
+    fn consume(&mut self, len: usize) {
+        if let Source::Slice(ref mut slice) = self.inner {
+            *slice = &slice[len..];
+        }
+        if let Some(ref mut limit) = self.limit {
+            *limit -= len as i32;
+        }
+        self.position += len;
+    }
+
+    /// Takes the specified number of bytes from a slice input
+    #[inline]
+    fn take_slice(&mut self, slice: &'a [u8], len: usize) -> InputResult<&'a [u8]> {
+        self.check_size_limit(len)?;
+        if slice.len() < len {
+            Err(self.error(unexpected_eof()))
         } else {
-            self.inner.read(buf)
+            self.consume(len);
+            Ok(&slice[..len])
         }
     }
 
-    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+    /// Reads a varint from a slice input without going through `Read`
+    #[inline]
+    fn read_slice_varint(&mut self, slice: &'a [u8]) -> InputResult<u64> {
+        let mut result = 0u64;
+        for (index, &byte) in slice.iter().take(10).enumerate() {
+            result |= u64::from(byte & 0x7F) << (7 * index);
+            if (byte & 0x80) == 0 {
+                self.check_size_limit(index + 1)?;
+                self.consume(index + 1);
+                return Ok(result);
+            }
+        }
+        if slice.len() < 10 {
+            Err(self.error(unexpected_eof()))
+        } else {
+            Err(self.error(InputErrorKind::MalformedVarint))
+        }
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> InputResult<usize> {
+        if buf.is_empty() || self.limit == Some(0) {
+            return Ok(0);
+        }
+        // the input can only end at the size limit, any bytes past it exceed the limit
+        let remaining = self.size_limit - self.position;
+        if remaining == 0 {
+            return if self.read_past_end()? { Err(self.error(InputErrorKind::SizeLimitExceeded)) } else { Ok(0) };
+        }
+        let max = min(buf.len(), remaining);
+
+        if let Some(slice) = self.remaining_slice() {
+            let n = min(max, slice.len());
+            buf[..n].copy_from_slice(&slice[..n]);
+            self.consume(n);
+            return Ok(n);
+        }
+
+        let inner =
+            match self.inner {
+                Source::Reader(ref mut inner) => inner,
+                Source::Slice(_) => unreachable!(),
+            };
+        let max = self.limit.map_or(max, |limit| min(max, limit as usize));
+        let n =
+            match inner.read(&mut buf[..max]) {
+                Ok(n) => n,
+                Err(e) => return Err(self.error(InputErrorKind::IoError(e))),
+            };
+        if let Some(ref mut limit) = self.limit {
+            *limit -= n as i32;
+        }
+        self.position += n;
+        Ok(n)
+    }
+
+    /// Checks if the input contains any more bytes, reading one byte from it if it does
+    fn read_past_end(&mut self) -> InputResult<bool> {
+        match self.inner {
+            Source::Slice(slice) => Ok(!slice.is_empty()),
+            Source::Reader(ref mut inner) => {
+                match inner.read(&mut [0u8]) {
+                    Ok(n) => Ok(n != 0),
+                    Err(e) => Err(self.error(InputErrorKind::IoError(e))),
+                }
+            }
+        }
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> InputResult<()> {
+        self.check_size_limit(buf.len())?;
+        if let Some(slice) = self.remaining_slice() {
+            if slice.len() < buf.len() {
+                return Err(self.error(unexpected_eof()));
+            }
+            buf.copy_from_slice(&slice[..buf.len()]);
+            self.consume(buf.len());
+            return Ok(());
+        }
+
+        let inner =
+            match self.inner {
+                Source::Reader(ref mut inner) => inner,
+                Source::Slice(_) => unreachable!(),
+            };
         if let Some(limit) = self.limit {
             if buf.len() > limit as usize {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::UnexpectedEof,
-                    "the input ended in the middle of a field",
-                ))
-            } else {
-                self.limit = Some(limit - buf.len() as i32);
-                self.inner.read_exact(buf)
+                return Err(self.error(unexpected_eof()));
             }
-        } else {
-            self.inner.read_exact(buf)
         }
+        if let Err(e) = inner.read_exact(buf) {
+            return Err(self.error(InputErrorKind::IoError(e)));
+        }
+        if let Some(ref mut limit) = self.limit {
+            *limit -= buf.len() as i32;
+        }
+        self.position += buf.len();
+        Ok(())
     }
 
-    pub(crate) fn read_length(&mut self) -> InputResult<i32> {
+    /// Reads the length of a length delimited value from the input
+    pub fn read_length(&mut self) -> InputResult<i32> {
         let length = self.read_int32()?;
         if length < 0 {
-            Err(InputError::NegativeSize)
+            Err(self.error(InputErrorKind::NegativeSize))
         } else {
             Ok(length)
         }
     }
 
+    /// Reads the length of a length delimited value from the input, or returns None if the input ended before it
+    pub(crate) fn read_length_or_end(&mut self) -> InputResult<Option<i32>> {
+        let mut buf = [0u8; 1];
+        if self.read(&mut buf)? == 0 {
+            return Ok(None);
+        }
+        let mut result = u64::from(buf[0] & 0x7F);
+        let mut shift = 7;
+        while (buf[0] & 0x80) != 0 {
+            if shift >= 70 {
+                return Err(self.error(InputErrorKind::MalformedVarint));
+            }
+            self.read_exact(&mut buf)?;
+            result |= u64::from(buf[0] & 0x7F) << shift;
+            shift += 7;
+        }
+        let length = result as i32;
+        if length < 0 {
+            Err(self.error(InputErrorKind::NegativeSize))
+        } else {
+            Ok(Some(length))
+        }
+    }
+
     pub(crate) fn registry(&self) -> Option<&'static crate::ExtensionRegistry> {
         self.registry
     }
@@ -507,26 +1087,59 @@ impl<'a> CodedInput<'a> {
         self.last_tag
     }
 
-    pub(crate) fn push_limit(&mut self, limit: i32) -> Option<i32> {
-        let old = {
-            if let Some(existing) = self.limit {
-                Some(existing - limit)
-            } else {
-                None
-            }
-        };
+    /// Limits the input to the specified number of bytes from the current position,
+    /// returning the previous limit which should be restored with [`pop_limit`](#method.pop_limit)
+    /// once the limited value has been read.
+    ///
+    /// Returns an error if the limit is negative or extends past the end of the current limit,
+    /// since the limited value would then be cut off by the end of its enclosing value
+    pub fn push_limit(&mut self, limit: i32) -> InputResult<Option<i32>> {
+        if limit < 0 {
+            return Err(self.error(InputErrorKind::NegativeSize));
+        }
+        let old =
+            match self.limit {
+                Some(existing) if limit > existing => return Err(self.error(unexpected_eof())),
+                Some(existing) => Some(existing - limit),
+                None => None,
+            };
         self.limit = Some(limit);
-        old
+        Ok(old)
     }
 
-    pub(crate) fn reached_limit(&self) -> bool {
+    /// Returns whether the input has read every byte up to the current limit
+    pub fn reached_limit(&self) -> bool {
         self.limit == Some(0)
     }
 
-    pub(crate) fn pop_limit(&mut self, previous: Option<i32>) {
+    /// Restores a limit returned by [`push_limit`](#method.push_limit)
+    pub fn pop_limit(&mut self, previous: Option<i32>) {
         mem::replace(&mut self.limit, previous);
     }
 
+    /// Runs the function one level of nesting deeper in the input,
+    /// returning an error instead if that would exceed the recursion limit.
+    /// If the input has a field filter, the function is run with the filter of the last read field
+    pub(crate) fn recurse<T>(&mut self, f: impl FnOnce(&mut Self) -> InputResult<T>) -> InputResult<T> {
+        if self.recursion_depth >= self.recursion_limit {
+            return Err(self.error(InputErrorKind::RecursionLimitExceeded));
+        }
+        let tag = self.last_tag;
+        let filter = self.field_filter;
+        if let (Some(filter), Some(tag)) = (filter, tag) {
+            self.field_filter = filter.nested(tag.number());
+        }
+        self.field_path.extend(tag.map(Tag::number));
+        self.recursion_depth += 1;
+        let result = f(self).map_err(|e| e.located(&self.field_path, self.position));
+        self.recursion_depth -= 1;
+        if tag.is_some() {
+            self.field_path.pop();
+        }
+        self.field_filter = filter;
+        result
+    }
+
     pub(crate) fn skip(&mut self, tag: Tag) -> InputResult<()> {
         match tag.wire_type() {
             WireType::Varint => {
@@ -540,23 +1153,31 @@ impl<'a> CodedInput<'a> {
             }
             WireType::StartGroup => {
                 let end = Tag::new(tag.number(), WireType::EndGroup);
-                while let Some(tag) = self.read_tag()? {
-                    if tag == end {
-                        break;
-                    } else {
-                        self.skip(tag)?;
+                self.recurse(|input| {
+                    while let Some(tag) = input.read_tag()? {
+                        if tag == end {
+                            break;
+                        } else {
+                            input.skip(tag)?;
+                        }
                     }
-                }
+                    Ok(())
+                })?;
             }
             WireType::Bit32 => {
                 self.read_fixed32()?;
             }
-            WireType::EndGroup => return Err(InputError::InvalidTag(tag.get())),
+            WireType::EndGroup => return Err(self.error(InputErrorKind::InvalidTag(tag.get()))),
         }
 
         Ok(())
     }
 
+    /// Returns an iterator over the raw fields in the rest of the input
+    pub fn events(&mut self) -> WireEvents<'a, '_> {
+        WireEvents { input: self, groups: Vec::new(), done: false }
+    }
+
     /// Reads a bool value from the input
     pub fn read_bool(&mut self) -> InputResult<bool> {
         Ok(self.read_uint32()? != 0)
@@ -564,17 +1185,25 @@ impl<'a> CodedInput<'a> {
     /// Reads a message from the input, merging it with an existing coded message
     pub fn read_message(&mut self, message: &mut dyn CodedMessage) -> InputResult<()> {
         let len = self.read_length()?;
-        let old = self.push_limit(len);
-        message.merge_from(self)?;
-        if !self.reached_limit() {
-            Err(InputError::IoError(std::io::Error::new(
-                std::io::ErrorKind::UnexpectedEof,
-                "the input ended in the middle of a field",
-            )))
-        } else {
-            self.pop_limit(old);
-            Ok(())
-        }
+        let old = self.push_limit(len)?;
+        let result =
+            self.recurse(|input| {
+                input.merge_resuming(message)?;
+                if input.reached_limit() {
+                    Ok(())
+                } else {
+                    Err(input.error(unexpected_eof()))
+                }
+            })
+            .or_else(|e| {
+                self.recover(e)?;
+                let result = self.skip_to_limit();
+                // the rest of the nested message couldn't be skipped, so enclosing messages can't recover either
+                self.recovery_stopped = result.is_err();
+                result
+            });
+        self.pop_limit(old);
+        result
     }
     #[inline(always)]
     #[doc(hidden)]
@@ -583,31 +1212,66 @@ impl<'a> CodedInput<'a> {
     }
     /// Reads a group message from the input, merging it with an existing coded message
     pub fn read_group(&mut self, message: &mut dyn CodedMessage) -> InputResult<()> {
-        message.merge_from(self)
+        self.recurse(|input| input.merge_resuming(message))
     }
     /// Reads a length delimited `bytes` value from the input
     pub fn read_bytes(&mut self) -> InputResult<Vec<u8>> {
         let len = self.read_length()? as usize;
-        let mut buf = Vec::with_capacity(len);
-        unsafe {
-            buf.set_len(len);
+        if let Some(slice) = self.remaining_slice() {
+            return Ok(self.take_slice(slice, len)?.to_vec());
+        }
+        self.check_size_limit(len)?;
+        let mut buf = Vec::new();
+        while buf.len() < len {
+            let start = buf.len();
+            buf.resize(start + min(len - start, Self::READ_CHUNK_SIZE), 0);
+            self.read_exact(&mut buf[start..])?;
         }
-        self.read_exact(&mut buf)?;
         Ok(buf)
     }
     /// Reads a length delimited `string` value from the input
     pub fn read_string(&mut self) -> InputResult<String> {
         let bytes = self.read_bytes()?;
-        Ok(String::from_utf8(bytes)?)
+        String::from_utf8(bytes).map_err(|e| self.error(InputErrorKind::InvalidString(e)))
+    }
+    /// Reads a length delimited `bytes` value from the input, borrowing it from the input if it reads from a slice
+    pub fn read_bytes_ref(&mut self) -> InputResult<Cow<'a, [u8]>> {
+        if let Source::Slice(_) = self.inner {
+            let len = self.read_length()? as usize;
+            let slice = self.remaining_slice().unwrap_or_default();
+            Ok(Cow::Borrowed(self.take_slice(slice, len)?))
+        } else {
+            self.read_bytes().map(Cow::Owned)
+        }
+    }
+    /// Reads a length delimited `string` value from the input, borrowing it from the input if it reads from a slice
+    pub fn read_str_ref(&mut self) -> InputResult<Cow<'a, str>> {
+        match self.read_bytes_ref()? {
+            Cow::Borrowed(bytes) => {
+                match std::str::from_utf8(bytes) {
+                    Ok(value) => Ok(Cow::Borrowed(value)),
+                    Err(_) => Err(self.error(InputErrorKind::InvalidString(String::from_utf8(bytes.to_vec()).unwrap_err()))),
+                }
+            }
+            Cow::Owned(bytes) => String::from_utf8(bytes).map(Cow::Owned).map_err(|e| self.error(InputErrorKind::InvalidString(e))),
+        }
     }
     /// Reads a `fixed32` value from the input
     pub fn read_fixed32(&mut self) -> InputResult<u32> {
+        if let Some(slice) = self.remaining_slice() {
+            let bytes = self.take_slice(slice, 4)?;
+            return Ok(u32::from_le_bytes(bytes.try_into().unwrap()));
+        }
         let mut buf = [0u8; 4];
         self.read_exact(&mut buf)?;
         Ok(u32::from_le_bytes(buf))
     }
     /// Reads an `sfixed32` value from the input
     pub fn read_sfixed32(&mut self) -> InputResult<i32> {
+        if let Some(slice) = self.remaining_slice() {
+            let bytes = self.take_slice(slice, 4)?;
+            return Ok(i32::from_le_bytes(bytes.try_into().unwrap()));
+        }
         let mut buf = [0u8; 4];
         self.read_exact(&mut buf)?;
         Ok(i32::from_le_bytes(buf))
@@ -618,12 +1282,20 @@ impl<'a> CodedInput<'a> {
     }
     /// Reads a `fixed64` value from the input
     pub fn read_fixed64(&mut self) -> InputResult<u64> {
+        if let Some(slice) = self.remaining_slice() {
+            let bytes = self.take_slice(slice, 8)?;
+            return Ok(u64::from_le_bytes(bytes.try_into().unwrap()));
+        }
         let mut buf = [0u8; 8];
         self.read_exact(&mut buf)?;
         Ok(u64::from_le_bytes(buf))
     }
     /// Reads an `sfixed64` value from the input
     pub fn read_sfixed64(&mut self) -> InputResult<i64> {
+        if let Some(slice) = self.remaining_slice() {
+            let bytes = self.take_slice(slice, 8)?;
+            return Ok(i64::from_le_bytes(bytes.try_into().unwrap()));
+        }
         let mut buf = [0u8; 8];
         self.read_exact(&mut buf)?;
         Ok(i64::from_le_bytes(buf))
@@ -652,6 +1324,9 @@ impl<'a> CodedInput<'a> {
     }
     /// Reads a `uint32` value from the input
     pub fn read_uint32(&mut self) -> InputResult<u32> {
+        if let Some(slice) = self.remaining_slice() {
+            return Ok(self.read_slice_varint(slice)? as u32);
+        }
         let mut shift = 0u32;
         let mut result = 0u32;
         let mut buf = [0u8; 1];
@@ -670,10 +1345,13 @@ impl<'a> CodedInput<'a> {
             }
             shift += 7;
         }
-        Err(InputError::MalformedVarint)
+        Err(self.error(InputErrorKind::MalformedVarint))
     }
     /// Reads a `uint64` value from the input
     pub fn read_uint64(&mut self) -> InputResult<u64> {
+        if let Some(slice) = self.remaining_slice() {
+            return self.read_slice_varint(slice);
+        }
         let mut shift = 0u32;
         let mut result = 0u64;
         let mut buf = [0u8; 1];
@@ -685,10 +1363,36 @@ impl<'a> CodedInput<'a> {
             }
             shift += 7;
         }
-        Err(InputError::MalformedVarint)
+        Err(self.error(InputErrorKind::MalformedVarint))
     }
-    /// Reads a tag from the input
+    /// Reads a tag from the input.
+    /// If the input has a field filter, fields that aren't included in the filter are skipped
     pub fn read_tag(&mut self) -> InputResult<Option<Tag>> {
+        loop {
+            let tag = self.read_next_tag()?;
+            match (tag, self.field_filter) {
+                (Some(tag), Some(filter)) if tag.wire_type() != WireType::EndGroup && !filter.contains(tag.number()) => {
+                    self.skip(tag)?;
+                }
+                _ => return Ok(tag),
+            }
+        }
+    }
+    fn read_next_tag(&mut self) -> InputResult<Option<Tag>> {
+        // errors while reading a tag don't occur in a field
+        self.last_tag = None;
+        if let Some(slice) = self.remaining_slice() {
+            if slice.is_empty() {
+                self.last_tag = None;
+                return Ok(None);
+            }
+            let result = self.read_slice_varint(slice)? as u32;
+            self.last_tag = Tag::new_from_raw(result);
+            return match self.last_tag {
+                Some(tag) => Ok(Some(tag)),
+                None => Err(self.error(InputErrorKind::InvalidTag(result))),
+            };
+        }
         let mut shift = 0u32;
         let mut result = 0u32;
         let mut buf = [0u8; 1];
@@ -710,7 +1414,7 @@ impl<'a> CodedInput<'a> {
                 return match Tag::new_from_raw(result) {
                     None => {
                         self.last_tag = None;
-                        Err(InputError::InvalidTag(result))
+                        Err(self.error(InputErrorKind::InvalidTag(result)))
                     }
                     tag => {
                         self.last_tag = tag;
@@ -718,32 +1422,336 @@ impl<'a> CodedInput<'a> {
                     }
                 };
             }
-            shift += 7;
+            shift += 7;
+        }
+        while shift < 64 {
+            self.read_exact(&mut buf)?;
+            if (buf[0] & 0x80) == 0 {
+                return match Tag::new_from_raw(result) {
+                    None => {
+                        self.last_tag = None;
+                        Err(self.error(InputErrorKind::InvalidTag(result)))
+                    }
+                    tag => {
+                        self.last_tag = tag;
+                        Ok(tag)
+                    }
+                };
+            }
+            shift += 7;
+        }
+        Err(self.error(InputErrorKind::MalformedVarint))
+    }
+    /// Reads an enum value from the input
+    pub fn read_enum_value<E: crate::Enum>(&mut self) -> InputResult<crate::EnumValue<E>> {
+        self.read_int32().map(crate::EnumValue::from)
+    }
+}
+
+/// A filter of the fields read by a [`CodedInput`](struct.CodedInput.html), created from a list of field paths.
+///
+/// Each path is a list of field names separated by dots, like the paths in a `FieldMask`.
+/// A path includes the last field it names along with everything nested in it,
+/// while the fields before the last must be message fields and only include the fields nested in them that are named by other paths.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldFilter {
+    fields: HashMap<FieldNumber, Option<FieldFilter>>,
+}
+
+impl FieldFilter {
+    /// Creates a new filter that includes the fields of the message type named by the specified paths
+    #[cfg(feature = "reflection")]
+    pub fn new<'a, P: AsRef<str>>(descriptor: &MessageDescriptor<'a>, paths: impl IntoIterator<Item = P>) -> Result<FieldFilter, InvalidFieldPath> {
+        let mut filter = FieldFilter::default();
+        for path in paths {
+            let path = path.as_ref();
+            if filter.add_path(descriptor, path).is_none() {
+                return Err(InvalidFieldPath(path.to_string()));
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Creates a new filter that includes the fields of the message type named by the paths in the field mask
+    #[cfg(feature = "reflection")]
+    pub fn from_field_mask<'a>(descriptor: &MessageDescriptor<'a>, mask: &crate::wkt::field_mask::FieldMask) -> Result<FieldFilter, InvalidFieldPath> {
+        FieldFilter::new(descriptor, mask.paths().iter())
+    }
+
+    #[cfg(feature = "reflection")]
+    fn add_path<'a>(&mut self, descriptor: &MessageDescriptor<'a>, path: &str) -> Option<()> {
+        let mut filter = self;
+        let mut descriptor = descriptor;
+        let mut names = path.split('.').peekable();
+        while let Some(name) = names.next() {
+            let field = descriptor.find_field_by_name(name)?;
+            if names.peek().is_none() {
+                filter.fields.insert(field.number(), None);
+                break;
+            }
+            descriptor =
+                match field.field_type() {
+                    FieldType::Message(message) | FieldType::Group(message) if !message.is_map_entry() => message,
+                    _ => return None,
+                };
+            match filter.fields.entry(field.number()).or_insert_with(|| Some(FieldFilter::default())) {
+                Some(nested) => filter = nested,
+                None => break,
+            }
         }
-        while shift < 64 {
-            self.read_exact(&mut buf)?;
-            if (buf[0] & 0x80) == 0 {
-                return match Tag::new_from_raw(result) {
-                    None => {
-                        self.last_tag = None;
-                        Err(InputError::InvalidTag(result))
+        Some(())
+    }
+
+    /// Returns whether the filter includes the field with the specified number
+    pub fn contains(&self, number: FieldNumber) -> bool {
+        self.fields.contains_key(&number)
+    }
+
+    /// Gets the filter of the fields nested in the field with the specified number,
+    /// or None if all the field's nested fields are included
+    pub fn nested(&self, number: FieldNumber) -> Option<&FieldFilter> {
+        self.fields.get(&number).and_then(Option::as_ref)
+    }
+}
+
+/// The error returned when a field path given to a [`FieldFilter`](struct.FieldFilter.html) doesn't name a field
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidFieldPath(pub(crate) String);
+
+impl InvalidFieldPath {
+    /// Gets the invalid path
+    pub fn path(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for InvalidFieldPath {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "the path '{}' doesn't name a field of the message", self.0)
+    }
+}
+
+impl Error for InvalidFieldPath {}
+
+/// A raw field value read from the input without a schema
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RawValue<'a> {
+    /// A varint value
+    Varint(u64),
+    /// A 64-bit fixed value
+    Bit64(u64),
+    /// The bytes of a length delimited value. Inputs reading from a slice borrow the bytes from it
+    LengthDelimited(Cow<'a, [u8]>),
+    /// A 32-bit fixed value
+    Bit32(u32),
+}
+
+impl RawValue<'_> {
+    /// Gets the wire type the value was read with
+    pub fn wire_type(&self) -> WireType {
+        match self {
+            RawValue::Varint(_) => WireType::Varint,
+            RawValue::Bit64(_) => WireType::Bit64,
+            RawValue::LengthDelimited(_) => WireType::LengthDelimited,
+            RawValue::Bit32(_) => WireType::Bit32,
+        }
+    }
+}
+
+/// An event read from the input by [`WireEvents`](struct.WireEvents.html)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WireEvent<'a> {
+    /// A field with a value
+    Field(FieldNumber, RawValue<'a>),
+    /// The start of a group. The events up to the matching `EndGroup` are the fields of the group
+    StartGroup(FieldNumber),
+    /// The end of a group
+    EndGroup(FieldNumber),
+}
+
+impl WireEvent<'_> {
+    /// Gets the field number of the event
+    pub fn number(&self) -> FieldNumber {
+        match self {
+            WireEvent::Field(number, _) | WireEvent::StartGroup(number) | WireEvent::EndGroup(number) => *number,
+        }
+    }
+
+    /// Gets the wire type of the event's tag
+    pub fn wire_type(&self) -> WireType {
+        match self {
+            WireEvent::Field(_, value) => value.wire_type(),
+            WireEvent::StartGroup(_) => WireType::StartGroup,
+            WireEvent::EndGroup(_) => WireType::EndGroup,
+        }
+    }
+}
+
+/// An iterator over the raw fields in an input, which reads the input without a schema.
+///
+/// Groups are checked to be closed by the end of the same field and not nested deeper than the input's recursion limit.
+/// Length delimited values aren't read any further, so a nested message can be read by iterating over the events of a new input
+/// reading the value's bytes. If an error occurs, the iterator returns it and ends.
+///
+/// # Example
+///
+/// ```
+/// use protrust::io::{CodedInput, FieldNumber, RawValue, WireEvent};
+///
+/// # fn main() -> protrust::io::InputResult<()> {
+/// let data = [8, 150, 1, 19, 18, 2, b'h', b'i', 20];
+/// let mut input = CodedInput::from_slice(&data);
+/// let events = input.events().collect::<Result<Vec<_>, _>>()?;
+///
+/// let number = |n| FieldNumber::new(n).unwrap();
+/// assert_eq!(events, [
+///     WireEvent::Field(number(1), RawValue::Varint(150)),
+///     WireEvent::StartGroup(number(2)),
+///     WireEvent::Field(number(2), RawValue::LengthDelimited(b"hi".as_ref().into())),
+///     WireEvent::EndGroup(number(2)),
+/// ]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct WireEvents<'a, 'b> {
+    input: &'b mut CodedInput<'a>,
+    groups: Vec<FieldNumber>,
+    done: bool,
+}
+
+impl<'a> WireEvents<'a, '_> {
+    fn read_next(&mut self) -> InputResult<Option<WireEvent<'a>>> {
+        let tag =
+            match self.input.read_tag()? {
+                Some(tag) => tag,
+                None if self.groups.is_empty() => return Ok(None),
+                None => return Err(self.input.error(unexpected_eof())),
+            };
+        let number = tag.number();
+        let event =
+            match tag.wire_type() {
+                WireType::Varint => WireEvent::Field(number, RawValue::Varint(self.input.read_uint64()?)),
+                WireType::Bit64 => WireEvent::Field(number, RawValue::Bit64(self.input.read_fixed64()?)),
+                WireType::LengthDelimited => WireEvent::Field(number, RawValue::LengthDelimited(self.input.read_bytes_ref()?)),
+                WireType::StartGroup => {
+                    if self.groups.len() >= self.input.recursion_limit() as usize {
+                        return Err(self.input.error(InputErrorKind::RecursionLimitExceeded));
                     }
-                    tag => {
-                        self.last_tag = tag;
-                        Ok(tag)
+                    self.groups.push(number);
+                    WireEvent::StartGroup(number)
+                }
+                WireType::EndGroup => {
+                    if self.groups.last() != Some(&number) {
+                        return Err(self.input.error(InputErrorKind::InvalidTag(tag.get())));
                     }
-                };
+                    self.groups.pop();
+                    WireEvent::EndGroup(number)
+                }
+                WireType::Bit32 => WireEvent::Field(number, RawValue::Bit32(self.input.read_fixed32()?)),
+            };
+        Ok(Some(event))
+    }
+}
+
+impl<'a> Iterator for WireEvents<'a, '_> {
+    type Item = InputResult<WireEvent<'a>>;
+
+    fn next(&mut self) -> Option<InputResult<WireEvent<'a>>> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_next().transpose();
+        if let None | Some(Err(_)) = result {
+            self.done = true;
+        }
+        result
+    }
+}
+
+impl std::iter::FusedIterator for WireEvents<'_, '_> {}
+
+/// An iterator over a stream of length delimited messages, such as the messages written by
+/// [`LiteMessage::write_delimited`](../trait.LiteMessage.html#method.write_delimited).
+///
+/// Each message is read with a new [`CodedInput`](struct.CodedInput.html), so the input's limits apply to each message
+/// individually instead of the whole stream. Messages are limited to
+/// [`CodedInput::DEFAULT_SIZE_LIMIT`](struct.CodedInput.html#associatedconstant.DEFAULT_SIZE_LIMIT) bytes
+/// unless a different limit is set with [`with_size_limit`](#method.with_size_limit). The iterator ends when the stream ends before the length of the next message.
+/// If the stream ends anywhere else, or a message can't be read, the iterator returns the error and ends.
+///
+/// # Example
+///
+/// ```
+/// use protrust::LiteMessage;
+/// use protrust::io::DelimitedMessages;
+/// use protrust::wkt::timestamp::Timestamp;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data = [2, 8, 3, 0, 2, 16, 5];
+/// let mut input = data.as_ref();
+/// let messages = DelimitedMessages::<Timestamp>::new(&mut input).collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(messages.len(), 3);
+/// assert_eq!(*messages[0].seconds(), 3);
+/// assert_eq!(messages[1], Timestamp::new());
+/// assert_eq!(*messages[2].nanos(), 5);
+/// # Ok(())
+/// # }
+/// ```
+pub struct DelimitedMessages<'a, T> {
+    inner: &'a mut dyn Read,
+    size_limit: usize,
+    done: bool,
+    marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, T: crate::LiteMessage> DelimitedMessages<'a, T> {
+    /// Creates a new iterator over the messages in the specified Read instance
+    pub fn new(inner: &'a mut dyn Read) -> Self {
+        DelimitedMessages { inner, size_limit: CodedInput::DEFAULT_SIZE_LIMIT, done: false, marker: std::marker::PhantomData }
+    }
+
+    /// Sets the maximum number of bytes read for each message, including its length
+    pub fn with_size_limit(mut self, limit: usize) -> Self {
+        self.size_limit = limit;
+        self
+    }
+
+    fn read_next(&mut self) -> InputResult<Option<T>> {
+        let mut input = CodedInput::new(&mut *self.inner).with_size_limit(self.size_limit);
+        match input.read_length_or_end()? {
+            Some(len) => {
+                let mut message = T::new();
+                input.push_limit(len)?;
+                input.recurse(|input| message.merge_from(input))?;
+                if input.reached_limit() {
+                    Ok(Some(message))
+                } else {
+                    Err(input.error(unexpected_eof()))
+                }
             }
-            shift += 7;
+            None => Ok(None),
         }
-        Err(InputError::MalformedVarint)
     }
-    /// Reads an enum value from the input
-    pub fn read_enum_value<E: crate::Enum>(&mut self) -> InputResult<crate::EnumValue<E>> {
-        self.read_int32().map(crate::EnumValue::from)
+}
+
+impl<T: crate::LiteMessage> Iterator for DelimitedMessages<'_, T> {
+    type Item = InputResult<T>;
+
+    fn next(&mut self) -> Option<InputResult<T>> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_next().transpose();
+        if let None | Some(Err(_)) = result {
+            self.done = true;
+        }
+        result
     }
 }
 
+impl<T: crate::LiteMessage> std::iter::FusedIterator for DelimitedMessages<'_, T> {}
+
 /// The error of an [OutputResult](#OutputResult)
 #[derive(Debug)]
 pub enum OutputError {
@@ -782,14 +1790,60 @@ impl Error for OutputError {
 pub type OutputResult = Result<(), OutputError>;
 
 /// A protocol buffers output stream
+///
+/// When a message value is written, its size is calculated once, which caches the sizes of its nested messages.
+/// The cached sizes are then reused as the nested messages are written, so each message in a tree is only sized once.
 pub struct CodedOutput<'a> {
     inner: &'a mut dyn Write,
+    sizes_cached: bool,
+    deterministic: bool,
 }
 
 impl<'a> CodedOutput<'a> {
     /// Creates a new CodedOutput using the specified Write object
     pub fn new(inner: &'a mut dyn Write) -> Self {
-        CodedOutput { inner }
+        CodedOutput { inner, sizes_cached: false, deterministic: false }
+    }
+
+    /// Creates a new CodedOutput that writes the nested messages of a message using their cached sizes.
+    /// The size of the message must be calculated right before it's written
+    pub(crate) fn with_cached_sizes(inner: &'a mut dyn Write) -> Self {
+        CodedOutput { inner, sizes_cached: true, deterministic: false }
+    }
+
+    /// Sets whether this output writes messages deterministically.
+    ///
+    /// By default, map entries, extensions, and unknown fields are written in the order they're stored,
+    /// which can differ between equal messages. A deterministic output writes map entries sorted by key
+    /// and extensions and unknown fields sorted by field number, so equal messages are always written as the same bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::{CodedMessage, LiteMessage};
+    /// use protrust::io::CodedOutput;
+    /// use protrust::wkt::r#struct::{Struct, Value};
+    ///
+    /// # fn main() -> protrust::io::OutputResult {
+    /// let mut value = Struct::new();
+    /// for key in &["b", "c", "a"] {
+    ///     value.fields_mut().insert(key.to_string(), Value::new());
+    /// }
+    ///
+    /// let mut output = Vec::new();
+    /// value.write_to(&mut CodedOutput::new(&mut output).with_deterministic(true))?;
+    /// assert_eq!(output, [10, 5, 10, 1, b'a', 18, 0, 10, 5, 10, 1, b'b', 18, 0, 10, 5, 10, 1, b'c', 18, 0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Gets whether this output writes messages deterministically
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
     }
 
     #[doc(hidden)]
@@ -815,19 +1869,32 @@ impl<'a> CodedOutput<'a> {
     /// Writes a message value to the output
     #[cfg(checked_size)]
     pub fn write_message(&mut self, value: &dyn CodedMessage) -> OutputResult {
-        if let Some(len) = value.calculate_size() {
-            self.write_int32(len)?;
-            value.write_to(self)
-        } else {
-            Err(OutputError::ValueTooLarge)
-        }
+        let len =
+            match value.cached_size() {
+                Some(len) if self.sizes_cached => len,
+                _ => value.calculate_size().ok_or(OutputError::ValueTooLarge)?,
+            };
+        self.write_sized_message(value, len)
     }
 
     /// Writes a message value to the output
     #[cfg(not(checked_size))]
     pub fn write_message(&mut self, value: &dyn CodedMessage) -> OutputResult {
-        self.write_int32(value.calculate_size())?;
-        value.write_to(self)
+        let len =
+            match value.cached_size() {
+                Some(len) if self.sizes_cached => len,
+                _ => value.calculate_size(),
+            };
+        self.write_sized_message(value, len)
+    }
+
+    /// Writes a message with its size. Calculating the size cached the sizes of its nested messages,
+    /// so they're reused while it's written
+    fn write_sized_message(&mut self, value: &dyn CodedMessage, len: i32) -> OutputResult {
+        let outer = mem::replace(&mut self.sizes_cached, true);
+        let result = self.write_int32(len).and_then(|_| value.write_to(self));
+        self.sizes_cached = outer;
+        result
     }
 
     #[inline(always)]
@@ -952,7 +2019,10 @@ impl<'a> CodedOutput<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::{CodedInput, CodedOutput, FieldNumber, InputError, InputResult, OutputResult, Tag};
+    use super::{CodedInput, CodedOutput, FieldNumber, InputError, InputErrorKind, InputResult, OutputResult, RawValue, Tag, WireEvent};
+    use crate::{CachedSize, CodedMessage};
+    use std::borrow::Cow;
+    use std::cell::Cell;
     use std::fmt::Debug;
 
     #[test]
@@ -1017,6 +2087,16 @@ mod tests {
 
         assert_eq!(returned_values, values);
 
+        let mut input = CodedInput::from_slice(&coded_vec);
+        returned_values.clear();
+
+        for _ in 0..values.len() {
+            returned_values.push_back(read(&mut input)?);
+        }
+
+        assert_eq!(returned_values, values);
+        assert!(input.read_tag()?.is_none());
+
         Ok(())
     }
 
@@ -1175,8 +2255,8 @@ mod tests {
         let mut slice: &[u8] = &data;
         let mut input = CodedInput::new(&mut slice);
 
-        match input.read_int32() {
-            Err(InputError::MalformedVarint) => {}
+        match input.read_int32().map_err(InputError::into_kind) {
+            Err(InputErrorKind::MalformedVarint) => {}
             _ => assert!(false),
         }
     }
@@ -1191,12 +2271,16 @@ mod tests {
         let mut input = CodedInput::new(&mut slice);
 
         match input.read_bytes() {
-            Err(InputError::NegativeSize) => Ok(()),
+            Err(e) => {
+                match e.kind() {
+                    InputErrorKind::NegativeSize => Ok(()),
+                    _ => Err(Box::new(e)),
+                }
+            }
             Ok(_) => {
                 assert!(false, "read_bytes returned true");
                 unreachable!()
             }
-            Err(e) => Err(Box::new(e)),
         }
     }
 
@@ -1206,8 +2290,8 @@ mod tests {
         let mut slice: &[u8] = &data;
         let mut input = CodedInput::new(&mut slice);
 
-        match input.read_tag() {
-            Err(InputError::InvalidTag(0)) => {}
+        match input.read_tag().map_err(InputError::into_kind) {
+            Err(InputErrorKind::InvalidTag(0)) => {}
             _ => assert!(false, "read_tag didn't return an invalid tag error"),
         }
     }
@@ -1230,8 +2314,8 @@ mod tests {
         let mut slice: &[u8] = &data;
         let mut input = CodedInput::new(&mut slice);
 
-        match input.read_tag() {
-            Err(InputError::IoError(_)) => {}
+        match input.read_tag().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(_)) => {}
             _ => assert!(false, "read_tag didn't error out"),
         }
     }
@@ -1243,9 +2327,374 @@ mod tests {
         let mut input = CodedInput::new(&mut slice);
         input.limit = Some(1);
 
-        match input.read_uint64() {
-            Err(InputError::IoError(_)) => {}
+        match input.read_uint64().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(_)) => {}
             _ => assert!(false, "read_uint64 didn't error out"),
         }
     }
+
+    #[test]
+    fn slice_malformed_varint_returns_err() {
+        let data = [255; 11];
+        let mut input = CodedInput::from_slice(&data);
+
+        match input.read_uint64().map_err(InputError::into_kind) {
+            Err(InputErrorKind::MalformedVarint) => {}
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn slice_eof_in_value_returns_err() {
+        let data = [255u8, 255];
+        let mut input = CodedInput::from_slice(&data);
+
+        match input.read_uint32().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(_)) => {}
+            _ => assert!(false, "read_uint32 didn't error out"),
+        }
+
+        let mut input = CodedInput::from_slice(&data);
+        match input.read_fixed32().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(_)) => {}
+            _ => assert!(false, "read_fixed32 didn't error out"),
+        }
+    }
+
+    #[test]
+    fn slice_respects_limit() -> Result {
+        let data = [3u8, 1, 2, 3, 4];
+        let mut input = CodedInput::from_slice(&data);
+        input.limit = Some(3);
+
+        match input.read_bytes().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(_)) => {}
+            _ => assert!(false, "read_bytes didn't error out"),
+        }
+
+        let mut input = CodedInput::from_slice(&data[1..]);
+        input.limit = Some(2);
+        assert!(input.read_fixed32().is_err());
+        assert_eq!(input.read_uint32()?, 1);
+        assert_eq!(input.read_uint32()?, 2);
+        assert!(input.read_tag()?.is_none());
+        assert!(input.reached_limit());
+
+        Ok(())
+    }
+
+    #[test]
+    fn push_limit_stays_within_enclosing_limit() -> Result {
+        let data = [0u8; 4];
+        let mut input = CodedInput::from_slice(&data);
+
+        let outer = input.push_limit(2)?;
+        match input.push_limit(3).map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            _ => assert!(false, "a limit past the enclosing limit was accepted"),
+        }
+        match input.push_limit(-1).map_err(InputError::into_kind) {
+            Err(InputErrorKind::NegativeSize) => {}
+            _ => assert!(false, "a negative limit was accepted"),
+        }
+        assert_eq!(input.limit, Some(2));
+
+        let inner = input.push_limit(1)?;
+        assert_eq!(input.read_uint32()?, 0);
+        assert!(input.reached_limit());
+        input.pop_limit(inner);
+        assert_eq!(input.limit, Some(1));
+        input.pop_limit(outer);
+        assert_eq!(input.limit, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_borrows_length_delimited_values() -> Result {
+        let data = [2u8, b'h', b'i', 1, 0xFF];
+        let mut input = CodedInput::from_slice(&data);
+
+        match input.read_str_ref()? {
+            Cow::Borrowed(value) => assert_eq!(value, "hi"),
+            Cow::Owned(_) => assert!(false, "read_str_ref copied from a slice"),
+        }
+        match input.read_bytes_ref()? {
+            Cow::Borrowed(value) => assert_eq!(value, &[0xFF]),
+            Cow::Owned(_) => assert!(false, "read_bytes_ref copied from a slice"),
+        }
+
+        let mut input = CodedInput::from_slice(&data[3..]);
+        match input.read_str_ref().map_err(InputError::into_kind) {
+            Err(InputErrorKind::InvalidString(_)) => {}
+            _ => assert!(false, "read_str_ref didn't return an invalid string error"),
+        }
+
+        let mut slice: &[u8] = &data;
+        let mut input = CodedInput::new(&mut slice);
+        assert_eq!(input.read_str_ref()?, "hi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn recursion_limit_bounds_nested_groups() -> Result {
+        fn nested_groups(depth: usize) -> Vec<u8> {
+            let mut data = vec![11u8; depth];
+            data.extend(vec![12u8; depth]);
+            data
+        }
+
+        fn skip(data: &[u8], limit: Option<u32>) -> InputResult<()> {
+            let mut input = CodedInput::from_slice(data);
+            if let Some(limit) = limit {
+                input = input.with_recursion_limit(limit);
+            }
+            let tag = input.read_tag()?.unwrap();
+            input.skip(tag)
+        }
+
+        assert_eq!(CodedInput::from_slice(&[]).recursion_limit(), CodedInput::DEFAULT_RECURSION_LIMIT);
+
+        skip(&nested_groups(100), None)?;
+        match skip(&nested_groups(101), None).map_err(InputError::into_kind) {
+            Err(InputErrorKind::RecursionLimitExceeded) => {}
+            _ => assert!(false, "skipping groups nested past the default limit didn't fail"),
+        }
+
+        skip(&nested_groups(5), Some(5))?;
+        match skip(&nested_groups(6), Some(5)).map_err(InputError::into_kind) {
+            Err(InputErrorKind::RecursionLimitExceeded) => {}
+            _ => assert!(false, "skipping groups nested past a custom limit didn't fail"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn size_limit_bounds_total_input() -> Result {
+        let data = [8u8, 1, 8, 2];
+
+        let mut input = CodedInput::from_slice(&data[..2]).with_size_limit(2);
+        assert!(input.read_tag()?.is_some());
+        assert_eq!(input.read_uint32()?, 1);
+        assert!(input.read_tag()?.is_none());
+        assert_eq!(input.position(), 2);
+
+        let mut slice: &[u8] = &data;
+        let mut input = CodedInput::new(&mut slice).with_size_limit(2);
+        assert!(input.read_tag()?.is_some());
+        assert_eq!(input.read_uint32()?, 1);
+        match input.read_tag().map_err(InputError::into_kind) {
+            Err(InputErrorKind::SizeLimitExceeded) => {}
+            _ => assert!(false, "reading a tag past the size limit didn't fail"),
+        }
+
+        let mut input = CodedInput::from_slice(&data).with_size_limit(2);
+        assert!(input.read_tag()?.is_some());
+        assert_eq!(input.read_uint32()?, 1);
+        match input.read_tag().map_err(InputError::into_kind) {
+            Err(InputErrorKind::SizeLimitExceeded) => {}
+            _ => assert!(false, "reading a tag past the size limit didn't fail"),
+        }
+
+        let mut input = CodedInput::from_slice(&data).with_size_limit(3);
+        input.read_tag()?;
+        input.read_uint32()?;
+        assert!(input.read_tag()?.is_some());
+        match input.read_uint32().map_err(InputError::into_kind) {
+            Err(InputErrorKind::SizeLimitExceeded) => {}
+            _ => assert!(false, "reading a value past the size limit didn't fail"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn huge_length_delimited_values_return_err() {
+        // a bytes value that claims to be 2147483647 bytes long
+        let data = [0xFFu8, 0xFF, 0xFF, 0xFF, 0x07, 1, 2, 3];
+
+        let mut slice: &[u8] = &data;
+        match CodedInput::new(&mut slice).read_bytes().map_err(InputError::into_kind) {
+            Err(InputErrorKind::SizeLimitExceeded) => {}
+            _ => assert!(false, "reading a value larger than the size limit didn't fail"),
+        }
+
+        let mut slice: &[u8] = &data;
+        match CodedInput::new(&mut slice).with_size_limit(usize::max_value()).read_bytes().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            _ => assert!(false, "reading a value larger than the input didn't fail"),
+        }
+
+        match CodedInput::from_slice(&data).with_size_limit(usize::max_value()).read_bytes().map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            _ => assert!(false, "reading a value larger than the input didn't fail"),
+        }
+    }
+
+    struct Chain {
+        child: Option<Box<Chain>>,
+        sized: Cell<usize>,
+        cached_size: CachedSize,
+    }
+
+    impl Chain {
+        fn new(depth: usize) -> Chain {
+            Chain {
+                child: if depth == 0 { None } else { Some(Box::new(Chain::new(depth - 1))) },
+                sized: Cell::new(0),
+                cached_size: CachedSize::default(),
+            }
+        }
+
+        fn for_each(&self, f: &mut dyn FnMut(&Chain)) {
+            f(self);
+            if let Some(child) = &self.child {
+                child.for_each(f);
+            }
+        }
+    }
+
+    impl CodedMessage for Chain {
+        fn merge_from(&mut self, _input: &mut CodedInput) -> InputResult<()> {
+            unreachable!()
+        }
+        #[cfg(checked_size)]
+        fn calculate_size(&self) -> Option<i32> {
+            self.sized.set(self.sized.get() + 1);
+            let size = match &self.child {
+                Some(child) => super::sizes::message(&**child)?.checked_add(1)?,
+                None => 0,
+            };
+            self.cached_size.set(size);
+            Some(size)
+        }
+        #[cfg(not(checked_size))]
+        fn calculate_size(&self) -> i32 {
+            self.sized.set(self.sized.get() + 1);
+            let size = match &self.child {
+                Some(child) => super::sizes::message(&**child) + 1,
+                None => 0,
+            };
+            self.cached_size.set(size);
+            size
+        }
+        fn write_to(&self, output: &mut CodedOutput) -> OutputResult {
+            if let Some(child) = &self.child {
+                output.write_raw_tag(10)?;
+                output.write_message(&**child)?;
+            }
+            Ok(())
+        }
+        fn cached_size(&self) -> Option<i32> {
+            Some(self.cached_size.get())
+        }
+    }
+
+    #[test]
+    fn nested_messages_are_sized_once() -> OutputResult {
+        let chain = Chain::new(50);
+        let vec = chain.write_to_vec()?;
+        assert_eq!(vec.len(), 100); // 50 tags and 50 one byte lengths
+        chain.for_each(&mut |c| assert_eq!(c.sized.get(), 1));
+
+        let chain = Chain::new(50);
+        let mut written = Vec::new();
+        chain.write(&mut written)?;
+        assert_eq!(written, vec);
+        chain.for_each(&mut |c| assert_eq!(c.sized.get(), if c as *const Chain == &chain as *const Chain { 0 } else { 1 }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cached_sizes_are_recalculated_after_changes() -> OutputResult {
+        let mut chain = Chain::new(50);
+        chain.write_to_vec()?;
+
+        let mut last = &mut chain;
+        for _ in 0..25 {
+            last = last.child.as_mut().unwrap();
+        }
+        last.child = None;
+
+        let vec = chain.write_to_vec()?;
+        assert_eq!(vec.len(), 50);
+
+        let mut written = Vec::new();
+        chain.write(&mut written)?;
+        assert_eq!(written, vec);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wire_events_check_groups() -> Result {
+        fn events(data: &[u8]) -> InputResult<Vec<WireEvent<'_>>> {
+            CodedInput::from_slice(data).events().collect()
+        }
+
+        let number = |n| FieldNumber::new(n).unwrap();
+        assert_eq!(events(&[11, 19, 20, 8, 1, 12])?, [
+            WireEvent::StartGroup(number(1)),
+            WireEvent::StartGroup(number(2)),
+            WireEvent::EndGroup(number(2)),
+            WireEvent::Field(number(1), RawValue::Varint(1)),
+            WireEvent::EndGroup(number(1)),
+        ]);
+
+        match events(&[11, 20]).map_err(InputError::into_kind) {
+            Err(InputErrorKind::InvalidTag(20)) => {}
+            _ => assert!(false, "a group ended by a different field didn't fail"),
+        }
+        match events(&[12]).map_err(InputError::into_kind) {
+            Err(InputErrorKind::InvalidTag(12)) => {}
+            _ => assert!(false, "a group end without a start didn't fail"),
+        }
+        match events(&[11, 8, 1]).map_err(InputError::into_kind) {
+            Err(InputErrorKind::IoError(ref e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            _ => assert!(false, "an unclosed group didn't fail"),
+        }
+
+        let data = [11u8; 6];
+        let mut input = CodedInput::from_slice(&data).with_recursion_limit(5);
+        let mut events = input.events();
+        for _ in 0..5 {
+            events.next().unwrap()?;
+        }
+        match events.next().map(|r| r.map_err(InputError::into_kind)) {
+            Some(Err(InputErrorKind::RecursionLimitExceeded)) => {}
+            _ => assert!(false, "groups nested past the recursion limit didn't fail"),
+        }
+        assert!(events.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wire_events_borrow_from_slices() -> Result {
+        let data = [10, 2, b'h', b'i', 21, 1, 0, 0, 0, 25, 2, 0, 0, 0, 0, 0, 0, 0];
+        let expected = |value: Cow<'static, [u8]>| {
+            let number = |n| FieldNumber::new(n).unwrap();
+            vec![
+                WireEvent::Field(number(1), RawValue::LengthDelimited(value)),
+                WireEvent::Field(number(2), RawValue::Bit32(1)),
+                WireEvent::Field(number(3), RawValue::Bit64(2)),
+            ]
+        };
+
+        let events = CodedInput::from_slice(&data).events().collect::<InputResult<Vec<_>>>()?;
+        assert_eq!(events, expected(Cow::Borrowed(b"hi")));
+        match &events[0] {
+            WireEvent::Field(_, RawValue::LengthDelimited(Cow::Borrowed(_))) => {}
+            _ => assert!(false, "a slice input didn't borrow a length delimited value"),
+        }
+
+        let mut slice = data.as_ref();
+        let events = CodedInput::new(&mut slice).events().collect::<InputResult<Vec<_>>>()?;
+        assert_eq!(events, expected(Cow::Owned(b"hi".to_vec())));
+
+        Ok(())
+    }
 }
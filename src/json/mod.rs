@@ -0,0 +1,328 @@
+//! Contains a printer and parser for the [proto3 JSON mapping](https://developers.google.com/protocol-buffers/docs/proto3#json)
+//! of protobuf messages.
+//!
+//! The printer and parser use reflection, so they work with any message that implements [`AnyMessage`],
+//! including generated messages and dynamic messages created from a [`DescriptorPool`].
+//!
+//! [`AnyMessage`]: ../reflect/trait.AnyMessage.html
+//! [`DescriptorPool`]: ../reflect/struct.DescriptorPool.html
+//!
+//! # Example
+//!
+//! ```
+//! use protrust::LiteMessage;
+//! use protrust::wkt::timestamp::Timestamp;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut time = Timestamp::new();
+//! *time.seconds_mut() = 1_000_000_000;
+//! *time.nanos_mut() = 500_000_000;
+//!
+//! assert_eq!(protrust::json::to_string(&time)?, r#""2001-09-09T01:46:40.500Z""#);
+//!
+//! let parsed: Timestamp = protrust::json::from_str(r#""2001-09-09T01:46:40.500Z""#)?;
+//! assert_eq!(parsed, time);
+//! # Ok(())
+//! # }
+//! ```
+
+mod parse;
+mod print;
+
+use crate::io::InputError;
+use crate::reflect::{AnyMessage, FieldDescriptor, MessageDescriptor};
+use crate::Message;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Returns true if the message type has a special representation in JSON
+fn is_well_known_type(descriptor: &MessageDescriptor) -> bool {
+    match descriptor.full_name() {
+        "google.protobuf.Any"
+        | "google.protobuf.Timestamp"
+        | "google.protobuf.Duration"
+        | "google.protobuf.FieldMask"
+        | "google.protobuf.Struct"
+        | "google.protobuf.Value"
+        | "google.protobuf.ListValue"
+        | "google.protobuf.DoubleValue"
+        | "google.protobuf.FloatValue"
+        | "google.protobuf.Int64Value"
+        | "google.protobuf.UInt64Value"
+        | "google.protobuf.Int32Value"
+        | "google.protobuf.UInt32Value"
+        | "google.protobuf.BoolValue"
+        | "google.protobuf.StringValue"
+        | "google.protobuf.BytesValue" => true,
+        _ => false,
+    }
+}
+
+
+/// An error returned when a message can't be printed as JSON
+#[derive(Debug)]
+pub enum PrintError {
+    /// An error occured while writing to the underlying `fmt::Write` object
+    FmtError(fmt::Error),
+    /// The type url of an `Any` value couldn't be resolved to a message type
+    UnresolvedAny(String),
+    /// The message contained in an `Any` value couldn't be read
+    InvalidAny(InputError),
+    /// A `Timestamp` was outside the range `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z`
+    InvalidTimestamp,
+    /// A `Duration` was outside the range of ±10,000 years or its seconds and nanos had different signs
+    InvalidDuration,
+    /// A `FieldMask` contained a path that can't be represented in JSON
+    InvalidFieldMask(String),
+    /// A `Value` didn't have a kind set or contained a number that wasn't finite
+    InvalidValue,
+}
+
+impl From<fmt::Error> for PrintError {
+    fn from(value: fmt::Error) -> PrintError {
+        PrintError::FmtError(value)
+    }
+}
+
+impl From<InputError> for PrintError {
+    fn from(value: InputError) -> PrintError {
+        PrintError::InvalidAny(value)
+    }
+}
+
+impl Display for PrintError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        use self::PrintError::*;
+        match self {
+            FmtError(_) => write!(fmt, "an error occured in the underlying output"),
+            UnresolvedAny(url) => write!(fmt, "the type url '{}' couldn't be resolved to a message type", url),
+            InvalidAny(_) => write!(fmt, "an Any value contained a message that couldn't be read"),
+            InvalidTimestamp => write!(fmt, "a Timestamp value was out of the range supported by JSON"),
+            InvalidDuration => write!(fmt, "a Duration value was out of range or had seconds and nanos of different signs"),
+            InvalidFieldMask(path) => write!(fmt, "the FieldMask path '{}' can't be represented in JSON", path),
+            InvalidValue => write!(fmt, "a Value had no kind set or contained a number that wasn't finite"),
+        }
+    }
+}
+
+impl Error for PrintError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PrintError::FmtError(ref e) => Some(e),
+            PrintError::InvalidAny(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Options that change how messages are printed and parsed.
+///
+/// The default options follow the proto3 JSON mapping without any of the optional behaviors.
+///
+/// # Example
+///
+/// ```
+/// use protrust::json::{self, JsonOptions};
+/// use protrust::LiteMessage;
+/// use protrust::wkt::source_context::SourceContext;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = JsonOptions {
+///     always_print_primitive_fields: true,
+///     preserve_proto_field_names: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(json::to_string(&SourceContext::new())?, "{}");
+/// assert_eq!(json::to_string_with_options(&SourceContext::new(), &options)?, r#"{"file_name":""}"#);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsonOptions {
+    /// Prints fields that don't track presence even if they contain their default value.
+    /// Repeated and map fields are printed as empty arrays and objects.
+    ///
+    /// Fields that track presence, like message fields, oneof fields, and proto2 optional fields,
+    /// are still only printed if they're set.
+    pub always_print_primitive_fields: bool,
+    /// Prints fields with their original name in the proto file instead of their JSON name
+    pub preserve_proto_field_names: bool,
+    /// Prints enum values as their number instead of their name
+    pub print_enums_as_ints: bool,
+    /// Skips object keys that don't match a field and enum value names that don't match a value
+    /// instead of returning an error while parsing
+    pub ignore_unknown_fields: bool,
+}
+
+/// Prints the message as JSON to the specified output.
+///
+/// `Any` values are resolved using the descriptor pool that contains the printed message's type.
+pub fn print<'a>(message: &(dyn AnyMessage<'a> + 'a), output: &mut dyn fmt::Write) -> Result<(), PrintError> {
+    print_with_options(message, output, &JsonOptions::default())
+}
+
+/// Prints the message as JSON to the specified output using the specified options.
+pub fn print_with_options<'a>(message: &(dyn AnyMessage<'a> + 'a), output: &mut dyn fmt::Write, options: &JsonOptions) -> Result<(), PrintError> {
+    print::Printer::new(message.descriptor(), output, *options).print_message(message)
+}
+
+/// Prints the message as a JSON string.
+///
+/// `Any` values are resolved using the descriptor pool that contains the printed message's type.
+pub fn to_string<'a>(message: &(dyn AnyMessage<'a> + 'a)) -> Result<String, PrintError> {
+    to_string_with_options(message, &JsonOptions::default())
+}
+
+/// Prints the message as a JSON string using the specified options.
+pub fn to_string_with_options<'a>(message: &(dyn AnyMessage<'a> + 'a), options: &JsonOptions) -> Result<String, PrintError> {
+    let mut output = String::new();
+    print_with_options(message, &mut output, options)?;
+    Ok(output)
+}
+
+/// An error returned when JSON text can't be parsed into a message.
+///
+/// The error contains the kind of error that occured along with the path
+/// to the JSON value where the error occured.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    path: String,
+}
+
+impl ParseError {
+    /// Gets the kind of error that occured
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Gets the path to the JSON value where the error occured, starting at the root value `$`.
+    ///
+    /// Object keys are appended as `.key` and array indices are appended as `[index]`,
+    /// for example `$.repeatedNestedMessage[1].bb`
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "{} at {}", self.kind, self.path)
+    }
+}
+
+impl Error for ParseError { }
+
+/// The kind of error that occured while parsing JSON
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// The input ended before a complete JSON value was read
+    UnexpectedEof,
+    /// The input contained a character that isn't valid at this point in the JSON text
+    UnexpectedChar(char),
+    /// A string contained an invalid escape sequence or an unescaped control character
+    InvalidString,
+    /// A JSON value had the wrong type for the field it was assigned to
+    UnexpectedValue,
+    /// An object contained a key that doesn't match a field in the message
+    UnknownField(String),
+    /// An object specified the same field more than once or specified multiple fields of the same oneof
+    DuplicateField(String),
+    /// A number was malformed or out of the range of the field type
+    InvalidNumber,
+    /// An enum value name didn't match any value defined in the enum
+    UnknownEnumValue(String),
+    /// A bytes value wasn't valid base64
+    InvalidBytes,
+    /// A map key couldn't be parsed as the key type of the map
+    InvalidMapKey(String),
+    /// A `Timestamp` wasn't a valid RFC 3339 date or was outside the range `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z`
+    InvalidTimestamp,
+    /// A `Duration` was malformed or outside the range of ±10,000 years
+    InvalidDuration,
+    /// A `FieldMask` contained a path that isn't a valid lowerCamelCase path
+    InvalidFieldMask(String),
+    /// An `Any` object with fields didn't contain a `@type` key
+    MissingAnyType,
+    /// The type url of an `Any` value couldn't be resolved to a message type
+    UnresolvedAny(String),
+    /// The input contained objects, arrays, or messages nested deeper than the recursion limit of 100
+    RecursionLimitExceeded,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        use self::ParseErrorKind::*;
+        match self {
+            UnexpectedEof => write!(fmt, "the input ended unexpectedly"),
+            UnexpectedChar(c) => write!(fmt, "the input contained an unexpected character '{}'", c.escape_debug()),
+            InvalidString => write!(fmt, "the input contained an invalid string"),
+            UnexpectedValue => write!(fmt, "the value had the wrong type for the field"),
+            UnknownField(name) => write!(fmt, "the message doesn't contain a field named '{}'", name),
+            DuplicateField(name) => write!(fmt, "the field '{}' was specified multiple times or another field in its oneof was already set", name),
+            InvalidNumber => write!(fmt, "the number was malformed or out of range for the field"),
+            UnknownEnumValue(name) => write!(fmt, "the enum doesn't contain a value named '{}'", name),
+            InvalidBytes => write!(fmt, "the bytes value wasn't valid base64"),
+            InvalidMapKey(key) => write!(fmt, "the map key '{}' couldn't be parsed as the map's key type", key),
+            InvalidTimestamp => write!(fmt, "the Timestamp value wasn't a valid RFC 3339 date or was out of range"),
+            InvalidDuration => write!(fmt, "the Duration value was malformed or out of range"),
+            InvalidFieldMask(path) => write!(fmt, "the FieldMask path '{}' isn't a valid lowerCamelCase path", path),
+            MissingAnyType => write!(fmt, "the Any value didn't contain a '@type' key"),
+            UnresolvedAny(url) => write!(fmt, "the type url '{}' couldn't be resolved to a message type", url),
+            RecursionLimitExceeded => write!(fmt, "the input contained values nested deeper than the recursion limit"),
+        }
+    }
+}
+
+/// Parses the JSON text and merges it into the specified message.
+///
+/// Fields can be specified by their JSON name or their original name in the proto file.
+/// `Any` values are resolved using the descriptor pool that contains the message's type.
+pub fn merge_from_str<'a>(message: &mut (dyn AnyMessage<'a> + 'a), input: &str) -> Result<(), ParseError> {
+    merge_from_str_with_options(message, input, &JsonOptions::default())
+}
+
+/// Parses the JSON text and merges it into the specified message using the specified options.
+pub fn merge_from_str_with_options<'a>(message: &mut (dyn AnyMessage<'a> + 'a), input: &str, options: &JsonOptions) -> Result<(), ParseError> {
+    let value = parse::read(input)?;
+    parse::Parser::new(message.descriptor(), *options).merge_message(message, value)
+}
+
+/// Parses the JSON text into a new instance of the message type.
+pub fn from_str<T: Message>(input: &str) -> Result<T, ParseError> {
+    from_str_with_options(input, &JsonOptions::default())
+}
+
+/// Parses the JSON text into a new instance of the message type using the specified options.
+pub fn from_str_with_options<T: Message>(input: &str, options: &JsonOptions) -> Result<T, ParseError> {
+    let mut message = T::new();
+    merge_from_str_with_options(&mut message, input, options)?;
+    Ok(message)
+}
+
+/// Gets the JSON name of a field, computing it from the field's name if the descriptor doesn't specify one
+fn json_name<'a>(field: &FieldDescriptor<'a>) -> Cow<'a, str> {
+    match field.json_name() {
+        Some(name) => Cow::Borrowed(name),
+        None => Cow::Owned(to_camel_case(field.name())),
+    }
+}
+
+/// Converts a snake_case name to a lowerCamelCase name the same way protoc computes json_name
+fn to_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize_next = false;
+    for c in name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
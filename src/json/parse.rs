@@ -0,0 +1,708 @@
+use super::{is_well_known_type, json_name, JsonOptions, ParseError, ParseErrorKind};
+use crate::io::CodedInput;
+use crate::reflect::access::FieldAccessor;
+use crate::reflect::{
+    AnyMessage, AnyValue, EnumDescriptor, FieldDescriptor, FieldScope, FieldType, MessageDescriptor,
+    FAILED_ACCESS_ERROR, INVALID_VALUE_TYPE, INVALID_WELL_KNOWN_TYPE,
+};
+use crate::wkt::any::get_type_name;
+use crate::wkt::{duration, timestamp};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+static WRITE_VEC_ERROR: &str = "couldn't write to vec";
+
+/// The maximum depth of nested JSON values and messages
+const MAX_DEPTH: usize = CodedInput::DEFAULT_RECURSION_LIMIT as usize;
+
+/// A parsed JSON value. Numbers are kept as their original text so
+/// 64-bit integers can be parsed without losing precision
+#[derive(Debug)]
+pub(super) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn format_path(path: &[String]) -> String {
+    let mut result = String::from("$");
+    for segment in path {
+        result.push_str(segment);
+    }
+    result
+}
+
+/// Returns true if the text is a number as defined by the JSON grammar
+fn is_number(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let digits = |i: &mut usize| {
+        let start = *i;
+        while let Some(b'0'..=b'9') = bytes.get(*i).cloned() {
+            *i += 1;
+        }
+        *i != start
+    };
+
+    if bytes.get(i).cloned() == Some(b'-') {
+        i += 1;
+    }
+    match bytes.get(i).cloned() {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => { digits(&mut i); },
+        _ => return false,
+    }
+    if bytes.get(i).cloned() == Some(b'.') {
+        i += 1;
+        if !digits(&mut i) {
+            return false;
+        }
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(i).cloned() {
+        i += 1;
+        if let Some(b'+') | Some(b'-') = bytes.get(i).cloned() {
+            i += 1;
+        }
+        if !digits(&mut i) {
+            return false;
+        }
+    }
+    i == bytes.len()
+}
+
+/// Reads JSON text into a JSON value, returning an error if the text isn't valid JSON
+pub(super) fn read(input: &str) -> Result<Json> {
+    let mut reader = Reader { input, position: 0, path: Vec::new(), depth: 0 };
+    let value = reader.read_value()?;
+    reader.skip_whitespace();
+    match reader.peek() {
+        Some(c) => Err(reader.error(ParseErrorKind::UnexpectedChar(c))),
+        None => Ok(value),
+    }
+}
+
+struct Reader<'i> {
+    input: &'i str,
+    position: usize,
+    path: Vec<String>,
+    depth: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError { kind, path: format_path(&self.path) }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Result<char> {
+        match self.peek() {
+            Some(c) => {
+                self.position += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.next()? {
+            c if c == expected => Ok(()),
+            c => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn read_value(&mut self) -> Result<Json> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.recurse(Self::read_object),
+            Some('[') => self.recurse(Self::read_array),
+            Some('"') => Ok(Json::String(self.read_string()?)),
+            Some('-') | Some('0'..='9') => self.read_number(),
+            Some(c) if c.is_ascii_alphabetic() => self.read_literal(),
+            Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    /// Reads a nested object or array, returning an error if it's nested deeper than the recursion limit
+    fn recurse(&mut self, read: fn(&mut Self) -> Result<Json>) -> Result<Json> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::RecursionLimitExceeded));
+        }
+        self.depth += 1;
+        let result = read(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn read_object(&mut self) -> Result<Json> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('"') => { },
+                Some(c) => return Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+                None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+            }
+            let key = self.read_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+
+            self.path.push(format!(".{}", key));
+            let value = self.read_value()?;
+            self.path.pop();
+            entries.push((key, value));
+
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Json::Object(entries)),
+                c => return Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            }
+        }
+    }
+
+    fn read_array(&mut self) -> Result<Json> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            self.path.push(format!("[{}]", values.len()));
+            let value = self.read_value()?;
+            self.path.pop();
+            values.push(value);
+
+            self.skip_whitespace();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Json::Array(values)),
+                c => return Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            }
+        }
+    }
+
+    fn read_hex_escape(&mut self) -> Result<u32> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = self.next()?.to_digit(16).ok_or_else(|| self.error(ParseErrorKind::InvalidString))?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut result = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(result),
+                '\\' => match self.next()? {
+                    '"' => result.push('"'),
+                    '\\' => result.push('\\'),
+                    '/' => result.push('/'),
+                    'b' => result.push('\u{8}'),
+                    'f' => result.push('\u{c}'),
+                    'n' => result.push('\n'),
+                    'r' => result.push('\r'),
+                    't' => result.push('\t'),
+                    'u' => {
+                        let mut code = self.read_hex_escape()?;
+                        if (0xD800..=0xDBFF).contains(&code) {
+                            // a high surrogate must be followed by an escaped low surrogate
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.read_hex_escape()?;
+                            if !(0xDC00..=0xDFFF).contains(&low) {
+                                return Err(self.error(ParseErrorKind::InvalidString));
+                            }
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        }
+                        result.push(std::char::from_u32(code).ok_or_else(|| self.error(ParseErrorKind::InvalidString))?);
+                    }
+                    _ => return Err(self.error(ParseErrorKind::InvalidString)),
+                },
+                c if c < ' ' => return Err(self.error(ParseErrorKind::InvalidString)),
+                c => result.push(c),
+            }
+        }
+    }
+
+    fn read_number(&mut self) -> Result<Json> {
+        let start = self.position;
+        while let Some('-') | Some('+') | Some('.') | Some('e') | Some('E') | Some('0'..='9') = self.peek() {
+            self.position += 1;
+        }
+        let text = &self.input[start..self.position];
+        if is_number(text) {
+            Ok(Json::Number(text.to_string()))
+        } else {
+            Err(self.error(ParseErrorKind::InvalidNumber))
+        }
+    }
+
+    fn read_literal(&mut self) -> Result<Json> {
+        let start = self.position;
+        while let Some('a'..='z') | Some('A'..='Z') = self.peek() {
+            self.position += 1;
+        }
+        match &self.input[start..self.position] {
+            "null" => Ok(Json::Null),
+            "true" => Ok(Json::Bool(true)),
+            "false" => Ok(Json::Bool(false)),
+            text => Err(ParseError { kind: ParseErrorKind::UnexpectedChar(text.chars().next().unwrap()), path: format_path(&self.path) }),
+        }
+    }
+}
+
+/// Parses an integer from a JSON number or string. Integers in exponent or
+/// decimal form are accepted as long as they don't have a fractional part
+fn parse_integer<T: FromStr + TryFrom<i128>>(value: &Json) -> std::result::Result<T, ParseErrorKind> {
+    let text =
+        match value {
+            Json::Number(text) | Json::String(text) => text,
+            _ => return Err(ParseErrorKind::UnexpectedValue),
+        };
+    if !is_number(text) {
+        return Err(ParseErrorKind::InvalidNumber);
+    }
+    if let Ok(value) = text.parse::<T>() {
+        return Ok(value);
+    }
+    // 2^127, the magnitude of i128::min_value(). Casting a float outside of the range of i128 is undefined
+    const I128_LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+    match text.parse::<f64>() {
+        Ok(value) if value.fract() == 0.0 && (-I128_LIMIT..I128_LIMIT).contains(&value) => {
+            T::try_from(value as i128).map_err(|_| ParseErrorKind::InvalidNumber)
+        }
+        _ => Err(ParseErrorKind::InvalidNumber),
+    }
+}
+
+/// Parses a float from a JSON number or string, including the special
+/// string values "NaN", "Infinity", and "-Infinity"
+fn parse_float(value: &Json) -> std::result::Result<f64, ParseErrorKind> {
+    match value {
+        Json::String(text) if text == "NaN" => Ok(std::f64::NAN),
+        Json::String(text) if text == "Infinity" => Ok(std::f64::INFINITY),
+        Json::String(text) if text == "-Infinity" => Ok(std::f64::NEG_INFINITY),
+        Json::Number(text) | Json::String(text) if is_number(text) => {
+            match text.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(value),
+                _ => Err(ParseErrorKind::InvalidNumber),
+            }
+        }
+        Json::Number(_) | Json::String(_) => Err(ParseErrorKind::InvalidNumber),
+        _ => Err(ParseErrorKind::UnexpectedValue),
+    }
+}
+
+/// Converts a lowerCamelCase field mask path to the snake_case path used in the proto
+fn parse_field_mask_path(path: &str) -> Option<String> {
+    let mut result = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            '_' => return None,
+            c if c.is_ascii_uppercase() => {
+                result.push('_');
+                result.push(c.to_ascii_lowercase());
+            }
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+/// Returns true if a JSON null is a meaningful value for the field type rather than the absence of a value
+fn accepts_null(field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::Message(m) => m.full_name() == "google.protobuf.Value",
+        FieldType::Enum(e) => e.full_name() == "google.protobuf.NullValue",
+        _ => false,
+    }
+}
+
+fn find_field<'a>(descriptor: &'a MessageDescriptor<'a>, name: &str) -> Option<&'a FieldDescriptor<'a>> {
+    descriptor.fields()
+        .iter()
+        .map(|f| &**f)
+        .find(|f| json_name(f) == name)
+        .or_else(|| descriptor.fields().iter().map(|f| &**f).find(|f| f.name() == name))
+}
+
+fn find_wkt_field<'a>(descriptor: &'a MessageDescriptor<'a>, name: &str) -> &'a FieldDescriptor<'a> {
+    descriptor.find_field_by_name(name).expect(INVALID_WELL_KNOWN_TYPE)
+}
+
+/// Merges JSON values into messages, resolving `Any` values with the pool containing a root message type
+pub(super) struct Parser<'a> {
+    pool: &'a MessageDescriptor<'a>,
+    path: Vec<String>,
+    depth: usize,
+    options: JsonOptions,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(pool: &'a MessageDescriptor<'a>, options: JsonOptions) -> Parser<'a> {
+        Parser { pool, path: Vec::new(), depth: 0, options }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError { kind, path: format_path(&self.path) }
+    }
+
+    pub(super) fn merge_message(&mut self, message: &mut dyn AnyMessage<'a>, value: Json) -> Result<()> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::RecursionLimitExceeded));
+        }
+        self.depth += 1;
+        let result =
+            if is_well_known_type(message.descriptor()) {
+                self.merge_well_known_type(message, value)
+            } else {
+                match value {
+                    Json::Object(entries) => self.merge_fields(message, entries),
+                    _ => Err(self.error(ParseErrorKind::UnexpectedValue)),
+                }
+            };
+        self.depth -= 1;
+        result
+    }
+
+    fn merge_fields(&mut self, message: &mut dyn AnyMessage<'a>, entries: Vec<(String, Json)>) -> Result<()> {
+        let descriptor = message.descriptor();
+        let mut fields = HashSet::new();
+        let mut oneofs = HashSet::new();
+        for (key, value) in entries {
+            self.path.push(format!(".{}", key));
+            let field =
+                match find_field(descriptor, &key) {
+                    Some(field) => field,
+                    None if self.options.ignore_unknown_fields => {
+                        self.path.pop();
+                        continue;
+                    }
+                    None => return Err(self.error(ParseErrorKind::UnknownField(key))),
+                };
+            if !fields.insert(field.number()) {
+                return Err(self.error(ParseErrorKind::DuplicateField(key)));
+            }
+            if let FieldScope::Oneof(oneof) = field.scope() {
+                let is_set = match value { Json::Null => accepts_null(field.field_type()), _ => true };
+                if is_set && !oneofs.insert(oneof.full_name()) {
+                    return Err(self.error(ParseErrorKind::DuplicateField(key)));
+                }
+            }
+            self.merge_field(message, field, value)?;
+            self.path.pop();
+        }
+        Ok(())
+    }
+
+    fn merge_field(&mut self, message: &mut dyn AnyMessage<'a>, field: &'a FieldDescriptor<'a>, value: Json) -> Result<()> {
+        match field.accessor().expect("message field had no accessor") {
+            FieldAccessor::Single(accessor) => {
+                match (value, field.field_type()) {
+                    (Json::Null, field_type) if !accepts_null(field_type) => {
+                        accessor.clear(message).expect(FAILED_ACCESS_ERROR);
+                        Ok(())
+                    }
+                    (value, FieldType::Message(_)) | (value, FieldType::Group(_)) => {
+                        let nested = accessor.get_mut(message).expect(FAILED_ACCESS_ERROR);
+                        self.merge_message(nested.as_message_mut().expect(INVALID_VALUE_TYPE), value)
+                    }
+                    (value, FieldType::Enum(e)) => {
+                        if let Some(number) = self.parse_enum(e, value)? {
+                            let target = accessor.get_mut(message).expect(FAILED_ACCESS_ERROR);
+                            target.as_enum_mut().expect(INVALID_VALUE_TYPE).set_i32(number);
+                        }
+                        Ok(())
+                    }
+                    (value, field_type) => {
+                        let value = self.parse_value(field_type, value)?;
+                        accessor.set(message, value).expect(FAILED_ACCESS_ERROR);
+                        Ok(())
+                    }
+                }
+            }
+            FieldAccessor::Repeated(accessor) => {
+                match value {
+                    Json::Null => Ok(()),
+                    Json::Array(values) => {
+                        for (index, value) in values.into_iter().enumerate() {
+                            self.path.push(format!("[{}]", index));
+                            if let Some(value) = self.parse_element(field, field.field_type(), value)? {
+                                accessor.push(message, value).expect(FAILED_ACCESS_ERROR);
+                            }
+                            self.path.pop();
+                        }
+                        Ok(())
+                    }
+                    _ => Err(self.error(ParseErrorKind::UnexpectedValue)),
+                }
+            }
+            FieldAccessor::Map(accessor) => {
+                let (key_field, value_field) =
+                    match field.field_type() {
+                        FieldType::Message(m) => (&m.fields()[0], &m.fields()[1]),
+                        _ => unreachable!("map accessor requires field type of message")
+                    };
+                match value {
+                    Json::Null => Ok(()),
+                    Json::Object(entries) => {
+                        for (key, value) in entries {
+                            self.path.push(format!(".{}", key));
+                            let key = self.parse_map_key(key_field.field_type(), key)?;
+                            if let Some(value) = self.parse_element(field, value_field.field_type(), value)? {
+                                accessor.insert(message, key, value).expect(FAILED_ACCESS_ERROR);
+                            }
+                            self.path.pop();
+                        }
+                        Ok(())
+                    }
+                    _ => Err(self.error(ParseErrorKind::UnexpectedValue)),
+                }
+            }
+        }
+    }
+
+    /// Parses an element of a repeated field or a map value.
+    /// This returns None if the value is an unknown enum value that should be ignored
+    fn parse_element(
+        &mut self,
+        field: &'a FieldDescriptor<'a>,
+        field_type: &'a FieldType<'a>,
+        value: Json) -> Result<Option<Box<dyn AnyValue<'a>>>> {
+        match field_type {
+            FieldType::Message(m) | FieldType::Group(m) => {
+                let mut instance = m.new_instance().expect("couldn't create an instance of the message type");
+                self.merge_message(instance.as_message_mut().expect(INVALID_VALUE_TYPE), value)?;
+                Ok(Some(instance))
+            }
+            FieldType::Enum(e) => {
+                match self.parse_enum(e, value)? {
+                    Some(number) => {
+                        field.new_enum_value(number)
+                            .map(Some)
+                            .ok_or_else(|| self.error(ParseErrorKind::UnknownEnumValue(number.to_string())))
+                    }
+                    None => Ok(None),
+                }
+            }
+            field_type => self.parse_value(field_type, value).map(Some),
+        }
+    }
+
+    /// Parses an enum value from its name or number.
+    /// This returns None if the name is unknown and unknown fields are ignored
+    fn parse_enum(&self, descriptor: &EnumDescriptor<'a>, value: Json) -> Result<Option<i32>> {
+        match value {
+            Json::Null => Ok(Some(0)),
+            Json::String(name) => {
+                match descriptor.find_enum_value_by_name(&name) {
+                    Some(value) => Ok(Some(value.number())),
+                    None if self.options.ignore_unknown_fields => Ok(None),
+                    None => Err(self.error(ParseErrorKind::UnknownEnumValue(name))),
+                }
+            }
+            value @ Json::Number(_) => parse_integer::<i32>(&value).map(Some).map_err(|kind| self.error(kind)),
+            _ => Err(self.error(ParseErrorKind::UnexpectedValue)),
+        }
+    }
+
+    fn parse_value(&self, field_type: &'a FieldType<'a>, value: Json) -> Result<Box<dyn AnyValue<'a>>> {
+        let result: std::result::Result<Box<dyn AnyValue<'a>>, ParseErrorKind> =
+            match (field_type, value) {
+                (FieldType::Bool, Json::Bool(value)) => Ok(Box::new(value)),
+                (FieldType::String, Json::String(value)) => Ok(Box::new(value)),
+                (FieldType::Bytes, Json::String(value)) => {
+                    base64::decode_config(&value, base64::STANDARD)
+                        .or_else(|_| base64::decode_config(&value, base64::URL_SAFE))
+                        .map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v))
+                        .map_err(|_| ParseErrorKind::InvalidBytes)
+                }
+                (FieldType::Int32, value) | (FieldType::Sint32, value) | (FieldType::Sfixed32, value) =>
+                    parse_integer::<i32>(&value).map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v)),
+                (FieldType::Int64, value) | (FieldType::Sint64, value) | (FieldType::Sfixed64, value) =>
+                    parse_integer::<i64>(&value).map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v)),
+                (FieldType::Uint32, value) | (FieldType::Fixed32, value) =>
+                    parse_integer::<u32>(&value).map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v)),
+                (FieldType::Uint64, value) | (FieldType::Fixed64, value) =>
+                    parse_integer::<u64>(&value).map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v)),
+                (FieldType::Double, value) => parse_float(&value).map::<Box<dyn AnyValue<'a>>, _>(|v| Box::new(v)),
+                (FieldType::Float, value) => {
+                    match parse_float(&value) {
+                        Ok(value) if value.is_finite() && value.abs() > f64::from(std::f32::MAX) => Err(ParseErrorKind::InvalidNumber),
+                        Ok(value) => Ok(Box::new(value as f32)),
+                        Err(e) => Err(e),
+                    }
+                }
+                _ => Err(ParseErrorKind::UnexpectedValue),
+            };
+        result.map_err(|kind| self.error(kind))
+    }
+
+    fn parse_map_key(&self, field_type: &'a FieldType<'a>, key: String) -> Result<Box<dyn AnyValue<'a>>> {
+        let result: std::result::Result<Box<dyn AnyValue<'a>>, ParseErrorKind> =
+            match field_type {
+                FieldType::String => return Ok(Box::new(key)),
+                FieldType::Bool if key == "true" => Ok(Box::new(true)),
+                FieldType::Bool if key == "false" => Ok(Box::new(false)),
+                FieldType::Bool => Err(ParseErrorKind::InvalidMapKey(key.to_string())),
+                field_type => self.parse_value(field_type, Json::String(key.to_string())).map_err(|e| e.kind),
+            };
+        result.map_err(|_| self.error(ParseErrorKind::InvalidMapKey(key)))
+    }
+
+    fn set_wkt_field(&self, message: &mut dyn AnyMessage<'a>, name: &str, value: Box<dyn AnyValue<'a>>) {
+        match find_wkt_field(message.descriptor(), name).accessor() {
+            Some(FieldAccessor::Single(accessor)) => accessor.set(message, value).expect(FAILED_ACCESS_ERROR),
+            _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+        }
+    }
+
+    fn merge_well_known_type(&mut self, message: &mut dyn AnyMessage<'a>, value: Json) -> Result<()> {
+        match (message.descriptor().full_name(), value) {
+            ("google.protobuf.Any", Json::Object(entries)) => self.merge_any(message, entries),
+            ("google.protobuf.Timestamp", Json::String(text)) => {
+                let (seconds, nanos) =
+                    timestamp::parse_rfc3339(&text)
+                        .filter(|&(seconds, nanos)| timestamp::is_valid(seconds, nanos))
+                        .ok_or_else(|| self.error(ParseErrorKind::InvalidTimestamp))?;
+                self.set_wkt_field(message, "seconds", Box::new(seconds));
+                self.set_wkt_field(message, "nanos", Box::new(nanos));
+                Ok(())
+            }
+            ("google.protobuf.Duration", Json::String(text)) => {
+                let (seconds, nanos) =
+                    duration::parse_duration(&text)
+                        .filter(|&(seconds, nanos)| duration::is_valid(seconds, nanos))
+                        .ok_or_else(|| self.error(ParseErrorKind::InvalidDuration))?;
+                self.set_wkt_field(message, "seconds", Box::new(seconds));
+                self.set_wkt_field(message, "nanos", Box::new(nanos));
+                Ok(())
+            }
+            ("google.protobuf.FieldMask", Json::String(text)) => {
+                let paths =
+                    text.split(',')
+                        .filter(|p| !p.is_empty())
+                        .map(|p| parse_field_mask_path(p).ok_or_else(|| self.error(ParseErrorKind::InvalidFieldMask(p.to_string()))))
+                        .collect::<Result<Vec<_>>>()?;
+                match find_wkt_field(message.descriptor(), "paths").accessor() {
+                    Some(FieldAccessor::Repeated(accessor)) => {
+                        for path in paths {
+                            accessor.push(message, Box::new(path)).expect(FAILED_ACCESS_ERROR);
+                        }
+                        Ok(())
+                    }
+                    _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+                }
+            }
+            ("google.protobuf.Struct", value @ Json::Object(_)) => {
+                let field = find_wkt_field(message.descriptor(), "fields");
+                self.merge_field(message, field, value)
+            }
+            ("google.protobuf.ListValue", value @ Json::Array(_)) => {
+                let field = find_wkt_field(message.descriptor(), "values");
+                self.merge_field(message, field, value)
+            }
+            ("google.protobuf.Value", value) => {
+                let name =
+                    match value {
+                        Json::Null => "null_value",
+                        Json::Number(_) => "number_value",
+                        Json::String(_) => "string_value",
+                        Json::Bool(_) => "bool_value",
+                        Json::Object(_) => "struct_value",
+                        Json::Array(_) => "list_value",
+                    };
+                let field = find_wkt_field(message.descriptor(), name);
+                self.merge_field(message, field, value)
+            }
+            ("google.protobuf.Any", _)
+            | ("google.protobuf.Timestamp", _)
+            | ("google.protobuf.Duration", _)
+            | ("google.protobuf.FieldMask", _)
+            | ("google.protobuf.Struct", _)
+            | ("google.protobuf.ListValue", _) => Err(self.error(ParseErrorKind::UnexpectedValue)),
+            (_, value) => {
+                // the wrapper types are parsed from their wrapped value
+                let field = find_wkt_field(message.descriptor(), "value");
+                self.merge_field(message, field, value)
+            }
+        }
+    }
+
+    fn merge_any(&mut self, message: &mut dyn AnyMessage<'a>, mut entries: Vec<(String, Json)>) -> Result<()> {
+        let type_url =
+            match entries.iter().position(|(key, _)| key == "@type") {
+                Some(index) => match entries.remove(index) {
+                    (_, Json::String(url)) => url,
+                    _ => {
+                        self.path.push(".@type".to_string());
+                        return Err(self.error(ParseErrorKind::UnexpectedValue));
+                    }
+                },
+                None if entries.is_empty() => return Ok(()),
+                None => return Err(self.error(ParseErrorKind::MissingAnyType)),
+            };
+
+        let descriptor =
+            get_type_name(&type_url)
+                .and_then(|name| self.pool.find_message_in_pool(name))
+                .ok_or_else(|| self.error(ParseErrorKind::UnresolvedAny(type_url.to_string())))?;
+        let mut instance = descriptor.new_instance().ok_or_else(|| self.error(ParseErrorKind::UnresolvedAny(type_url.to_string())))?;
+        let instance = instance.as_message_mut().expect(INVALID_VALUE_TYPE);
+        if is_well_known_type(descriptor) {
+            let mut value = None;
+            for (key, entry) in entries {
+                if key == "value" && value.is_none() {
+                    value = Some(entry);
+                    continue;
+                }
+                self.path.push(format!(".{}", key));
+                if key == "value" {
+                    return Err(self.error(ParseErrorKind::DuplicateField(key)));
+                }
+                if !self.options.ignore_unknown_fields {
+                    return Err(self.error(ParseErrorKind::UnknownField(key)));
+                }
+                self.path.pop();
+            }
+            self.path.push(".value".to_string());
+            self.merge_message(instance, value.unwrap_or(Json::Null))?;
+            self.path.pop();
+        } else {
+            self.merge_fields(instance, entries)?;
+        }
+
+        let value = instance.write_to_vec().expect(WRITE_VEC_ERROR);
+        self.set_wkt_field(message, "type_url", Box::new(type_url));
+        self.set_wkt_field(message, "value", Box::new(value));
+        Ok(())
+    }
+}
@@ -0,0 +1,335 @@
+use super::{is_well_known_type, json_name, JsonOptions, PrintError};
+use crate::reflect::access::FieldAccessor;
+use crate::reflect::{
+    get_single, is_implicit_default, AnyMessage, AnyValue, FieldDescriptor, FieldType, MessageDescriptor,
+    FAILED_ACCESS_ERROR, INVALID_VALUE_TYPE, INVALID_WELL_KNOWN_TYPE, MISREPORTED_LEN,
+};
+use crate::wkt::any::get_type_name;
+use crate::wkt::{duration, timestamp};
+use std::fmt::{self, Display};
+
+type Result = std::result::Result<(), PrintError>;
+
+fn get_i64<'a>(message: &(dyn AnyMessage<'a> + 'a), name: &str) -> i64 {
+    get_single(message, name).map_or(0, |v| *v.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))
+}
+
+fn get_i32<'a>(message: &(dyn AnyMessage<'a> + 'a), name: &str) -> i32 {
+    get_single(message, name).map_or(0, |v| *v.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))
+}
+
+fn write_string(output: &mut dyn fmt::Write, value: &str) -> fmt::Result {
+    output.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => output.write_str("\\\"")?,
+            '\\' => output.write_str("\\\\")?,
+            '\u{8}' => output.write_str("\\b")?,
+            '\u{c}' => output.write_str("\\f")?,
+            '\n' => output.write_str("\\n")?,
+            '\r' => output.write_str("\\r")?,
+            '\t' => output.write_str("\\t")?,
+            c if c < ' ' => write!(output, "\\u{:04x}", c as u32)?,
+            c => output.write_char(c)?,
+        }
+    }
+    output.write_char('"')
+}
+
+fn write_float<T: Into<f64> + Display + Copy>(output: &mut dyn fmt::Write, value: T) -> fmt::Result {
+    let wide: f64 = value.into();
+    if wide.is_nan() {
+        output.write_str("\"NaN\"")
+    } else if wide.is_infinite() {
+        output.write_str(if wide > 0.0 { "\"Infinity\"" } else { "\"-Infinity\"" })
+    } else {
+        write!(output, "{}", value)
+    }
+}
+
+/// Prints messages as JSON, resolving `Any` values with the pool containing a root message type
+pub(super) struct Printer<'a, 'w> {
+    pool: &'a MessageDescriptor<'a>,
+    output: &'w mut dyn fmt::Write,
+    options: JsonOptions,
+}
+
+impl<'a, 'w> Printer<'a, 'w> {
+    pub(super) fn new(pool: &'a MessageDescriptor<'a>, output: &'w mut dyn fmt::Write, options: JsonOptions) -> Printer<'a, 'w> {
+        Printer { pool, output, options }
+    }
+
+    pub(super) fn print_message(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        if is_well_known_type(message.descriptor()) {
+            self.print_well_known_type(message)
+        } else {
+            self.output.write_char('{')?;
+            self.print_fields(message, true)?;
+            self.output.write_char('}')?;
+            Ok(())
+        }
+    }
+
+    fn print_name(&mut self, field: &FieldDescriptor<'a>, first: &mut bool) -> Result {
+        if !*first {
+            self.output.write_char(',')?;
+        }
+        *first = false;
+        if self.options.preserve_proto_field_names {
+            write_string(self.output, field.name())?;
+        } else {
+            write_string(self.output, &json_name(field))?;
+        }
+        self.output.write_char(':')?;
+        Ok(())
+    }
+
+    fn print_fields(&mut self, message: &(dyn AnyMessage<'a> + 'a), mut first: bool) -> Result {
+        for field in message.descriptor().fields() {
+            match field.accessor().expect("message field had no accessor") {
+                FieldAccessor::Single(accessor) => {
+                    if let Some(value) = accessor.get(message).expect(FAILED_ACCESS_ERROR) {
+                        if self.options.always_print_primitive_fields || !is_implicit_default(field, value) {
+                            self.print_name(field, &mut first)?;
+                            self.print_value(field.field_type(), value)?;
+                        }
+                    }
+                }
+                FieldAccessor::Repeated(accessor) => {
+                    let len = accessor.len(message).expect(FAILED_ACCESS_ERROR);
+                    if len != 0 || self.options.always_print_primitive_fields {
+                        self.print_name(field, &mut first)?;
+                        self.output.write_char('[')?;
+                        for index in 0..len {
+                            if index != 0 {
+                                self.output.write_char(',')?;
+                            }
+                            let value = accessor.get(message, index).expect(FAILED_ACCESS_ERROR).expect(MISREPORTED_LEN);
+                            self.print_value(field.field_type(), value)?;
+                        }
+                        self.output.write_char(']')?;
+                    }
+                }
+                FieldAccessor::Map(accessor) => {
+                    if self.options.always_print_primitive_fields || accessor.iter(message).expect(FAILED_ACCESS_ERROR).next().is_some() {
+                        self.print_name(field, &mut first)?;
+                        self.print_map(field, message)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the entries of a map field as a JSON object
+    fn print_map(&mut self, field: &FieldDescriptor<'a>, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let (key_field, value_field) =
+            match field.field_type() {
+                FieldType::Message(m) => (&m.fields()[0], &m.fields()[1]),
+                _ => unreachable!("map accessor requires field type of message")
+            };
+        match field.accessor() {
+            Some(FieldAccessor::Map(accessor)) => {
+                self.output.write_char('{')?;
+                for (index, (key, value)) in accessor.iter(message).expect(FAILED_ACCESS_ERROR).enumerate() {
+                    if index != 0 {
+                        self.output.write_char(',')?;
+                    }
+                    self.print_map_key(key_field.field_type(), key)?;
+                    self.output.write_char(':')?;
+                    self.print_value(value_field.field_type(), value)?;
+                }
+                self.output.write_char('}')?;
+                Ok(())
+            }
+            _ => unreachable!("field is not a map")
+        }
+    }
+
+    fn print_map_key(&mut self, field_type: &FieldType<'a>, key: &(dyn AnyValue<'a> + 'a)) -> Result {
+        match field_type {
+            FieldType::String => write_string(self.output, key.downcast_ref::<String>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Bool => write!(self.output, "\"{}\"", key.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Int32 | FieldType::Sint32 | FieldType::Sfixed32 => write!(self.output, "\"{}\"", key.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Int64 | FieldType::Sint64 | FieldType::Sfixed64 => write!(self.output, "\"{}\"", key.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Uint32 | FieldType::Fixed32 => write!(self.output, "\"{}\"", key.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Uint64 | FieldType::Fixed64 => write!(self.output, "\"{}\"", key.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE))?,
+            _ => unreachable!("invalid map key type"),
+        }
+        Ok(())
+    }
+
+    fn print_value(&mut self, field_type: &FieldType<'a>, value: &(dyn AnyValue<'a> + 'a)) -> Result {
+        match field_type {
+            FieldType::Bool => write!(self.output, "{}", value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Int32 | FieldType::Sint32 | FieldType::Sfixed32 => write!(self.output, "{}", value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Uint32 | FieldType::Fixed32 => write!(self.output, "{}", value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Int64 | FieldType::Sint64 | FieldType::Sfixed64 => write!(self.output, "\"{}\"", value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Uint64 | FieldType::Fixed64 => write!(self.output, "\"{}\"", value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Float => write_float(self.output, *value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Double => write_float(self.output, *value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::String => write_string(self.output, value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE))?,
+            FieldType::Bytes => write!(self.output, "\"{}\"", base64::encode(value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE)))?,
+            FieldType::Enum(e) => {
+                let value = value.as_enum().expect(INVALID_VALUE_TYPE);
+                if e.full_name() == "google.protobuf.NullValue" {
+                    self.output.write_str("null")?;
+                } else if self.options.print_enums_as_ints {
+                    write!(self.output, "{}", value.get_i32())?;
+                } else {
+                    match value.get() {
+                        Some(defined) => write_string(self.output, defined.name())?,
+                        None => write!(self.output, "{}", value.get_i32())?,
+                    }
+                }
+            }
+            FieldType::Message(_) | FieldType::Group(_) => self.print_message(value.as_message().expect(INVALID_VALUE_TYPE))?,
+        }
+        Ok(())
+    }
+
+    fn print_well_known_type(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        match message.descriptor().full_name() {
+            "google.protobuf.Any" => self.print_any(message),
+            "google.protobuf.Timestamp" => self.print_timestamp(message),
+            "google.protobuf.Duration" => self.print_duration(message),
+            "google.protobuf.FieldMask" => self.print_field_mask(message),
+            "google.protobuf.Struct" => {
+                // a struct is printed as the object in its map field
+                let field = message.descriptor().find_field_by_name("fields").expect(INVALID_WELL_KNOWN_TYPE);
+                self.print_map(field, message)
+            }
+            "google.protobuf.Value" => self.print_struct_value(message),
+            "google.protobuf.ListValue" => {
+                let field = message.descriptor().find_field_by_name("values").expect(INVALID_WELL_KNOWN_TYPE);
+                match field.accessor() {
+                    Some(FieldAccessor::Repeated(accessor)) => {
+                        self.output.write_char('[')?;
+                        for index in 0..accessor.len(message).expect(FAILED_ACCESS_ERROR) {
+                            if index != 0 {
+                                self.output.write_char(',')?;
+                            }
+                            let value = accessor.get(message, index).expect(FAILED_ACCESS_ERROR).expect(MISREPORTED_LEN);
+                            self.print_value(field.field_type(), value)?;
+                        }
+                        self.output.write_char(']')?;
+                        Ok(())
+                    }
+                    _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+                }
+            }
+            _ => {
+                // the wrapper types are printed as their wrapped value
+                let field = message.descriptor().find_field_by_name("value").expect(INVALID_WELL_KNOWN_TYPE);
+                let value = get_single(message, "value").expect(INVALID_WELL_KNOWN_TYPE);
+                self.print_value(field.field_type(), value)
+            }
+        }
+    }
+
+    fn print_any(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let type_url = get_single(message, "type_url").map_or("", |v| v.downcast_ref::<String>().expect(INVALID_VALUE_TYPE));
+        let value = get_single(message, "value").map_or(&[][..], |v| v.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE));
+
+        if type_url.is_empty() && value.is_empty() {
+            self.output.write_str("{}")?;
+            return Ok(());
+        }
+
+        let pool = self.pool;
+        let descriptor =
+            get_type_name(type_url)
+                .and_then(|name| pool.find_message_in_pool(name))
+                .ok_or_else(|| PrintError::UnresolvedAny(type_url.to_string()))?;
+        let mut instance = descriptor.new_instance().ok_or_else(|| PrintError::UnresolvedAny(type_url.to_string()))?;
+        let instance = instance.as_message_mut().expect(INVALID_VALUE_TYPE);
+        instance.merge_from_slice(value)?;
+
+        self.output.write_str("{\"@type\":")?;
+        write_string(self.output, type_url)?;
+        if is_well_known_type(descriptor) {
+            self.output.write_str(",\"value\":")?;
+            self.print_message(instance)?;
+        } else {
+            self.print_fields(instance, false)?;
+        }
+        self.output.write_char('}')?;
+        Ok(())
+    }
+
+    fn print_timestamp(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let seconds = get_i64(message, "seconds");
+        let nanos = get_i32(message, "nanos");
+        if !timestamp::is_valid(seconds, nanos) {
+            return Err(PrintError::InvalidTimestamp);
+        }
+
+        self.output.write_char('"')?;
+        timestamp::write_rfc3339(self.output, seconds, nanos)?;
+        self.output.write_char('"')?;
+        Ok(())
+    }
+
+    fn print_duration(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let seconds = get_i64(message, "seconds");
+        let nanos = get_i32(message, "nanos");
+        if !duration::is_valid(seconds, nanos) {
+            return Err(PrintError::InvalidDuration);
+        }
+
+        self.output.write_char('"')?;
+        duration::write_duration(self.output, seconds, nanos)?;
+        self.output.write_char('"')?;
+        Ok(())
+    }
+
+    fn print_field_mask(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let accessor =
+            match message.descriptor().find_field_by_name("paths").and_then(FieldDescriptor::accessor) {
+                Some(FieldAccessor::Repeated(accessor)) => accessor,
+                _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+            };
+
+        let mut joined = String::new();
+        for index in 0..accessor.len(message).expect(FAILED_ACCESS_ERROR) {
+            let path = accessor.get(message, index).expect(FAILED_ACCESS_ERROR).expect(MISREPORTED_LEN);
+            let path = path.downcast_ref::<String>().expect(INVALID_VALUE_TYPE);
+            // paths must survive a round trip through lowerCamelCase
+            let mut chars = path.chars().peekable();
+            while let Some(c) = chars.next() {
+                if c == '_' {
+                    match chars.next() {
+                        Some(next) if next.is_ascii_lowercase() => joined.push(next.to_ascii_uppercase()),
+                        _ => return Err(PrintError::InvalidFieldMask(path.to_string())),
+                    }
+                } else if c.is_ascii_uppercase() {
+                    return Err(PrintError::InvalidFieldMask(path.to_string()));
+                } else {
+                    joined.push(c);
+                }
+            }
+            if index + 1 != accessor.len(message).expect(FAILED_ACCESS_ERROR) {
+                joined.push(',');
+            }
+        }
+        write_string(self.output, &joined)?;
+        Ok(())
+    }
+
+    fn print_struct_value(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result {
+        let oneof = message.descriptor().find_oneof_by_name("kind").expect(INVALID_WELL_KNOWN_TYPE);
+        let field = oneof.get_set_case(message).ok_or(PrintError::InvalidValue)?;
+        let value =
+            match field.accessor() {
+                Some(FieldAccessor::Single(accessor)) => accessor.get(message).expect(FAILED_ACCESS_ERROR).ok_or(PrintError::InvalidValue)?,
+                _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+            };
+        if let Some(number) = value.downcast_ref::<f64>() {
+            if !number.is_finite() {
+                return Err(PrintError::InvalidValue);
+            }
+        }
+        self.print_value(field.field_type(), value)
+    }
+}
+
@@ -93,11 +93,14 @@ pub mod prelude {
     pub use crate::EnumValue::{self, Defined};
     pub use crate::ExtendableMessage;
     pub use crate::LiteMessage;
+    pub use crate::UnknownFields;
     #[cfg(feature = "reflection")]
     pub use crate::Message;
 }
 pub mod collections;
 pub mod io;
+#[cfg(feature = "json")]
+pub mod json;
 #[cfg(all(feature = "reflection"))]
 pub mod wkt;
 #[cfg(feature = "reflection")]
@@ -106,17 +109,19 @@ pub use crate::generated::google_protobuf_descriptor_proto as descriptor;
 pub mod plugin;
 #[cfg(feature = "reflection")]
 pub mod reflect;
+#[cfg(feature = "reflection")]
+pub mod text_format;
 
 pub use extend::{
     Extension, ExtensionField, ExtensionIdentifier, ExtendableMessage, ExtensionRegistry,
     ExtensionSet, RepeatedExtension, RepeatedExtensionField,
 };
 
-use crate::io::{Tag, WireType};
-use std::collections::HashMap;
+use crate::io::{FieldNumber, Tag, WireType};
 use std::convert::TryFrom;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicI32, Ordering};
 
 /// A Protocol Buffers message capable of writing itself to a coded output or reading itself from a coded input.
 /// 
@@ -198,6 +203,16 @@ pub trait CodedMessage {
         self.merge_from(&mut reader)
     }
 
+    /// Merges an instance of self from a byte slice. This reads values directly from the slice,
+    /// which is faster than reading the same data through [`merge_from_reader`].
+    /// 
+    /// [`merge_from_reader`]: #method.merge_from_reader
+    #[inline]
+    fn merge_from_slice(&mut self, slice: &[u8]) -> io::InputResult<()> {
+        let mut input = io::CodedInput::from_slice(slice);
+        self.merge_from(&mut input)
+    }
+
     /// Calculates the size of the message and returns it as an 32-bit integer or None if the message is larger than `i32::MAX`
     /// 
     /// # Example
@@ -317,7 +332,7 @@ pub trait CodedMessage {
     fn write_to_vec(&self) -> Result<Vec<u8>, io::OutputError> {
         if let Some(size) = self.calculate_size() {
             let mut out = Vec::with_capacity(size as usize);
-            self.write(&mut out)?;
+            self.write_to(&mut io::CodedOutput::with_cached_sizes(&mut out))?;
             Ok(out)
         } else {
             Err(io::OutputError::ValueTooLarge)
@@ -347,8 +362,9 @@ pub trait CodedMessage {
     #[cfg(not(checked_size))]
     #[inline]
     fn write_to_vec(&self) -> Result<Vec<u8>, io::OutputError> {
-        let mut out = Vec::with_capacity(self.calculate_size() as usize);
-        self.write(&mut out)?;
+        let size = self.calculate_size();
+        let mut out = Vec::with_capacity(size as usize);
+        self.write_to(&mut io::CodedOutput::with_cached_sizes(&mut out))?;
         Ok(out)
     }
 
@@ -380,6 +396,14 @@ pub trait CodedMessage {
     fn is_initialized(&self) -> bool {
         true
     }
+
+    /// Gets the size of the message from the last time it was calculated,
+    /// or None if the message doesn't cache its size
+    #[doc(hidden)]
+    #[inline]
+    fn cached_size(&self) -> Option<i32> {
+        None
+    }
 }
 
 /// A LITE Protocol Buffers message.
@@ -438,12 +462,81 @@ pub trait LiteMessage:
         Self::read_new_from_input(&mut reader)
     }
 
+    /// Reads a new instance of Self from the specified byte slice using a [`CodedInput`]
+    /// 
+    /// [`CodedInput`]: io/struct.CodedInput.html
+    fn read_new_from_slice(slice: &[u8]) -> io::InputResult<Self> {
+        let mut input = io::CodedInput::from_slice(slice);
+        Self::read_new_from_input(&mut input)
+    }
+
     /// Reads a new instance of Self from the specified CodedInput
     fn read_new_from_input(input: &mut io::CodedInput) -> io::InputResult<Self> {
         let mut instance = Self::new();
         instance.merge_from(input)?;
         Ok(instance)
     }
+
+    /// Reads a new instance of Self prefixed with its length from the specified [`Read`].
+    /// This only reads the bytes of the one message, so it can be used to read successive messages
+    /// from a stream. To iterate over every message in a stream, use [`DelimitedMessages`].
+    /// The message is limited to [`CodedInput::DEFAULT_SIZE_LIMIT`] bytes.
+    /// 
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    /// [`DelimitedMessages`]: io/struct.DelimitedMessages.html
+    /// [`CodedInput::DEFAULT_SIZE_LIMIT`]: io/struct.CodedInput.html#associatedconstant.DEFAULT_SIZE_LIMIT
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::timestamp::Timestamp;
+    /// 
+    /// fn main() -> protrust::io::InputResult<()> {
+    ///     let data = [2, 8, 3, 2, 16, 5];
+    ///     let mut input = data.as_ref();
+    /// 
+    ///     assert_eq!(*Timestamp::read_delimited(&mut input)?.seconds(), 3);
+    ///     assert_eq!(*Timestamp::read_delimited(&mut input)?.nanos(), 5);
+    ///     assert!(input.is_empty());
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    fn read_delimited(read: &mut dyn std::io::Read) -> io::InputResult<Self> {
+        let mut instance = Self::new();
+        io::CodedInput::new(read).read_message(&mut instance)?;
+        Ok(instance)
+    }
+
+    /// Writes the message prefixed with its length to the specified [`Write`], 
+    /// which can be read back with [`read_delimited`].
+    /// 
+    /// [`Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`read_delimited`]: #method.read_delimited
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::timestamp::Timestamp;
+    /// 
+    /// fn main() -> protrust::io::OutputResult {
+    ///     let mut timestamp = Timestamp::new();
+    ///     *timestamp.seconds_mut() = 3;
+    /// 
+    ///     let mut output = Vec::new();
+    ///     timestamp.write_delimited(&mut output)?;
+    ///     timestamp.write_delimited(&mut output)?;
+    /// 
+    ///     assert_eq!(&output, &[2, 8, 3, 2, 8, 3]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    fn write_delimited(&self, write: &mut dyn std::io::Write) -> io::OutputResult {
+        io::CodedOutput::new(write).write_message(self)
+    }
 }
 
 /// A Protocol Buffers message. This exposes the static descriptor that describes the generated message.
@@ -453,6 +546,50 @@ pub trait Message: LiteMessage {
     fn descriptor() -> &'static reflect::MessageDescriptor<'static>;
 }
 
+/// A message that keeps the fields it read that weren't known by it
+pub trait UnknownFields: LiteMessage {
+    /// Gets the fields read by this message that weren't known by it
+    fn unknown_fields(&self) -> &UnknownFieldSet;
+
+    /// Gets a mutable reference to the fields read by this message that weren't known by it
+    fn unknown_fields_mut(&mut self) -> &mut UnknownFieldSet;
+}
+
+/// The size of a message from the last time it was calculated, reused while the message is written
+#[doc(hidden)]
+#[derive(Default)]
+pub struct CachedSize(AtomicI32);
+
+impl CachedSize {
+    #[inline]
+    pub fn get(&self) -> i32 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn set(&self, size: i32) {
+        self.0.store(size, Ordering::Relaxed)
+    }
+}
+
+impl Clone for CachedSize {
+    fn clone(&self) -> CachedSize {
+        CachedSize::default()
+    }
+}
+
+impl PartialEq for CachedSize {
+    fn eq(&self, _other: &CachedSize) -> bool {
+        true
+    }
+}
+
+impl Debug for CachedSize {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("CachedSize")
+    }
+}
+
 /// The error result for when an enum value is undefined
 pub struct VariantUndefinedError;
 
@@ -574,6 +711,11 @@ enum ValueSize<T> {
     Func(fn(&T) -> Option<i32>),
     #[cfg(not(checked_size))]
     Func(fn(&T) -> i32),
+    /// A message value, with a function to get its size from its cached size
+    #[cfg(checked_size)]
+    Message(fn(&T) -> Option<i32>, fn(&T) -> Option<i32>),
+    #[cfg(not(checked_size))]
+    Message(fn(&T) -> i32, fn(&T) -> Option<i32>),
 }
 
 const fn is_packed(tag: u32) -> bool {
@@ -606,7 +748,7 @@ impl<T> Codec<T> {
     fn calculate_size(&self, value: &T) -> Option<i32> {
         match self.size {
             ValueSize::Fixed(s) => Some(s),
-            ValueSize::Func(f) => (f)(value),
+            ValueSize::Func(f) | ValueSize::Message(f, _) => (f)(value),
         }
     }
 
@@ -615,7 +757,27 @@ impl<T> Codec<T> {
     fn calculate_size(&self, value: &T) -> i32 {
         match self.size {
             ValueSize::Fixed(s) => s,
-            ValueSize::Func(f) => (f)(value),
+            ValueSize::Func(f) | ValueSize::Message(f, _) => (f)(value),
+        }
+    }
+
+    /// Gets the size of the value, using the cached size of message values
+    #[cfg(checked_size)]
+    #[inline]
+    fn cached_size(&self, value: &T) -> Option<i32> {
+        match self.size {
+            ValueSize::Message(_, f) => (f)(value),
+            _ => self.calculate_size(value),
+        }
+    }
+
+    /// Gets the size of the value, using the cached size of message values
+    #[cfg(not(checked_size))]
+    #[inline]
+    fn cached_size(&self, value: &T) -> Option<i32> {
+        match self.size {
+            ValueSize::Message(_, f) => (f)(value),
+            _ => Some(self.calculate_size(value)),
         }
     }
 
@@ -973,7 +1135,7 @@ impl<M: LiteMessage> Codec<M> {
             Codec {
                 start: Tag::new_unchecked(tag),
                 end: None,
-                size: ValueSize::Func(|m| io::sizes::message(m)),
+                size: ValueSize::Message(|m| io::sizes::message(m), |m| io::sizes::cached_message(m)),
                 merge: |i, v| {
                     if let Some(v) = v {
                         i.read_message(v)?;
@@ -1024,7 +1186,7 @@ impl<M: ExtendableMessage> Codec<M> {
             Codec {
                 start: Tag::new_unchecked(tag),
                 end: None,
-                size: ValueSize::Func(|m| io::sizes::message(m)),
+                size: ValueSize::Message(|m| io::sizes::message(m), |m| io::sizes::cached_message(m)),
                 merge: |i, v| {
                     if let Some(v) = v {
                         i.read_message(v)?;
@@ -1068,28 +1230,132 @@ impl<E: Enum> Codec<EnumValue<E>> {
     }
 }
 
+/// A set of fields read from the input that weren't known by the message that read them.
+///
+/// Fields are kept in the order they were read, including every occurrence of a field that appeared multiple times,
+/// and are written back out in that order after the message's known fields.
+///
+/// # Example
+///
+/// ```
+/// use protrust::{CodedMessage, LiteMessage, UnknownField, UnknownFields};
+/// use protrust::io::FieldNumber;
+/// use protrust::wkt::empty::Empty;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut empty = Empty::read_new_from_slice(&[8, 1, 8, 2, 18, 1, 3])?;
+///
+/// let one = FieldNumber::new(1).unwrap();
+/// let two = FieldNumber::new(2).unwrap();
+/// let values: Vec<_> = empty.unknown_fields().get(one).collect();
+/// assert_eq!(values, [&UnknownField::Varint(1), &UnknownField::Varint(2)]);
+///
+/// empty.unknown_fields_mut().remove(one);
+/// empty.unknown_fields_mut().add(two, UnknownField::Bit32(4));
+/// assert_eq!(empty.write_to_vec()?, [18, 1, 3, 21, 4, 0, 0, 0]);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq, Default)]
-#[doc(hidden)]
-pub struct UnknownFieldSet(HashMap<Tag, UnknownField>);
+pub struct UnknownFieldSet(Vec<(FieldNumber, UnknownField)>);
 
+/// The value of a field in an [`UnknownFieldSet`](struct.UnknownFieldSet.html)
 #[derive(Clone, Debug, PartialEq)]
-enum UnknownField {
+pub enum UnknownField {
+    /// A variable length integer value
     Varint(u64),
+    /// A 64-bit value
     Bit64(u64),
+    /// A length delimited value, such as a string, bytes, message, or packed repeated field
     LengthDelimited(Vec<u8>),
+    /// A group containing its own set of fields
     Group(UnknownFieldSet),
+    /// A 32-bit value
     Bit32(u32),
 }
 
+impl UnknownField {
+    /// Gets the wire type used to write this value. Groups are written with a `StartGroup` tag
+    pub fn wire_type(&self) -> WireType {
+        match self {
+            UnknownField::Varint(_) => WireType::Varint,
+            UnknownField::Bit64(_) => WireType::Bit64,
+            UnknownField::LengthDelimited(_) => WireType::LengthDelimited,
+            UnknownField::Group(_) => WireType::StartGroup,
+            UnknownField::Bit32(_) => WireType::Bit32,
+        }
+    }
+}
+
 impl UnknownFieldSet {
+    /// Creates a new empty set
     pub fn new() -> UnknownFieldSet {
-        UnknownFieldSet(HashMap::new())
+        UnknownFieldSet(Vec::new())
+    }
+
+    /// Gets the number of values in the set, counting each occurrence of a field
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Gets whether the set contains no fields
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the fields in the set in the order they were read or added
+    pub fn iter(&self) -> impl Iterator<Item = (FieldNumber, &UnknownField)> + '_ {
+        self.0.iter().map(|(number, value)| (*number, value))
+    }
+
+    /// Iterates over each value of the field with the specified number in the order they were read or added
+    pub fn get(&self, number: FieldNumber) -> impl Iterator<Item = &UnknownField> + '_ {
+        self.0.iter().filter(move |(n, _)| *n == number).map(|(_, value)| value)
     }
 
+    /// Gets whether the set contains a value for the field with the specified number
+    pub fn contains(&self, number: FieldNumber) -> bool {
+        self.0.iter().any(|(n, _)| *n == number)
+    }
+
+    /// Adds a value for the field with the specified number to the end of the set
+    pub fn add(&mut self, number: FieldNumber, value: UnknownField) {
+        self.0.push((number, value));
+    }
+
+    /// Removes every value of the field with the specified number, returning whether any values were removed
+    pub fn remove(&mut self, number: FieldNumber) -> bool {
+        let len = self.0.len();
+        self.0.retain(|(n, _)| *n != number);
+        self.0.len() != len
+    }
+
+    /// Keeps only the fields the predicate returns `true` for
+    pub fn retain<F: FnMut(FieldNumber, &UnknownField) -> bool>(&mut self, mut f: F) {
+        self.0.retain(|(number, value)| f(*number, value))
+    }
+
+    /// Removes all fields from the set
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    #[doc(hidden)]
     pub fn write_to(&self, output: &mut io::CodedOutput) -> io::OutputResult {
-        for field in &self.0 {
-            output.write_tag(*field.0)?;
-            match field.1 {
+        let mut sorted;
+        let mut unsorted;
+        let fields: &mut dyn Iterator<Item = &(FieldNumber, UnknownField)> = if output.is_deterministic() {
+            let mut fields: Vec<_> = self.0.iter().collect();
+            fields.sort_by_key(|(number, _)| *number);
+            sorted = fields.into_iter();
+            &mut sorted
+        } else {
+            unsorted = self.0.iter();
+            &mut unsorted
+        };
+        for (number, value) in fields {
+            output.write_tag(Tag::new(*number, value.wire_type()))?;
+            match value {
                 UnknownField::Varint(v) => {
                     output.write_uint64(*v)?;
                 }
@@ -1101,7 +1367,7 @@ impl UnknownFieldSet {
                 }
                 UnknownField::Group(v) => {
                     v.write_to(output)?;
-                    output.write_tag(Tag::new(field.0.number(), WireType::EndGroup))?;
+                    output.write_tag(Tag::new(*number, WireType::EndGroup))?;
                 }
                 UnknownField::Bit32(v) => {
                     output.write_fixed32(*v)?;
@@ -1112,11 +1378,12 @@ impl UnknownFieldSet {
     }
 
     #[cfg(checked_size)]
+    #[doc(hidden)]
     pub fn calculate_size(&self) -> Option<i32> {
         let mut size = 0i32;
-        for field in &self.0 {
-            size = size.checked_add(io::sizes::uint32(field.0.get()))?;
-            match field.1 {
+        for (number, value) in &self.0 {
+            size = size.checked_add(io::sizes::uint32(Tag::new(*number, value.wire_type()).get()))?;
+            match value {
                 UnknownField::Varint(v) => {
                     size = size.checked_add(io::sizes::uint64(*v))?;
                 }
@@ -1128,10 +1395,7 @@ impl UnknownFieldSet {
                 }
                 UnknownField::Group(v) => {
                     size = size.checked_add(v.calculate_size()?)?;
-                    size = size.checked_add(io::sizes::uint32(Tag::new(
-                        field.0.number(),
-                        WireType::EndGroup,
-                    )))?;
+                    size = size.checked_add(io::sizes::uint32(Tag::new(*number, WireType::EndGroup).get()))?;
                 }
                 UnknownField::Bit32(v) => {
                     size = size.checked_add(io::sizes::fixed32(*v))?;
@@ -1142,11 +1406,12 @@ impl UnknownFieldSet {
     }
 
     #[cfg(not(checked_size))]
+    #[doc(hidden)]
     pub fn calculate_size(&self) -> i32 {
         let mut size = 0i32;
-        for field in &self.0 {
-            size += io::sizes::uint32(field.0.get());
-            match field.1 {
+        for (number, value) in &self.0 {
+            size += io::sizes::uint32(Tag::new(*number, value.wire_type()).get());
+            match value {
                 UnknownField::Varint(v) => {
                     size += io::sizes::uint64(*v);
                 }
@@ -1158,7 +1423,7 @@ impl UnknownFieldSet {
                 }
                 UnknownField::Group(v) => {
                     size += v.calculate_size();
-                    size += io::sizes::uint32(Tag::new(field.0.number(), WireType::EndGroup).get());
+                    size += io::sizes::uint32(Tag::new(*number, WireType::EndGroup).get());
                 }
                 UnknownField::Bit32(v) => {
                     size += io::sizes::fixed32(*v);
@@ -1168,45 +1433,48 @@ impl UnknownFieldSet {
         size
     }
 
+    #[doc(hidden)]
     pub fn merge_from(&mut self, tag: Tag, input: &mut io::CodedInput) -> io::InputResult<()> {
-        let wt = tag.wire_type();
-        match wt {
+        if input.discard_unknown_fields() {
+            return input.skip(tag);
+        }
+
+        let number = tag.number();
+        match tag.wire_type() {
             WireType::Varint => {
-                self.0
-                    .insert(tag, UnknownField::Varint(input.read_uint64()?));
+                self.0.push((number, UnknownField::Varint(input.read_uint64()?)));
             }
             WireType::Bit64 => {
-                self.0
-                    .insert(tag, UnknownField::Bit64(input.read_fixed64()?));
+                self.0.push((number, UnknownField::Bit64(input.read_fixed64()?)));
             }
             WireType::LengthDelimited => {
-                self.0
-                    .insert(tag, UnknownField::LengthDelimited(input.read_bytes()?));
+                self.0.push((number, UnknownField::LengthDelimited(input.read_bytes()?)));
             }
             WireType::StartGroup => {
-                let end = Tag::new(tag.number(), WireType::EndGroup);
+                let end = Tag::new(number, WireType::EndGroup);
                 let mut set = UnknownFieldSet::new();
-                while let Some(tag) = input.read_tag()? {
-                    match tag.get() {
-                        _ if end == tag => break,
-                        _ => set.merge_from(tag, input)?,
+                input.recurse(|input| {
+                    while let Some(tag) = input.read_tag()? {
+                        match tag.get() {
+                            _ if end == tag => break,
+                            _ => set.merge_from(tag, input)?,
+                        }
                     }
-                }
-                self.0.insert(tag, UnknownField::Group(set));
+                    Ok(())
+                })?;
+                self.0.push((number, UnknownField::Group(set)));
             }
-            WireType::EndGroup => return Err(io::InputError::InvalidTag(tag.get())),
+            WireType::EndGroup => return Err(input.error(io::InputErrorKind::InvalidTag(tag.get()))),
             WireType::Bit32 => {
-                self.0
-                    .insert(tag, UnknownField::Bit32(input.read_fixed32()?));
+                self.0.push((number, UnknownField::Bit32(input.read_fixed32()?)));
             }
         }
 
         Ok(())
     }
 
+    #[doc(hidden)]
     pub fn merge(&mut self, other: &Self) {
-        for field in &other.0 {
-            self.0.insert(*field.0, field.1.clone());
-        }
+        self.0.extend(other.0.iter().cloned());
     }
 }
\ No newline at end of file
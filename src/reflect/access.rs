@@ -41,6 +41,11 @@ pub trait SingleFieldAccessor<'a>: Send + Sync {
         instance: &mut dyn AnyMessage<'a>,
     ) -> Result<'a, Option<Box<dyn AnyValue<'a> + 'a>>>;
     fn clear(&self, instance: &mut dyn AnyMessage<'a>) -> Result<'a, ()>;
+
+    /// Creates a value of this field's enum type from its number, or None if the field isn't an enum field
+    fn new_enum_value(&self, _number: i32) -> Option<Box<dyn AnyValue<'a> + 'a>> {
+        None
+    }
 }
 
 pub trait RepeatedFieldAccessor<'a>: Send + Sync {
@@ -79,6 +84,28 @@ pub trait RepeatedFieldAccessor<'a>: Send + Sync {
     ) -> Result<'a, Box<dyn AnyValue<'a>>>;
 
     fn clear(&self, instance: &mut dyn AnyMessage<'a>) -> Result<'a, ()>;
+
+    /// Creates an element of this field's enum type from its number, or None if the field isn't an enum field
+    fn new_enum_value(&self, _number: i32) -> Option<Box<dyn AnyValue<'a> + 'a>> {
+        None
+    }
+}
+
+/// Creates values of generated enum types from their numbers
+trait NewEnumValue {
+    fn new_enum_value(number: i32) -> Option<Box<dyn AnyValue<'static>>>;
+}
+
+impl<T> NewEnumValue for T {
+    default fn new_enum_value(_: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        None
+    }
+}
+
+impl<E: crate::Enum> NewEnumValue for crate::EnumValue<E> {
+    fn new_enum_value(number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        Some(Box::new(crate::EnumValue::<E>::from(number)))
+    }
 }
 
 // we use this to unconditionally extend our borrow of extensions to be static
@@ -161,6 +188,10 @@ impl<
             .ok_or(FieldAccessError::ExtensionNotFound)?
             .clear())
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 impl<
@@ -316,6 +347,10 @@ impl<
             Err(FieldAccessError::ExtensionNotFound)
         }
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 /// An accessor for accessing fields with a shared reference getter and unique reference getter
@@ -385,6 +420,10 @@ impl<T: AnyMessage<'static>, V: AnyValue<'static> + Default>
         ) = Default::default();
         Ok(())
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 impl<T: AnyMessage<'static> + 'static, V: AnyValue<'static> + 'static>
@@ -501,6 +540,10 @@ impl<T: AnyMessage<'static> + 'static, V: AnyValue<'static> + 'static>
         field.clear();
         Ok(())
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 /// An accessor for accessing fields with a getter that returns an optional shared reference and unique reference getter
@@ -573,6 +616,10 @@ where
         ) = Default::default();
         Ok(())
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 pub struct VerboseFieldAccessor<T, V> {
@@ -648,6 +695,10 @@ impl<T: AnyMessage<'static>, V: AnyValue<'static>> SingleFieldAccessor<'static>
         );
         Ok(())
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
 
 pub trait MapFieldAccessor<'a>: Send + Sync {
@@ -686,6 +737,11 @@ pub trait MapFieldAccessor<'a>: Send + Sync {
         &self,
         instance: &'b mut dyn AnyMessage<'a>,
     ) -> Result<'a, Box<dyn Iterator<Item = (&'b dyn AnyValue<'a>, &'b mut dyn AnyValue<'a>)> + 'b>>;
+
+    /// Creates a value of this map's enum value type from its number, or None if the map's values aren't enums
+    fn new_enum_value(&self, _number: i32) -> Option<Box<dyn AnyValue<'a> + 'a>> {
+        None
+    }
 }
 
 impl<
@@ -846,4 +902,8 @@ impl<
             ), _>(|(k, v)| (k, v)),
         ))
     }
+
+    fn new_enum_value(&self, number: i32) -> Option<Box<dyn AnyValue<'static>>> {
+        <V as NewEnumValue>::new_enum_value(number)
+    }
 }
\ No newline at end of file
@@ -4,7 +4,7 @@ use crate::reflect::{
     AnyEnum, AnyMessage, AnyValue, CompositeScope, DynamicType, Descriptor, Syntax, EnumDescriptor, EnumValueDescriptor,
     FieldDescriptor, FieldLabel, FieldScope, FieldType, MessageDescriptor, OneofDescriptor, ValueType,
 };
-use crate::{CodedMessage, ExtensionRegistry, UnknownFieldSet};
+use crate::{CachedSize, CodedMessage, ExtensionRegistry, UnknownFieldSet};
 use std::any::TypeId;
 use hashbrown::{HashMap, hash_map::{Entry, RawEntryMut}};
 use std::fmt::{self, Debug, Formatter};
@@ -43,12 +43,129 @@ fn check_value<'a>(field: &FieldDescriptor<'a>, value: Box<dyn AnyValue<'a> + 'a
     }
 }
 
+/// Returns true if the value is the default value of the field type. Messages and groups never have a default value
+fn is_default_value<'a>(field_type: &FieldType, value: &(dyn AnyValue<'a> + 'a)) -> bool {
+    match field_type {
+        FieldType::Bool => !*value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE),
+        FieldType::Bytes => value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE).is_empty(),
+        FieldType::Double => *value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE) == 0.0,
+        FieldType::Enum(_) => value.as_enum().expect(INVALID_VALUE_TYPE).get_i32() == 0,
+        FieldType::Fixed32 | FieldType::Uint32 => *value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE) == 0,
+        FieldType::Fixed64 | FieldType::Uint64 => *value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE) == 0,
+        FieldType::Float => *value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE) == 0.0,
+        FieldType::Int32 | FieldType::Sfixed32 | FieldType::Sint32 => *value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE) == 0,
+        FieldType::Int64 | FieldType::Sfixed64 | FieldType::Sint64 => *value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE) == 0,
+        FieldType::String => value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE).is_empty(),
+        FieldType::Message(_) | FieldType::Group(_) => false,
+    }
+}
+
+/// Returns true if the field doesn't track presence and contains its default value,
+/// in which case it isn't written or printed
+pub(crate) fn is_implicit_default<'a>(field: &FieldDescriptor<'a>, value: &(dyn AnyValue<'a> + 'a)) -> bool {
+    if let FieldScope::Oneof(_) = field.scope() {
+        return false;
+    }
+    field.file().syntax() == Syntax::Proto3 && !field.is_extension() && is_default_value(field.field_type(), value)
+}
+
+/// Gets the value of a singular field of a well-known type by name, or None if the field isn't set
+pub(crate) fn get_single<'a, 'b>(message: &'b (dyn AnyMessage<'a> + 'a), name: &str) -> Option<&'b (dyn AnyValue<'a> + 'a)> {
+    match message.descriptor().find_field_by_name(name).and_then(FieldDescriptor::accessor) {
+        Some(FieldAccessor::Single(accessor)) => accessor.get(message).expect(FAILED_ACCESS_ERROR),
+        _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+    }
+}
+
+#[cfg(not(checked_size))]
+fn value_size<'a>(field_type: &FieldType, value: &(dyn AnyValue<'a> + 'a)) -> i32 {
+    match field_type {
+        FieldType::Bool => io::sizes::bool(*value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Bytes => io::sizes::bytes(value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Double => io::sizes::double(*value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Enum(_) => io::sizes::int32(value.as_enum().expect(INVALID_VALUE_TYPE).get_i32()),
+        FieldType::Fixed32 => io::sizes::fixed32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Fixed64 => io::sizes::fixed64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Float => io::sizes::float(*value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Group(_) => io::sizes::group(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Int32 => io::sizes::int32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Int64 => io::sizes::int64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Message(_) => io::sizes::message(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sfixed32 => io::sizes::sfixed32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sfixed64 => io::sizes::sfixed64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sint32 => io::sizes::sint32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sint64 => io::sizes::sint64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::String => io::sizes::string(value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Uint32 => io::sizes::uint32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Uint64 => io::sizes::uint64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE)),
+    }
+}
+
+#[cfg(checked_size)]
+fn value_size<'a>(field_type: &FieldType, value: &(dyn AnyValue<'a> + 'a)) -> Option<i32> {
+    match field_type {
+        FieldType::Bool => Some(io::sizes::bool(*value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Bytes => io::sizes::bytes(value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Double => Some(io::sizes::double(*value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Enum(_) => Some(io::sizes::int32(value.as_enum().expect(INVALID_VALUE_TYPE).get_i32())),
+        FieldType::Fixed32 => Some(io::sizes::fixed32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Fixed64 => Some(io::sizes::fixed64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Float => Some(io::sizes::float(*value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Group(_) => io::sizes::group(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Int32 => Some(io::sizes::int32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Int64 => Some(io::sizes::int64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Message(_) => io::sizes::message(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sfixed32 => Some(io::sizes::sfixed32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sfixed64 => Some(io::sizes::sfixed64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sint32 => Some(io::sizes::sint32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sint64 => Some(io::sizes::sint64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE))),
+        FieldType::String => io::sizes::string(value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Uint32 => Some(io::sizes::uint32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE))),
+        FieldType::Uint64 => Some(io::sizes::uint64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE))),
+    }
+}
+
+/// Gets the size of a value, using the cached size of message values
+fn cached_value_size<'a>(field_type: &FieldType, value: &(dyn AnyValue<'a> + 'a)) -> Option<i32> {
+    match field_type {
+        FieldType::Message(_) => io::sizes::cached_message(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        #[cfg(checked_size)]
+        _ => value_size(field_type, value),
+        #[cfg(not(checked_size))]
+        _ => Some(value_size(field_type, value)),
+    }
+}
+
+fn write_value<'a>(output: &mut io::CodedOutput, field_type: &FieldType, value: &(dyn AnyValue<'a> + 'a)) -> io::OutputResult {
+    match field_type {
+        FieldType::Bool => output.write_bool(*value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Bytes => output.write_bytes(value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Double => output.write_double(*value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Enum(_) => output.write_int32(value.as_enum().expect(INVALID_VALUE_TYPE).get_i32()),
+        FieldType::Fixed32 => output.write_fixed32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Fixed64 => output.write_fixed64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Float => output.write_float(*value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Group(_) => output.write_group(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Int32 => output.write_int32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Int64 => output.write_int64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Message(_) => output.write_message(&AnyMessageWrapper(value.as_message().expect(INVALID_VALUE_TYPE))),
+        FieldType::Sfixed32 => output.write_sfixed32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sfixed64 => output.write_sfixed64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sint32 => output.write_sint32(*value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Sint64 => output.write_sint64(*value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+        FieldType::String => output.write_string(value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Uint32 => output.write_uint32(*value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE)),
+        FieldType::Uint64 => output.write_uint64(*value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE)),
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct DynamicMessage<'a> {
     descriptor: &'a MessageDescriptor<'a>,
     fields: HashMap<FieldNumber, DynamicFieldValue<'a>>,
     oneofs: HashMap<usize, (usize, DynamicFieldValue<'a>)>,
     unknown_fields: UnknownFieldSet,
+    cached_size: CachedSize,
 }
 
 impl<'a> DynamicMessage<'a> {
@@ -64,6 +181,7 @@ impl<'a> DynamicMessage<'a> {
                                 .collect(),
                     oneofs: HashMap::new(),
                     unknown_fields: UnknownFieldSet::new(),
+                    cached_size: CachedSize::default(),
                 }
             },
             _ => {
@@ -72,6 +190,7 @@ impl<'a> DynamicMessage<'a> {
                     fields: HashMap::new(),
                     oneofs: HashMap::new(),
                     unknown_fields: UnknownFieldSet::new(),
+                    cached_size: CachedSize::default(),
                 }
             }
         }
@@ -131,6 +250,211 @@ impl<'a> DynamicMessage<'a> {
             }
         }
     }
+
+    /// Reads the values of a packed repeated field up to the current limit of the input
+    fn read_packed(&mut self, repeated: &dyn access::RepeatedFieldAccessor<'a>, field: &'a FieldDescriptor<'a>, input: &mut io::CodedInput) -> io::InputResult<()> {
+        while !input.reached_limit() {
+            match field.field_type() {
+                FieldType::Bool => repeated.push(self, Box::new(input.read_bool()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Double => repeated.push(self, Box::new(input.read_double()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Enum(e) => repeated.push(self, e.new_from(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Fixed32 => repeated.push(self, Box::new(input.read_fixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Fixed64 => repeated.push(self, Box::new(input.read_fixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Float => repeated.push(self, Box::new(input.read_float()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Int32 => repeated.push(self, Box::new(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Int64 => repeated.push(self, Box::new(input.read_int64()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Sfixed32 => repeated.push(self, Box::new(input.read_sfixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Sfixed64 => repeated.push(self, Box::new(input.read_sfixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Sint32 => repeated.push(self, Box::new(input.read_sint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Sint64 => repeated.push(self, Box::new(input.read_sint64()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Uint32 => repeated.push(self, Box::new(input.read_uint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                FieldType::Uint64 => repeated.push(self, Box::new(input.read_uint64()?)).expect(FAILED_SELF_ACCESS_ERROR),
+                _ => unreachable!("field type is unusable in packed fields")
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the fields to write in the order they're written in generated code:
+    /// declared fields in declaration order, then set extension fields by number
+    fn written_fields<'b>(&'b self) -> impl Iterator<Item = &'b FieldDescriptor<'a>> + 'b {
+        let mut extensions =
+            self.fields
+                .keys()
+                .filter_map(|n| self.descriptor.find_extension_by_number(*n))
+                .collect::<Vec<_>>();
+        extensions.sort_by_key(|f| f.number());
+
+        self.descriptor.fields().iter().map(|f| &**f).chain(extensions)
+    }
+
+    // the accessors only hand out values borrowed for as long as the message is,
+    // so serialization reads values out of the message directly
+
+    fn single_value(&self, field: &FieldDescriptor<'a>) -> Option<&(dyn AnyValue<'a> + 'a)> {
+        let value = 
+            match field.scope() {
+                FieldScope::Oneof(o) => {
+                    match self.oneofs.get(&o.message_index()) {
+                        Some((index, value)) if *index == field.composite_scope_index() => value,
+                        _ => return None
+                    }
+                },
+                _ => self.fields.get(&field.number())?
+            };
+        match value {
+            DynamicFieldValue::Single(value) if !is_implicit_default(field, value.as_ref()) => Some(value.as_ref()),
+            DynamicFieldValue::Single(_) => None,
+            _ => unreachable!()
+        }
+    }
+
+    fn repeated_values(&self, field: &FieldDescriptor<'a>) -> &[Box<dyn AnyValue<'a>>] {
+        match self.fields.get(&field.number()) {
+            Some(DynamicFieldValue::Repeated(values)) => values,
+            None => &[],
+            _ => unreachable!()
+        }
+    }
+
+    fn map_entries<'b>(&'b self, field: &'b FieldDescriptor<'a>) -> impl Iterator<Item = MapEntryWriteAdapter<'a, 'b>> + 'b {
+        let (key_field, value_field) =
+            match field.field_type() {
+                FieldType::Message(m) => (&*m.fields()[0], &*m.fields()[1]),
+                _ => unreachable!("map accessor requires field type of message")
+            };
+        let entries = 
+            match self.fields.get(&field.number()) {
+                Some(DynamicFieldValue::Map(entries)) => Some(entries),
+                None => None,
+                _ => unreachable!()
+            };
+        entries
+            .into_iter()
+            .flat_map(|entries| entries.iter())
+            .map(move |(key, value)| MapEntryWriteAdapter { key_field, key, value_field, value: value.as_ref() })
+    }
+
+    #[cfg(not(checked_size))]
+    fn field_size(&self, field: &FieldDescriptor<'a>) -> i32 {
+        let tag_size = io::sizes::uint32(Tag::new(field.number(), field.wire_type()).get());
+        let end_tag_size = if field.field_type().is_group() { tag_size } else { 0 };
+        match field.accessor().unwrap() {
+            FieldAccessor::Single(_) => {
+                match self.single_value(field) {
+                    Some(value) => tag_size + value_size(field.field_type(), value) + end_tag_size,
+                    None => 0
+                }
+            },
+            FieldAccessor::Repeated(_) => {
+                let values = self.repeated_values(field);
+                if values.is_empty() {
+                    0
+                } else if field.is_packed() {
+                    let length = values.iter().fold(0, |size, value| size + value_size(field.field_type(), value.as_ref()));
+                    tag_size + io::sizes::int32(length) + length
+                } else {
+                    values.iter().fold(0, |size, value| size + tag_size + value_size(field.field_type(), value.as_ref()) + end_tag_size)
+                }
+            },
+            FieldAccessor::Map(_) => {
+                self.map_entries(field).fold(0, |size, entry| size + tag_size + io::sizes::message(&entry))
+            }
+        }
+    }
+
+    #[cfg(checked_size)]
+    fn field_size(&self, field: &FieldDescriptor<'a>) -> Option<i32> {
+        let tag_size = io::sizes::uint32(Tag::new(field.number(), field.wire_type()).get());
+        let end_tag_size = if field.field_type().is_group() { tag_size } else { 0 };
+        match field.accessor().unwrap() {
+            FieldAccessor::Single(_) => {
+                match self.single_value(field) {
+                    Some(value) => tag_size.checked_add(value_size(field.field_type(), value)?)?.checked_add(end_tag_size),
+                    None => Some(0)
+                }
+            },
+            FieldAccessor::Repeated(_) => {
+                let values = self.repeated_values(field);
+                if values.is_empty() {
+                    Some(0)
+                } else if field.is_packed() {
+                    let length = values.iter().try_fold(0i32, |size, value| size.checked_add(value_size(field.field_type(), value.as_ref())?))?;
+                    tag_size.checked_add(io::sizes::int32(length))?.checked_add(length)
+                } else {
+                    values.iter().try_fold(0i32, |size, value| {
+                        size.checked_add(tag_size)?.checked_add(value_size(field.field_type(), value.as_ref())?)?.checked_add(end_tag_size)
+                    })
+                }
+            },
+            FieldAccessor::Map(_) => {
+                self.map_entries(field).try_fold(0i32, |size, entry| size.checked_add(tag_size)?.checked_add(io::sizes::message(&entry)?))
+            }
+        }
+    }
+
+    fn write_field(&self, output: &mut io::CodedOutput, field: &FieldDescriptor<'a>) -> io::OutputResult {
+        let tag = Tag::new(field.number(), field.wire_type());
+        let end_tag = if field.field_type().is_group() { Some(Tag::new(field.number(), WireType::EndGroup)) } else { None };
+        match field.accessor().unwrap() {
+            FieldAccessor::Single(_) => {
+                if let Some(value) = self.single_value(field) {
+                    output.write_tag(tag)?;
+                    write_value(output, field.field_type(), value)?;
+                    if let Some(end_tag) = end_tag {
+                        output.write_tag(end_tag)?;
+                    }
+                }
+            },
+            FieldAccessor::Repeated(_) => {
+                let values = self.repeated_values(field);
+                if values.is_empty() {
+                    return Ok(());
+                }
+                if field.is_packed() {
+                    #[cfg(checked_size)]
+                    let length = 
+                        values
+                            .iter()
+                            .try_fold(0i32, |size, value| size.checked_add(value_size(field.field_type(), value.as_ref())?))
+                            .ok_or(io::OutputError::ValueTooLarge)?;
+
+                    #[cfg(not(checked_size))]
+                    let length = values.iter().fold(0, |size, value| size + value_size(field.field_type(), value.as_ref()));
+
+                    output.write_tag(tag)?;
+                    output.write_int32(length)?;
+                    for value in values {
+                        write_value(output, field.field_type(), value.as_ref())?;
+                    }
+                } else {
+                    for value in values {
+                        output.write_tag(tag)?;
+                        write_value(output, field.field_type(), value.as_ref())?;
+                        if let Some(end_tag) = end_tag {
+                            output.write_tag(end_tag)?;
+                        }
+                    }
+                }
+            },
+            FieldAccessor::Map(_) => {
+                if output.is_deterministic() {
+                    let mut entries: Vec<_> = self.map_entries(field).collect();
+                    entries.sort_unstable_by(|a, b| a.key.cmp(b.key));
+                    for entry in entries {
+                        output.write_tag(tag)?;
+                        output.write_message(&entry)?;
+                    }
+                } else {
+                    for entry in self.map_entries(field) {
+                        output.write_tag(tag)?;
+                        output.write_message(&entry)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Debug for DynamicMessage<'_> {
@@ -144,7 +468,10 @@ impl Debug for DynamicMessage<'_> {
 }
 
 static FAILED_SELF_ACCESS_ERROR: &str = "couldn't access field on self";
-static MISREPORTED_LEN: &str = "repeated field accessor misreported its length";
+pub(crate) static MISREPORTED_LEN: &str = "repeated field accessor misreported its length";
+pub(crate) static INVALID_VALUE_TYPE: &str = "field contained a value of the wrong type";
+pub(crate) static INVALID_WELL_KNOWN_TYPE: &str = "well-known type didn't contain the expected fields";
+pub(crate) static FAILED_ACCESS_ERROR: &str = "couldn't access field on message";
 
 struct FieldDebugFormatter<'a, 'b, 'c>(FieldAccessor<'a, 'b>, &'c dyn AnyMessage<'a>);
 
@@ -201,6 +528,161 @@ impl CodedMessage for MutAnyMessageWrapper<'_, '_> {
     }
 }
 
+struct AnyMessageWrapper<'a, 'b>(&'a dyn AnyMessage<'b>);
+
+impl CodedMessage for AnyMessageWrapper<'_, '_> {
+    fn merge_from(&mut self, _input: &mut io::CodedInput) -> io::InputResult<()> {
+        unreachable!()
+    }
+    #[cfg(not(checked_size))]
+    fn calculate_size(&self) -> i32 {
+        self.0.calculate_size()
+    }
+    #[cfg(checked_size)]
+    fn calculate_size(&self) -> Option<i32> {
+        self.0.calculate_size()
+    }
+    fn write_to(&self, output: &mut io::CodedOutput) -> io::OutputResult {
+        self.0.write_to(output)
+    }
+    fn is_initialized(&self) -> bool {
+        self.0.is_initialized()
+    }
+    fn cached_size(&self) -> Option<i32> {
+        self.0.cached_size()
+    }
+}
+
+struct MapEntryReadAdapter<'a> {
+    key_field: &'a FieldDescriptor<'a>,
+    key: Option<Box<dyn AnyValue<'a> + 'a>>,
+    invalid_key: bool,
+    value_field: &'a FieldDescriptor<'a>,
+    value: Option<Box<dyn AnyValue<'a> + 'a>>,
+}
+
+impl<'a> MapEntryReadAdapter<'a> {
+    fn read_key(&self, input: &mut io::CodedInput) -> io::InputResult<Box<dyn AnyValue<'a> + 'a>> {
+        Ok(match self.key_field.field_type() {
+            FieldType::Bool => Box::new(input.read_bool()?),
+            FieldType::Fixed32 => Box::new(input.read_fixed32()?),
+            FieldType::Fixed64 => Box::new(input.read_fixed64()?),
+            FieldType::Int32 => Box::new(input.read_int32()?),
+            FieldType::Int64 => Box::new(input.read_int64()?),
+            FieldType::Sfixed32 => Box::new(input.read_sfixed32()?),
+            FieldType::Sfixed64 => Box::new(input.read_sfixed64()?),
+            FieldType::Sint32 => Box::new(input.read_sint32()?),
+            FieldType::Sint64 => Box::new(input.read_sint64()?),
+            FieldType::Uint32 => Box::new(input.read_uint32()?),
+            FieldType::Uint64 => Box::new(input.read_uint64()?),
+            FieldType::String => Box::new(input.read_string()?),
+            _ => unreachable!("invalid field type for map key")
+        })
+    }
+}
+
+impl CodedMessage for MapEntryReadAdapter<'_> {
+    fn merge_from(&mut self, input: &mut io::CodedInput) -> io::InputResult<()> {
+        let key_tag = Tag::new(self.key_field.number(), self.key_field.wire_type());
+        let value_tag = Tag::new(self.value_field.number(), self.value_field.wire_type());
+        while let Some(tag) = input.read_tag()? {
+            match tag {
+                tag if tag == key_tag => {
+                    // a key skipped in lenient mode drops the entry, since it can't be keyed by a default
+                    let key = self.read_key(input);
+                    self.invalid_key = key.is_err();
+                    self.key = Some(key?);
+                },
+                tag if tag == value_tag => {
+                    self.value =
+                        Some(match self.value_field.field_type() {
+                            FieldType::Bool => Box::new(input.read_bool()?),
+                            FieldType::Fixed32 => Box::new(input.read_fixed32()?),
+                            FieldType::Fixed64 => Box::new(input.read_fixed64()?),
+                            FieldType::Int32 => Box::new(input.read_int32()?),
+                            FieldType::Int64 => Box::new(input.read_int64()?),
+                            FieldType::Sfixed32 => Box::new(input.read_sfixed32()?),
+                            FieldType::Sfixed64 => Box::new(input.read_sfixed64()?),
+                            FieldType::Sint32 => Box::new(input.read_sint32()?),
+                            FieldType::Sint64 => Box::new(input.read_sint64()?),
+                            FieldType::Uint32 => Box::new(input.read_uint32()?),
+                            FieldType::Uint64 => Box::new(input.read_uint64()?),
+                            FieldType::Bytes => Box::new(input.read_bytes()?),
+                            FieldType::Double => Box::new(input.read_double()?),
+                            FieldType::Enum(e) => e.new_from(input.read_int32()?),
+                            FieldType::Float => Box::new(input.read_float()?),
+                            FieldType::Group(m) | FieldType::Message(m) => {
+                                let mut value = m.new_instance().unwrap();
+                                input.read_message(&mut MutAnyMessageWrapper(value.as_message_mut().unwrap()))?;
+                                value
+                            },
+                            FieldType::String => Box::new(input.read_string()?)
+                        });
+                },
+                tag => input.skip(tag)?
+            }
+        }
+        Ok(())
+    }
+    #[cfg(not(checked_size))]
+    fn calculate_size(&self) -> i32 {
+        unreachable!()
+    }
+    #[cfg(checked_size)]
+    fn calculate_size(&self) -> Option<i32> {
+        unreachable!()
+    }
+    fn write_to(&self, _output: &mut io::CodedOutput) -> io::OutputResult {
+        unreachable!()
+    }
+}
+
+struct MapEntryWriteAdapter<'a, 'b> {
+    key_field: &'b FieldDescriptor<'a>,
+    key: &'b DynamicKey,
+    value_field: &'b FieldDescriptor<'a>,
+    value: &'b (dyn AnyValue<'a> + 'a),
+}
+
+impl CodedMessage for MapEntryWriteAdapter<'_, '_> {
+    fn merge_from(&mut self, _input: &mut io::CodedInput) -> io::InputResult<()> {
+        unreachable!()
+    }
+    #[cfg(not(checked_size))]
+    fn calculate_size(&self) -> i32 {
+        let mut size = 0i32;
+        size += io::sizes::uint32(Tag::new(self.key_field.number(), self.key_field.wire_type()).get());
+        size += value_size(self.key_field.field_type(), self.key.as_any());
+        size += io::sizes::uint32(Tag::new(self.value_field.number(), self.value_field.wire_type()).get());
+        size += value_size(self.value_field.field_type(), self.value);
+        size
+    }
+    #[cfg(checked_size)]
+    fn calculate_size(&self) -> Option<i32> {
+        let mut size = 0i32;
+        size = size.checked_add(io::sizes::uint32(Tag::new(self.key_field.number(), self.key_field.wire_type()).get()))?;
+        size = size.checked_add(value_size(self.key_field.field_type(), self.key.as_any())?)?;
+        size = size.checked_add(io::sizes::uint32(Tag::new(self.value_field.number(), self.value_field.wire_type()).get()))?;
+        size = size.checked_add(value_size(self.value_field.field_type(), self.value)?)?;
+        Some(size)
+    }
+    fn write_to(&self, output: &mut io::CodedOutput) -> io::OutputResult {
+        output.write_tag(Tag::new(self.key_field.number(), self.key_field.wire_type()))?;
+        write_value(output, self.key_field.field_type(), self.key.as_any())?;
+        output.write_tag(Tag::new(self.value_field.number(), self.value_field.wire_type()))?;
+        write_value(output, self.value_field.field_type(), self.value)?;
+        Ok(())
+    }
+    fn cached_size(&self) -> Option<i32> {
+        let mut size = 0i32;
+        size = size.checked_add(io::sizes::uint32(Tag::new(self.key_field.number(), self.key_field.wire_type()).get()))?;
+        size = size.checked_add(cached_value_size(self.key_field.field_type(), self.key.as_any())?)?;
+        size = size.checked_add(io::sizes::uint32(Tag::new(self.value_field.number(), self.value_field.wire_type()).get()))?;
+        size = size.checked_add(cached_value_size(self.value_field.field_type(), self.value)?)?;
+        Some(size)
+    }
+}
+
 impl<'a> CodedMessage for DynamicMessage<'a> {
     fn merge_from(&mut self, input: &mut io::CodedInput) -> io::InputResult<()> {
         let descriptor = self.descriptor();
@@ -224,9 +706,12 @@ impl<'a> CodedMessage for DynamicMessage<'a> {
                             FieldType::Float => single.set(self, Box::new(input.read_float()?)).expect(FAILED_SELF_ACCESS_ERROR),
                             FieldType::Int32 => single.set(self, Box::new(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
                             FieldType::Int64 => single.set(self, Box::new(input.read_int64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                            FieldType::Message(_) | FieldType::Group(_) => {
+                            FieldType::Message(_) => {
                                 input.read_message(&mut MutAnyMessageWrapper(single.get_mut(self).expect(FAILED_SELF_ACCESS_ERROR).as_message_mut().unwrap()))?
                             },
+                            FieldType::Group(_) => {
+                                input.read_group(&mut MutAnyMessageWrapper(single.get_mut(self).expect(FAILED_SELF_ACCESS_ERROR).as_message_mut().unwrap()))?
+                            },
                             FieldType::Sfixed32 => single.set(self, Box::new(input.read_sfixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
                             FieldType::Sfixed64 => single.set(self, Box::new(input.read_sfixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
                             FieldType::Sint32 => single.set(self, Box::new(input.read_sint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
@@ -237,29 +722,12 @@ impl<'a> CodedMessage for DynamicMessage<'a> {
                         }
                     },
                     FieldAccessor::Repeated(repeated) => {
-                        if tag.wire_type() == WireType::LengthDelimited && field.wire_type().is_packable() {
+                        if tag.wire_type() == WireType::LengthDelimited && field.field_type().wire_type().is_packable() {
                             let new_limit = input.read_length()?;
-                            let old = input.push_limit(new_limit);
-                            while !input.reached_limit() {
-                                match field.field_type() {
-                                    FieldType::Bool => repeated.push(self, Box::new(input.read_bool()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Double => repeated.push(self, Box::new(input.read_double()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Enum(e) => repeated.push(self, e.new_from(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Fixed32 => repeated.push(self, Box::new(input.read_fixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Fixed64 => repeated.push(self, Box::new(input.read_fixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Float => repeated.push(self, Box::new(input.read_float()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Int32 => repeated.push(self, Box::new(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Int64 => repeated.push(self, Box::new(input.read_int64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Sfixed32 => repeated.push(self, Box::new(input.read_sfixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Sfixed64 => repeated.push(self, Box::new(input.read_sfixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Sint32 => repeated.push(self, Box::new(input.read_sint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Sint64 => repeated.push(self, Box::new(input.read_sint64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Uint32 => repeated.push(self, Box::new(input.read_uint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    FieldType::Uint64 => repeated.push(self, Box::new(input.read_uint64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                    _ => unreachable!("field type is unusable in packed fields")
-                                }
-                            }
+                            let old = input.push_limit(new_limit)?;
+                            let result = self.read_packed(repeated, field, input);
                             input.pop_limit(old);
+                            result?;
                         } else {
                             match field.field_type() {
                                 FieldType::Bool => repeated.push(self, Box::new(input.read_bool()?)).expect(FAILED_SELF_ACCESS_ERROR),
@@ -271,11 +739,16 @@ impl<'a> CodedMessage for DynamicMessage<'a> {
                                 FieldType::Float => repeated.push(self, Box::new(input.read_float()?)).expect(FAILED_SELF_ACCESS_ERROR),
                                 FieldType::Int32 => repeated.push(self, Box::new(input.read_int32()?)).expect(FAILED_SELF_ACCESS_ERROR),
                                 FieldType::Int64 => repeated.push(self, Box::new(input.read_int64()?)).expect(FAILED_SELF_ACCESS_ERROR),
-                                FieldType::Message(m) | FieldType::Group(m) => {
+                                FieldType::Message(m) => {
                                     let mut value = m.new_instance().unwrap();
                                     input.read_message(&mut MutAnyMessageWrapper(value.as_message_mut().unwrap()))?;
                                     repeated.push(self, value).expect(FAILED_SELF_ACCESS_ERROR)
                                 },
+                                FieldType::Group(m) => {
+                                    let mut value = m.new_instance().unwrap();
+                                    input.read_group(&mut MutAnyMessageWrapper(value.as_message_mut().unwrap()))?;
+                                    repeated.push(self, value).expect(FAILED_SELF_ACCESS_ERROR)
+                                },
                                 FieldType::Sfixed32 => repeated.push(self, Box::new(input.read_sfixed32()?)).expect(FAILED_SELF_ACCESS_ERROR),
                                 FieldType::Sfixed64 => repeated.push(self, Box::new(input.read_sfixed64()?)).expect(FAILED_SELF_ACCESS_ERROR),
                                 FieldType::Sint32 => repeated.push(self, Box::new(input.read_sint32()?)).expect(FAILED_SELF_ACCESS_ERROR),
@@ -294,69 +767,15 @@ impl<'a> CodedMessage for DynamicMessage<'a> {
                             };
 
                         let key_field = &entry.fields()[0];
-                        let key_tag = Tag::new(key_field.number(), key_field.wire_type());
                         let value_field = &entry.fields()[1];
-                        let value_tag = Tag::new(value_field.number(), value_field.wire_type());
-
-                        let new_limit = input.read_length()?;
-                        let old = input.push_limit(new_limit);
-                        while !input.reached_limit() {
-                            let mut key: Option<Box<dyn AnyValue<'a>>> = None;
-                            let mut value: Option<Box<dyn AnyValue<'a>>> = None;
-                            while let Some(tag) = input.read_tag()? {
-                                match tag {
-                                    tag if tag == key_tag => {
-                                        key = 
-                                            Some(match key_field.field_type() {
-                                                FieldType::Bool => Box::new(input.read_bool()?),
-                                                FieldType::Fixed32 => Box::new(input.read_fixed32()?),
-                                                FieldType::Fixed64 => Box::new(input.read_fixed64()?),
-                                                FieldType::Int32 => Box::new(input.read_int32()?),
-                                                FieldType::Int64 => Box::new(input.read_int64()?),
-                                                FieldType::Sfixed32 => Box::new(input.read_sfixed32()?),
-                                                FieldType::Sfixed64 => Box::new(input.read_sfixed64()?),
-                                                FieldType::Sint32 => Box::new(input.read_sint32()?),
-                                                FieldType::Sint64 => Box::new(input.read_sint64()?),
-                                                FieldType::Uint32 => Box::new(input.read_uint32()?),
-                                                FieldType::Uint64 => Box::new(input.read_uint64()?),
-                                                _ => unreachable!("invalid field type for map key")
-                                            });
-                                    },
-                                    tag if tag == value_tag => {
-                                        value = 
-                                            Some(match value_field.field_type() {
-                                                FieldType::Bool => Box::new(input.read_bool()?),
-                                                FieldType::Fixed32 => Box::new(input.read_fixed32()?),
-                                                FieldType::Fixed64 => Box::new(input.read_fixed64()?),
-                                                FieldType::Int32 => Box::new(input.read_int32()?),
-                                                FieldType::Int64 => Box::new(input.read_int64()?),
-                                                FieldType::Sfixed32 => Box::new(input.read_sfixed32()?),
-                                                FieldType::Sfixed64 => Box::new(input.read_sfixed64()?),
-                                                FieldType::Sint32 => Box::new(input.read_sint32()?),
-                                                FieldType::Sint64 => Box::new(input.read_sint64()?),
-                                                FieldType::Uint32 => Box::new(input.read_uint32()?),
-                                                FieldType::Uint64 => Box::new(input.read_uint64()?),
-                                                FieldType::Bytes => Box::new(input.read_bytes()?),
-                                                FieldType::Double => Box::new(input.read_double()?),
-                                                FieldType::Enum(e) => e.new_from(input.read_int32()?),
-                                                FieldType::Float => Box::new(input.read_float()?),
-                                                FieldType::Group(m) | FieldType::Message(m) => {
-                                                    let mut value = m.new_instance().unwrap();
-                                                    input.read_message(&mut MutAnyMessageWrapper(value.as_message_mut().unwrap()))?;
-                                                    value
-                                                },
-                                                FieldType::String => Box::new(input.read_string()?)
-                                            });
-                                    },
-                                    tag => input.skip(tag)?
-                                }
-                            }
+                        let mut adapter = MapEntryReadAdapter { key_field, key: None, invalid_key: false, value_field, value: None };
+                        input.read_message(&mut adapter)?;
+                        if !adapter.invalid_key {
                             map.insert(
-                                self, 
-                                key.unwrap_or_else(|| new_default_value(descriptor, key_field)), 
-                                value.unwrap_or_else(|| new_default_value(descriptor, value_field))).expect(FAILED_SELF_ACCESS_ERROR);
+                                self,
+                                adapter.key.unwrap_or_else(|| new_default_value(entry, key_field)),
+                                adapter.value.unwrap_or_else(|| new_default_value(entry, value_field))).expect(FAILED_SELF_ACCESS_ERROR);
                         }
-                        input.pop_limit(old);
                     }
                 }
             } else {
@@ -367,14 +786,33 @@ impl<'a> CodedMessage for DynamicMessage<'a> {
     }
     #[cfg(not(checked_size))]
     fn calculate_size(&self) -> i32 {
-        unimplemented!()
+        let mut size = 0i32;
+        for field in self.written_fields() {
+            size += self.field_size(field);
+        }
+        size += self.unknown_fields.calculate_size();
+        self.cached_size.set(size);
+        size
     }
     #[cfg(checked_size)]
     fn calculate_size(&self) -> Option<i32> {
-        unimplemented!()
+        let mut size = 0i32;
+        for field in self.written_fields() {
+            size = size.checked_add(self.field_size(field)?)?;
+        }
+        size = size.checked_add(self.unknown_fields.calculate_size()?)?;
+        self.cached_size.set(size);
+        Some(size)
     }
     fn write_to(&self, output: &mut io::CodedOutput) -> io::OutputResult {
-        unimplemented!()
+        for field in self.written_fields() {
+            self.write_field(output, field)?;
+        }
+        self.unknown_fields.write_to(output)?;
+        Ok(())
+    }
+    fn cached_size(&self) -> Option<i32> {
+        Some(self.cached_size.get())
     }
     fn is_initialized(&self) -> bool {
         if self.descriptor().file().syntax() == Syntax::Proto3 {
@@ -640,6 +1078,9 @@ impl<'a> AnyMessage<'a> for DynamicMessage<'a> {
     fn descriptor(&self) -> &'a MessageDescriptor<'a> {
         self.descriptor
     }
+    fn unknown_fields(&self) -> Option<&UnknownFieldSet> {
+        Some(&self.unknown_fields)
+    }
     fn registry(&self) -> Option<&'static ExtensionRegistry> {
         None
     }
@@ -1112,11 +1553,16 @@ impl<'a> access::MapFieldAccessor<'a> for FieldDescriptor<'a> {
         value: Box<dyn AnyValue<'a> + 'a>,
     ) -> access::Result<'a, Option<Box<dyn AnyValue<'a> + 'a>>> {
         let instance = DynamicMessage::downcast_any_mut(instance).ok_or(InvalidMessage)?;
-        let key = match check_value(self, key) {
+        let (key_field, value_field) =
+            match self.field_type() {
+                FieldType::Message(m) => (&m.fields()[0], &m.fields()[1]),
+                _ => unreachable!("map accessor requires field type of message")
+            };
+        let key = match check_value(key_field, key) {
             Ok(k) => k,
             Err(e) => return Err(FieldAccessError::InvalidEntry(e, value)),
         };
-        let value = match check_value(self, value) {
+        let value = match check_value(value_field, value) {
             Ok(v) => v,
             Err(e) => return Err(FieldAccessError::InvalidEntry(key, e)),
         };
@@ -1284,7 +1730,7 @@ enum DynamicFieldValue<'a> {
     Map(HashMap<DynamicKey, Box<dyn AnyValue<'a>>>),
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum DynamicKey {
     Int32(i32),
     Int64(i64),
@@ -9,7 +9,7 @@ use crate::descriptor::{
     ServiceOptions,
 };
 use crate::io::{FieldNumber, WireType};
-use crate::{CodedMessage, Enum, EnumValue::{self, Defined}, ExtendableMessage, ExtensionRegistry, Message};
+use crate::{CodedMessage, Enum, EnumValue::{self, Defined}, ExtendableMessage, ExtensionRegistry, Message, UnknownFieldSet, UnknownFields};
 use std::any::TypeId;
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -27,6 +27,11 @@ use std::sync::Arc;
 pub mod access;
 mod dynamic;
 
+pub(crate) use self::dynamic::{
+    get_single, is_implicit_default, FAILED_ACCESS_ERROR, INVALID_VALUE_TYPE, INVALID_WELL_KNOWN_TYPE,
+    MISREPORTED_LEN,
+};
+
 // macro the methods and impls from std::any::Any
 macro_rules! any_extensions {
     ($nm:tt) => {
@@ -276,6 +281,12 @@ pub trait AnyMessage<'a>: CodedMessage + AnyValue<'a> {
     /// Gets the descriptor for this message
     fn descriptor(&self) -> &'a MessageDescriptor<'a>;
 
+    /// Gets the fields read by this message that weren't known by it,
+    /// or None if the message doesn't keep unknown fields
+    fn unknown_fields(&self) -> Option<&UnknownFieldSet> {
+        None
+    }
+
     /// For extension messages, gets the registry in use by the message.
     /// Dynamic messages don't contain extension registries
     fn registry(&self) -> Option<&'static ExtensionRegistry>;
@@ -335,6 +346,10 @@ impl<T: Message> AnyMessage<'static> for T {
         T::descriptor()
     }
 
+    fn unknown_fields(&self) -> Option<&UnknownFieldSet> {
+        MaybeUnknownFields::maybe_unknown_fields(self)
+    }
+
     default fn registry(&self) -> Option<&'static ExtensionRegistry> {
         None
     }
@@ -347,6 +362,23 @@ impl<T: Message> AnyMessage<'static> for T {
     }
 }
 
+/// Gets the unknown fields of messages that implement [`UnknownFields`](../trait.UnknownFields.html)
+trait MaybeUnknownFields {
+    fn maybe_unknown_fields(&self) -> Option<&UnknownFieldSet>;
+}
+
+impl<T> MaybeUnknownFields for T {
+    default fn maybe_unknown_fields(&self) -> Option<&UnknownFieldSet> {
+        None
+    }
+}
+
+impl<T: UnknownFields> MaybeUnknownFields for T {
+    fn maybe_unknown_fields(&self) -> Option<&UnknownFieldSet> {
+        Some(self.unknown_fields())
+    }
+}
+
 impl<T: Message + ExtendableMessage> AnyMessage<'static> for T {
     fn registry(&self) -> Option<&'static ExtensionRegistry> {
         ExtendableMessage::registry(self)
@@ -1395,7 +1427,7 @@ impl<'a> MessageDescriptor<'a> {
             self.db.num_symbol_map
                 .try_borrow_unguarded().expect(UNCHECKED_BORROW_MSG)
                 .get(&(num.get() as i32, Ref::from(self)))
-                .and_then(|r| if !r.is_extension() { Some(&*r.0.as_ptr()) } else { None })
+                .and_then(|r| if r.is_extension() { Some(&*r.0.as_ptr()) } else { None })
         }
     }
 
@@ -1408,6 +1440,19 @@ impl<'a> MessageDescriptor<'a> {
         &self.extensions
     }
 
+    /// Finds a message by its full name in the pool this message is defined in
+    pub(crate) fn find_message_in_pool(&self, name: &str) -> Option<&MessageDescriptor<'a>> {
+        match self.db.borrow_symbol(name) {
+            Some(Symbol::Message(ref m)) => Some(m),
+            _ => None
+        }
+    }
+
+    /// Gets the extensions of this message defined in the pool this message is defined in
+    pub(crate) fn find_extensions_in_pool<'b>(&'b self) -> impl Iterator<Item = &'b FieldDescriptor<'a>> + 'b {
+        self.db.find_extensions_for_message_by_index(Ref::from(self))
+    }
+
     pub fn messages(&self) -> &[MessageRef<'a>] {
         &self.messages
     }
@@ -2340,6 +2385,31 @@ impl<'a> FieldDescriptor<'a> {
         }
     }
 
+    /// Creates a value of this field's enum type from its number that can be used with the field's accessor.
+    /// For map fields, this creates a value of the map's value type.
+    ///
+    /// This returns None if the field doesn't contain enum values or if the number isn't defined in a proto2 enum
+    pub fn new_enum_value(&'a self, number: i32) -> Option<Box<dyn AnyValue<'a> + 'a>> {
+        let enum_type =
+            match self.field_type() {
+                FieldType::Enum(e) => &**e,
+                FieldType::Message(m) if m.is_map_entry() => match m.fields()[1].field_type() {
+                    FieldType::Enum(e) => &**e,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+        if enum_type.file().syntax() == Syntax::Proto2 && !enum_type.values().iter().any(|v| v.number() == number) {
+            return None;
+        }
+        match self.accessor {
+            Some(access::FieldAccessor::Single(accessor)) => accessor.new_enum_value(number),
+            Some(access::FieldAccessor::Repeated(accessor)) => accessor.new_enum_value(number),
+            Some(access::FieldAccessor::Map(accessor)) => accessor.new_enum_value(number),
+            None => Some(enum_type.new_from(number)),
+        }
+    }
+
     pub fn options(&self) -> Option<&'a FieldOptions> {
         self.proto().options()
     }
@@ -2664,7 +2734,16 @@ impl<'a> OneofDescriptor<'a> {
             if let Some(message) = dynamic::DynamicMessage::downcast_any_ref(message) {
                 message.get_set_case(self)
             } else {
-                None
+                self.fields()
+                    .iter()
+                    .find(|f| match f.accessor() {
+                        Some(access::FieldAccessor::Single(accessor)) => match accessor.get(message) {
+                            Ok(value) => value.is_some(),
+                            Err(_) => false,
+                        },
+                        _ => false,
+                    })
+                    .map(|f| &**f)
             }
         }
     }
@@ -0,0 +1,195 @@
+//! Contains a printer and parser for the protobuf text format, the human readable
+//! `field: value` and `message { ... }` format used by protoc and other protobuf tools.
+//!
+//! The printer and parser use reflection, so they work with any message that implements [`AnyMessage`],
+//! including generated messages and dynamic messages created from a [`DescriptorPool`].
+//!
+//! [`AnyMessage`]: ../reflect/trait.AnyMessage.html
+//! [`DescriptorPool`]: ../reflect/struct.DescriptorPool.html
+//!
+//! # Example
+//!
+//! ```
+//! use protrust::LiteMessage;
+//! use protrust::text_format;
+//! use protrust::wkt::{any::Any, timestamp::Timestamp};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut time = Timestamp::new();
+//! *time.seconds_mut() = 1_000_000_000;
+//! *time.nanos_mut() = 500_000_000;
+//!
+//! assert_eq!(text_format::to_string(&time)?, "seconds: 1000000000\nnanos: 500000000\n");
+//! assert_eq!(
+//!     text_format::to_single_line_string(&Any::pack(&time)?)?,
+//!     "[type.googleapis.com/google.protobuf.Timestamp] { seconds: 1000000000 nanos: 500000000 }");
+//!
+//! let parsed: Timestamp = text_format::from_str("seconds: 1000000000 # a comment\nnanos: 500000000")?;
+//! assert_eq!(parsed, time);
+//! # Ok(())
+//! # }
+//! ```
+
+mod parse;
+mod print;
+mod raw;
+
+use crate::Message;
+use crate::io::InputError;
+use crate::reflect::AnyMessage;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Prints the message in the text format to the specified output, with each field on its own line.
+///
+/// Fields are printed in field number order, followed by any unknown fields.
+/// `Any` values are expanded if their type can be resolved using the descriptor pool that contains the printed message's type.
+pub fn print<'a>(message: &(dyn AnyMessage<'a> + 'a), output: &mut dyn fmt::Write) -> fmt::Result {
+    print::Printer::new(message.descriptor(), output, false).print_message(message)
+}
+
+/// Prints the message in the text format to the specified output, with all fields on a single line.
+pub fn print_single_line<'a>(message: &(dyn AnyMessage<'a> + 'a), output: &mut dyn fmt::Write) -> fmt::Result {
+    print::Printer::new(message.descriptor(), output, true).print_message(message)
+}
+
+/// Prints the message as a text format string, with each field on its own line.
+pub fn to_string<'a>(message: &(dyn AnyMessage<'a> + 'a)) -> Result<String, fmt::Error> {
+    let mut output = String::new();
+    print(message, &mut output)?;
+    Ok(output)
+}
+
+/// Prints the message as a text format string, with all fields on a single line.
+pub fn to_single_line_string<'a>(message: &(dyn AnyMessage<'a> + 'a)) -> Result<String, fmt::Error> {
+    let mut output = String::new();
+    print_single_line(message, &mut output)?;
+    Ok(output)
+}
+
+/// Prints arbitrary protobuf bytes without a schema, in a format like the output of `protoc --decode_raw`.
+///
+/// Fields are printed by number in the order they appear in the data. Varint and fixed values are followed by a comment
+/// with their other possible interpretations. Like protoc, length delimited values are printed as nested messages if they
+/// can be read as messages, as strings if they're valid UTF-8 text, and as escaped bytes otherwise,
+/// so short strings like `"hi"` may be printed as messages.
+///
+/// # Example
+///
+/// ```
+/// use protrust::text_format;
+///
+/// # fn main() -> protrust::io::InputResult<()> {
+/// let data = [8, 150, 1, 18, 5, b'h', b'e', b'l', b'l', b'o', 26, 2, 8, 1, 37, 0, 0, 128, 63];
+/// assert_eq!(text_format::decode_raw(&data)?, "\
+/// 1: 150  # sint: 75
+/// 2: \"hello\"
+/// 3 {
+///   1: 1  # sint: -1
+/// }
+/// 4: 0x3f800000  # uint: 1065353216, float: 1
+/// ");
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_raw(data: &[u8]) -> Result<String, InputError> {
+    let fields = raw::parse(data)?;
+    let mut output = String::new();
+    raw::RawPrinter::new(&mut output).print_fields(&fields).expect("a String can't fail to be written to");
+    Ok(output)
+}
+
+/// An error returned when text format input can't be parsed into a message.
+///
+/// The error contains the kind of error that occured along with the line and column
+/// of the token where the error occured.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// Gets the kind of error that occured
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Gets the line where the error occured, starting at 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the column where the error occured, starting at 1. Columns are counted in characters
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "{} at line {}, column {}", self.kind, self.line, self.column)
+    }
+}
+
+impl Error for ParseError { }
+
+/// The kind of error that occured while parsing text format input
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// The input ended before a complete message was read
+    UnexpectedEof,
+    /// The input contained a character that isn't valid at this point in the text
+    UnexpectedChar(char),
+    /// A string was unterminated, contained an invalid escape sequence, or wasn't valid UTF-8 for a string field
+    InvalidString,
+    /// A field name didn't match a field or extension of the message
+    UnknownField(String),
+    /// A non-repeated field was specified more than once or multiple fields of the same oneof were specified
+    DuplicateField(String),
+    /// A number was malformed or out of the range of the field type
+    InvalidNumber,
+    /// A bool field contained a value other than `true`, `false`, `t`, `f`, `1`, or `0`
+    InvalidBool(String),
+    /// An enum value name or number didn't match any value defined in the enum
+    UnknownEnumValue(String),
+    /// The type url of an expanded `Any` value couldn't be resolved to a message type
+    UnresolvedAny(String),
+    /// The input contained messages nested deeper than the recursion limit of 100
+    RecursionLimitExceeded,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error> {
+        use self::ParseErrorKind::*;
+        match self {
+            UnexpectedEof => write!(fmt, "the input ended unexpectedly"),
+            UnexpectedChar(c) => write!(fmt, "the input contained an unexpected character '{}'", c.escape_debug()),
+            InvalidString => write!(fmt, "the input contained an invalid string"),
+            UnknownField(name) => write!(fmt, "the message doesn't contain a field named '{}'", name),
+            DuplicateField(name) => write!(fmt, "the field '{}' was specified multiple times or another field in its oneof was already set", name),
+            InvalidNumber => write!(fmt, "the number was malformed or out of range for the field"),
+            InvalidBool(value) => write!(fmt, "the value '{}' isn't a valid bool", value),
+            UnknownEnumValue(name) => write!(fmt, "the enum doesn't contain a value named '{}'", name),
+            UnresolvedAny(url) => write!(fmt, "the type url '{}' couldn't be resolved to a message type", url),
+            RecursionLimitExceeded => write!(fmt, "the input contained messages nested deeper than the recursion limit"),
+        }
+    }
+}
+
+/// Parses the text format input and merges it into the specified message.
+///
+/// Extensions are written as their full name in brackets and can only be set if they're
+/// in the message's extension registry. Expanded `Any` values are resolved using the
+/// descriptor pool that contains the message's type.
+pub fn merge_from_str<'a>(message: &mut (dyn AnyMessage<'a> + 'a), input: &str) -> Result<(), ParseError> {
+    parse::Parser::new(message.descriptor(), input).merge_message(message, None)
+}
+
+/// Parses the text format input into a new instance of the message type.
+pub fn from_str<T: Message>(input: &str) -> Result<T, ParseError> {
+    let mut message = T::new();
+    merge_from_str(&mut message, input)?;
+    Ok(message)
+}
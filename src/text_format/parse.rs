@@ -0,0 +1,614 @@
+use super::{ParseError, ParseErrorKind};
+use crate::io::CodedInput;
+use crate::reflect::access::FieldAccessor;
+use crate::reflect::{
+    AnyMessage, AnyValue, Descriptor, EnumDescriptor, FieldDescriptor, FieldScope, FieldType, MessageDescriptor, Syntax,
+    INVALID_VALUE_TYPE, INVALID_WELL_KNOWN_TYPE,
+};
+use crate::wkt::any::get_type_name;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::iter::Peekable;
+use std::str::Chars;
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+static WRITE_VEC_ERROR: &str = "couldn't write to vec";
+
+/// The maximum depth of nested messages
+const MAX_DEPTH: usize = CodedInput::DEFAULT_RECURSION_LIMIT as usize;
+
+fn find_field<'a>(descriptor: &'a MessageDescriptor<'a>, name: &str) -> Option<&'a FieldDescriptor<'a>> {
+    descriptor.find_field_by_name(name)
+        .or_else(|| {
+            // groups are written with the name of their message type
+            descriptor.fields()
+                .iter()
+                .map(|f| &**f)
+                .find(|f| match f.field_type() { FieldType::Group(m) => m.name() == name, _ => false })
+        })
+}
+
+fn is_defined(descriptor: &EnumDescriptor, number: i32) -> bool {
+    descriptor.file().syntax() != Syntax::Proto2 || descriptor.values().iter().any(|v| v.number() == number)
+}
+
+fn default_scalar<'a>(field_type: &FieldType<'a>) -> Box<dyn AnyValue<'a> + 'a> {
+    match field_type {
+        FieldType::Bool => Box::new(false),
+        FieldType::Bytes => Box::new(Vec::<u8>::new()),
+        FieldType::Double => Box::new(0.0f64),
+        FieldType::Fixed32 | FieldType::Uint32 => Box::new(0u32),
+        FieldType::Fixed64 | FieldType::Uint64 => Box::new(0u64),
+        FieldType::Float => Box::new(0.0f32),
+        FieldType::Int32 | FieldType::Sfixed32 | FieldType::Sint32 => Box::new(0i32),
+        FieldType::Int64 | FieldType::Sfixed64 | FieldType::Sint64 => Box::new(0i64),
+        FieldType::String => Box::new(String::new()),
+        FieldType::Message(_) | FieldType::Group(_) | FieldType::Enum(_) => unreachable!("value is not a scalar"),
+    }
+}
+
+/// Merges text format input into messages, resolving `Any` values with the pool containing a root message type
+pub(super) struct Parser<'a, 'i> {
+    pool: &'a MessageDescriptor<'a>,
+    input: Peekable<Chars<'i>>,
+    line: usize,
+    column: usize,
+    token: (usize, usize),
+    depth: usize,
+}
+
+impl<'a, 'i> Parser<'a, 'i> {
+    pub(super) fn new(pool: &'a MessageDescriptor<'a>, input: &'i str) -> Parser<'a, 'i> {
+        Parser { pool, input: input.chars().peekable(), line: 1, column: 1, token: (1, 1), depth: 0 }
+    }
+
+    /// Creates an error at the start of the last token that was peeked
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        self.error_at(self.token, kind)
+    }
+
+    fn error_at(&self, (line, column): (usize, usize), kind: ParseErrorKind) -> ParseError {
+        ParseError { kind, line, column }
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.input.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&c) = self.input.peek() {
+            if c == '#' {
+                while self.advance().map_or(false, |c| c != '\n') { }
+            } else if c.is_whitespace() {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Skips whitespace and comments and peeks at the start of the next token
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.token = (self.line, self.column);
+        self.input.peek().cloned()
+    }
+
+    fn try_consume(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.advance();
+                Ok(())
+            }
+            Some(c) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn unexpected(&mut self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEof),
+        }
+    }
+
+    /// Reads characters while they match the predicate, starting at the current position
+    fn read_while(&mut self, mut predicate: impl FnMut(&str, char) -> bool) -> String {
+        let mut text = String::new();
+        while let Some(&c) = self.input.peek() {
+            if !predicate(&text, c) {
+                break;
+            }
+            text.push(c);
+            self.advance();
+        }
+        text
+    }
+
+    fn read_identifier(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => Ok(self.read_while(|_, c| c.is_ascii_alphanumeric() || c == '_')),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Reads the name of an extension or the type url of an expanded `Any` value inside brackets
+    fn read_bracketed_name(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphanumeric() || c == '_' => {
+                let name = self.read_while(|_, c| c.is_ascii_alphanumeric() || "_.-/".contains(c));
+                self.expect(']')?;
+                Ok(name)
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Reads the text of a number without its sign
+    fn read_number(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                Ok(self.read_while(|text, c| {
+                    c.is_ascii_alphanumeric()
+                        || c == '.'
+                        || ((c == '+' || c == '-') && (text.ends_with('e') || text.ends_with('E')) && !text.starts_with("0x") && !text.starts_with("0X"))
+                }))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Reads one or more adjacent string literals, concatenating their contents
+    fn read_string(&mut self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        let mut first = true;
+        loop {
+            let quote =
+                match self.peek() {
+                    Some(q) if q == '"' || q == '\'' => q,
+                    _ if first => return Err(self.unexpected()),
+                    _ => return Ok(bytes),
+                };
+            first = false;
+            self.advance();
+            loop {
+                match self.advance() {
+                    None | Some('\n') => return Err(self.error(ParseErrorKind::InvalidString)),
+                    Some(c) if c == quote => break,
+                    Some('\\') => self.read_escape(&mut bytes)?,
+                    Some(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                }
+            }
+        }
+    }
+
+    fn read_digits(&mut self, radix: u32, max: usize) -> u32 {
+        let mut value = 0;
+        for _ in 0..max {
+            match self.input.peek().and_then(|c| c.to_digit(radix)) {
+                Some(digit) => {
+                    value = value * radix + digit;
+                    self.advance();
+                }
+                None => break,
+            }
+        }
+        value
+    }
+
+    fn read_escape(&mut self, bytes: &mut Vec<u8>) -> Result<()> {
+        let c = self.advance().ok_or_else(|| self.error(ParseErrorKind::InvalidString))?;
+        match c {
+            'n' => bytes.push(b'\n'),
+            'r' => bytes.push(b'\r'),
+            't' => bytes.push(b'\t'),
+            'a' => bytes.push(0x07),
+            'b' => bytes.push(0x08),
+            'f' => bytes.push(0x0C),
+            'v' => bytes.push(0x0B),
+            '\\' | '\'' | '"' | '?' => bytes.push(c as u8),
+            '0'..='7' => {
+                let mut value = c.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match self.input.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            self.advance();
+                        }
+                        None => break,
+                    }
+                }
+                bytes.push(u8::try_from(value).map_err(|_| self.error(ParseErrorKind::InvalidString))?);
+            }
+            'x' | 'X' => {
+                if self.input.peek().and_then(|c| c.to_digit(16)).is_none() {
+                    return Err(self.error(ParseErrorKind::InvalidString));
+                }
+                bytes.push(self.read_digits(16, 2) as u8);
+            }
+            'u' | 'U' => {
+                let len = if c == 'u' { 4 } else { 8 };
+                let mut digits = String::new();
+                for _ in 0..len {
+                    match self.advance() {
+                        Some(c) if c.is_ascii_hexdigit() => digits.push(c),
+                        _ => return Err(self.error(ParseErrorKind::InvalidString)),
+                    }
+                }
+                let c =
+                    u32::from_str_radix(&digits, 16)
+                        .ok()
+                        .and_then(std::char::from_u32)
+                        .ok_or_else(|| self.error(ParseErrorKind::InvalidString))?;
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            _ => return Err(self.error(ParseErrorKind::InvalidString)),
+        }
+        Ok(())
+    }
+
+    fn read_integer<T: TryFrom<i128>>(&mut self) -> Result<T> {
+        self.peek();
+        let start = self.token;
+        let negative = self.try_consume('-');
+        let text = self.read_number()?;
+        let (digits, radix) =
+            if text.starts_with("0x") || text.starts_with("0X") {
+                (&text[2..], 16)
+            } else if text.len() > 1 && text.starts_with('0') {
+                (&text[1..], 8)
+            } else {
+                (&text[..], 10)
+            };
+        i128::from_str_radix(digits, radix)
+            .ok()
+            .and_then(|value| T::try_from(if negative { -value } else { value }).ok())
+            .ok_or_else(|| self.error_at(start, ParseErrorKind::InvalidNumber))
+    }
+
+    fn read_float(&mut self) -> Result<f64> {
+        self.peek();
+        let start = self.token;
+        let negative = self.try_consume('-');
+        let value =
+            match self.peek() {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    match self.read_identifier()?.to_ascii_lowercase().as_str() {
+                        "inf" | "infinity" => Some(std::f64::INFINITY),
+                        "nan" => Some(std::f64::NAN),
+                        _ => None,
+                    }
+                }
+                _ => {
+                    let text = self.read_number()?;
+                    text.trim_end_matches(&['f', 'F'][..]).parse::<f64>().ok()
+                }
+            };
+        value
+            .map(|value| if negative { -value } else { value })
+            .ok_or_else(|| self.error_at(start, ParseErrorKind::InvalidNumber))
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        let text =
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => self.read_number()?,
+                _ => self.read_identifier()?,
+            };
+        match text.as_str() {
+            "true" | "True" | "t" | "1" => Ok(true),
+            "false" | "False" | "f" | "0" => Ok(false),
+            _ => Err(self.error(ParseErrorKind::InvalidBool(text))),
+        }
+    }
+
+    /// Reads an enum value by its name or number
+    fn read_enum(&mut self, descriptor: &EnumDescriptor<'a>) -> Result<i32> {
+        let number =
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() || c == '-' => self.read_integer::<i32>()?,
+                _ => {
+                    let name = self.read_identifier()?;
+                    match descriptor.find_enum_value_by_name(&name) {
+                        Some(value) => value.number(),
+                        None => return Err(self.error(ParseErrorKind::UnknownEnumValue(name))),
+                    }
+                }
+            };
+        if is_defined(descriptor, number) {
+            Ok(number)
+        } else {
+            Err(self.error(ParseErrorKind::UnknownEnumValue(number.to_string())))
+        }
+    }
+
+    fn read_scalar(&mut self, field_type: &FieldType<'a>) -> Result<Box<dyn AnyValue<'a> + 'a>> {
+        Ok(match field_type {
+            FieldType::Bool => Box::new(self.read_bool()?),
+            FieldType::Int32 | FieldType::Sint32 | FieldType::Sfixed32 => Box::new(self.read_integer::<i32>()?),
+            FieldType::Int64 | FieldType::Sint64 | FieldType::Sfixed64 => Box::new(self.read_integer::<i64>()?),
+            FieldType::Uint32 | FieldType::Fixed32 => Box::new(self.read_integer::<u32>()?),
+            FieldType::Uint64 | FieldType::Fixed64 => Box::new(self.read_integer::<u64>()?),
+            FieldType::Double => Box::new(self.read_float()?),
+            FieldType::Float => {
+                let start = self.token;
+                match self.read_float()? {
+                    value if value.is_finite() && value.abs() > f64::from(std::f32::MAX) => return Err(self.error_at(start, ParseErrorKind::InvalidNumber)),
+                    value => Box::new(value as f32),
+                }
+            }
+            FieldType::String => {
+                let bytes = self.read_string()?;
+                Box::new(String::from_utf8(bytes).map_err(|_| self.error(ParseErrorKind::InvalidString))?)
+            }
+            FieldType::Bytes => Box::new(self.read_string()?),
+            FieldType::Message(_) | FieldType::Group(_) | FieldType::Enum(_) => unreachable!("value is not a scalar"),
+        })
+    }
+
+    /// Reads the start of a message value, returning the character that ends the message
+    fn read_message_start(&mut self) -> Result<char> {
+        if self.try_consume('{') {
+            Ok('}')
+        } else if self.try_consume('<') {
+            Ok('>')
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Reads the separator between a field name and its value, which is optional for messages
+    fn read_separator(&mut self, field_type: &FieldType<'a>) -> Result<()> {
+        match field_type {
+            FieldType::Message(_) | FieldType::Group(_) => {
+                self.try_consume(':');
+                Ok(())
+            }
+            _ => self.expect(':'),
+        }
+    }
+
+    /// Reads values with the specified function, either as a list in brackets or as a single value
+    fn read_list(&mut self, mut read: impl FnMut(&mut Self) -> Result<()>) -> Result<()> {
+        if self.try_consume('[') {
+            if self.try_consume(']') {
+                return Ok(());
+            }
+            loop {
+                read(self)?;
+                if self.try_consume(']') {
+                    return Ok(());
+                }
+                self.expect(',')?;
+            }
+        } else {
+            read(self)
+        }
+    }
+
+    /// Merges fields into the message until the terminator is reached, or until the end of the input if there is no terminator.
+    /// This returns an error if the message is nested deeper than the recursion limit
+    pub(super) fn merge_message(&mut self, message: &mut dyn AnyMessage<'a>, terminator: Option<char>) -> Result<()> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::RecursionLimitExceeded));
+        }
+        self.depth += 1;
+        let result = self.merge_fields(message, terminator);
+        self.depth -= 1;
+        result
+    }
+
+    fn merge_fields(&mut self, message: &mut dyn AnyMessage<'a>, terminator: Option<char>) -> Result<()> {
+        let mut fields = HashSet::new();
+        let mut oneofs = HashSet::new();
+        loop {
+            match self.peek() {
+                Some(c) if Some(c) == terminator => {
+                    self.advance();
+                    return Ok(());
+                }
+                None if terminator.is_none() => return Ok(()),
+                None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+                Some(_) => { },
+            }
+            self.merge_field(message, &mut fields, &mut oneofs)?;
+            if !self.try_consume(',') {
+                self.try_consume(';');
+            }
+        }
+    }
+
+    fn merge_field(&mut self, message: &mut dyn AnyMessage<'a>, fields: &mut HashSet<u32>, oneofs: &mut HashSet<String>) -> Result<()> {
+        let descriptor = message.descriptor();
+        let (field, name) =
+            if self.try_consume('[') {
+                let name = self.read_bracketed_name()?;
+                if name.contains('/') && descriptor.full_name() == "google.protobuf.Any" {
+                    return self.merge_any(message, name);
+                }
+                match descriptor.find_extensions_in_pool().find(|f| f.full_name() == name) {
+                    Some(field) => (field, format!("[{}]", name)),
+                    None => return Err(self.error(ParseErrorKind::UnknownField(format!("[{}]", name)))),
+                }
+            } else {
+                let name = self.read_identifier()?;
+                match find_field(descriptor, &name) {
+                    Some(field) => (field, name),
+                    None => return Err(self.error(ParseErrorKind::UnknownField(name))),
+                }
+            };
+
+        let accessor = field.accessor().expect("message field had no accessor");
+        if let FieldAccessor::Single(_) = accessor {
+            if !fields.insert(field.number().get()) {
+                return Err(self.error(ParseErrorKind::DuplicateField(name)));
+            }
+            if let FieldScope::Oneof(oneof) = field.scope() {
+                if !oneofs.insert(oneof.full_name().to_string()) {
+                    return Err(self.error(ParseErrorKind::DuplicateField(name)));
+                }
+            }
+        }
+        // extensions can only be accessed if they're in the message's registry
+        let unregistered = self.error(ParseErrorKind::UnknownField(name));
+
+        self.read_separator(field.field_type())?;
+        match accessor {
+            FieldAccessor::Single(accessor) => {
+                match field.field_type() {
+                    FieldType::Message(_) | FieldType::Group(_) => {
+                        let terminator = self.read_message_start()?;
+                        let nested = accessor.get_mut(message).map_err(|_| unregistered)?;
+                        self.merge_message(nested.as_message_mut().expect(INVALID_VALUE_TYPE), Some(terminator))
+                    }
+                    FieldType::Enum(e) => {
+                        let number = self.read_enum(e)?;
+                        let target = accessor.get_mut(message).map_err(|_| unregistered)?;
+                        target.as_enum_mut().expect(INVALID_VALUE_TYPE).set_i32(number);
+                        Ok(())
+                    }
+                    field_type => {
+                        let value = self.read_scalar(field_type)?;
+                        accessor.set(message, value).map_err(|_| unregistered)
+                    }
+                }
+            }
+            FieldAccessor::Repeated(accessor) => {
+                let mut values = Vec::new();
+                self.read_list(|parser| {
+                    values.push(parser.read_element(field, field.field_type())?);
+                    Ok(())
+                })?;
+                for value in values {
+                    if accessor.push(message, value).is_err() {
+                        return Err(unregistered);
+                    }
+                }
+                Ok(())
+            }
+            FieldAccessor::Map(accessor) => {
+                let mut entries = Vec::new();
+                self.read_list(|parser| {
+                    entries.push(parser.read_map_entry(field)?);
+                    Ok(())
+                })?;
+                for (key, value) in entries {
+                    if accessor.insert(message, key, value).is_err() {
+                        return Err(unregistered);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Reads an element of a repeated field or a map entry's key or value
+    fn read_element(
+        &mut self,
+        field: &'a FieldDescriptor<'a>,
+        field_type: &'a FieldType<'a>) -> Result<Box<dyn AnyValue<'a> + 'a>> {
+        match field_type {
+            FieldType::Message(m) | FieldType::Group(m) => {
+                let terminator = self.read_message_start()?;
+                let mut instance = m.new_instance().expect("couldn't create an instance of the message type");
+                self.merge_message(instance.as_message_mut().expect(INVALID_VALUE_TYPE), Some(terminator))?;
+                Ok(instance)
+            }
+            FieldType::Enum(e) => {
+                let number = self.read_enum(e)?;
+                field.new_enum_value(number).ok_or_else(|| self.error(ParseErrorKind::UnknownEnumValue(number.to_string())))
+            }
+            field_type => self.read_scalar(field_type),
+        }
+    }
+
+    /// Reads a map entry message containing a key and value, using the default value for either if it's missing
+    fn read_map_entry(
+        &mut self,
+        field: &'a FieldDescriptor<'a>) -> Result<(Box<dyn AnyValue<'a> + 'a>, Box<dyn AnyValue<'a> + 'a>)> {
+        let (key_field, value_field) =
+            match field.field_type() {
+                FieldType::Message(m) => (&*m.fields()[0], &*m.fields()[1]),
+                _ => unreachable!("map accessor requires field type of message")
+            };
+
+        let terminator = self.read_message_start()?;
+        let mut key = None;
+        let mut value = None;
+        while !self.try_consume(terminator) {
+            if self.peek().is_none() {
+                return Err(self.error(ParseErrorKind::UnexpectedEof));
+            }
+            let name = self.read_identifier()?;
+            let (target, slot) =
+                match name.as_str() {
+                    "key" => (key_field, &mut key),
+                    "value" => (value_field, &mut value),
+                    _ => return Err(self.error(ParseErrorKind::UnknownField(name))),
+                };
+            if slot.is_some() {
+                return Err(self.error(ParseErrorKind::DuplicateField(name)));
+            }
+            self.read_separator(target.field_type())?;
+            *slot = Some(self.read_element(field, target.field_type())?);
+            if !self.try_consume(',') {
+                self.try_consume(';');
+            }
+        }
+
+        let key = key.unwrap_or_else(|| default_scalar(key_field.field_type()));
+        let value =
+            match value {
+                Some(value) => value,
+                None => match value_field.field_type() {
+                    FieldType::Message(m) | FieldType::Group(m) => m.new_instance().expect("couldn't create an instance of the message type"),
+                    FieldType::Enum(_) => field.new_enum_value(0).ok_or_else(|| self.error(ParseErrorKind::UnknownEnumValue(0.to_string())))?,
+                    field_type => default_scalar(field_type),
+                }
+            };
+        Ok((key, value))
+    }
+
+    fn set_wkt_field(&self, message: &mut dyn AnyMessage<'a>, name: &str, value: Box<dyn AnyValue<'a>>) {
+        match message.descriptor().find_field_by_name(name).and_then(FieldDescriptor::accessor) {
+            Some(FieldAccessor::Single(accessor)) => accessor.set(message, value).expect("couldn't access field on message"),
+            _ => panic!("{}", INVALID_WELL_KNOWN_TYPE),
+        }
+    }
+
+    /// Merges an expanded `Any` value with the specified type url
+    fn merge_any(&mut self, message: &mut dyn AnyMessage<'a>, type_url: String) -> Result<()> {
+        let descriptor =
+            get_type_name(&type_url)
+                .and_then(|name| self.pool.find_message_in_pool(name))
+                .ok_or_else(|| self.error(ParseErrorKind::UnresolvedAny(type_url.to_string())))?;
+        let mut instance = descriptor.new_instance().ok_or_else(|| self.error(ParseErrorKind::UnresolvedAny(type_url.to_string())))?;
+        let instance = instance.as_message_mut().expect(INVALID_VALUE_TYPE);
+
+        self.try_consume(':');
+        let terminator = self.read_message_start()?;
+        self.merge_message(instance, Some(terminator))?;
+
+        let value = instance.write_to_vec().expect(WRITE_VEC_ERROR);
+        self.set_wkt_field(message, "type_url", Box::new(type_url));
+        self.set_wkt_field(message, "value", Box::new(value));
+        Ok(())
+    }
+}
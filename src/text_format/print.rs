@@ -0,0 +1,276 @@
+use super::raw::write_value;
+use crate::reflect::access::FieldAccessor;
+use crate::reflect::{
+    get_single, is_implicit_default, AnyMessage, AnyValue, FieldDescriptor, FieldType, MessageDescriptor, INVALID_VALUE_TYPE,
+    MISREPORTED_LEN,
+};
+use crate::wkt::any::get_type_name;
+use crate::{UnknownField, UnknownFieldSet};
+use std::fmt;
+
+pub(super) fn write_string(output: &mut dyn fmt::Write, value: &str) -> fmt::Result {
+    output.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => output.write_str("\\\"")?,
+            '\'' => output.write_str("\\'")?,
+            '\\' => output.write_str("\\\\")?,
+            '\n' => output.write_str("\\n")?,
+            '\r' => output.write_str("\\r")?,
+            '\t' => output.write_str("\\t")?,
+            c if c < ' ' || c == '\u{7f}' => write!(output, "\\{:03o}", c as u32)?,
+            c => output.write_char(c)?,
+        }
+    }
+    output.write_char('"')
+}
+
+pub(super) fn write_bytes(output: &mut dyn fmt::Write, value: &[u8]) -> fmt::Result {
+    output.write_char('"')?;
+    for &b in value {
+        match b {
+            b'"' => output.write_str("\\\"")?,
+            b'\'' => output.write_str("\\'")?,
+            b'\\' => output.write_str("\\\\")?,
+            b'\n' => output.write_str("\\n")?,
+            b'\r' => output.write_str("\\r")?,
+            b'\t' => output.write_str("\\t")?,
+            b' '..=b'~' => output.write_char(b as char)?,
+            b => write!(output, "\\{:03o}", b)?,
+        }
+    }
+    output.write_char('"')
+}
+
+pub(super) fn write_float<T: Into<f64> + fmt::Display + Copy>(output: &mut dyn fmt::Write, value: T) -> fmt::Result {
+    let wide: f64 = value.into();
+    if wide.is_nan() {
+        output.write_str("nan")
+    } else if wide.is_infinite() {
+        output.write_str(if wide > 0.0 { "inf" } else { "-inf" })
+    } else {
+        write!(output, "{}", value)
+    }
+}
+
+/// Gets the name a field is printed with. Extensions are printed with their full name in brackets
+/// and groups are printed with the name of their message type
+fn write_field_name(output: &mut dyn fmt::Write, field: &FieldDescriptor) -> fmt::Result {
+    if field.is_extension() {
+        write!(output, "[{}]", field.full_name())
+    } else {
+        match field.field_type() {
+            FieldType::Group(m) => output.write_str(m.name()),
+            _ => output.write_str(field.name()),
+        }
+    }
+}
+
+/// Prints messages in the text format, resolving `Any` values with the pool containing a root message type
+pub(super) struct Printer<'a, 'w> {
+    pool: &'a MessageDescriptor<'a>,
+    output: &'w mut dyn fmt::Write,
+    single_line: bool,
+    indent: usize,
+    needs_space: bool,
+}
+
+impl<'a, 'w> Printer<'a, 'w> {
+    pub(super) fn new(pool: &'a MessageDescriptor<'a>, output: &'w mut dyn fmt::Write, single_line: bool) -> Printer<'a, 'w> {
+        Printer { pool, output, single_line, indent: 0, needs_space: false }
+    }
+
+    /// Starts a new field, writing the indentation for the field in multi-line mode or a separating space in single-line mode
+    fn begin_field(&mut self) -> fmt::Result {
+        if self.single_line {
+            if self.needs_space {
+                self.output.write_char(' ')?;
+            }
+        } else {
+            for _ in 0..self.indent {
+                self.output.write_str("  ")?;
+            }
+        }
+        self.needs_space = true;
+        Ok(())
+    }
+
+    fn end_field(&mut self) -> fmt::Result {
+        if !self.single_line {
+            self.output.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    fn begin_message(&mut self) -> fmt::Result {
+        self.output.write_str(" {")?;
+        if !self.single_line {
+            self.output.write_char('\n')?;
+        }
+        self.indent += 1;
+        Ok(())
+    }
+
+    fn end_message(&mut self) -> fmt::Result {
+        self.indent -= 1;
+        if self.single_line {
+            self.output.write_str(" }")
+        } else {
+            for _ in 0..self.indent {
+                self.output.write_str("  ")?;
+            }
+            self.output.write_char('}')
+        }
+    }
+
+    pub(super) fn print_message(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> fmt::Result {
+        if message.descriptor().full_name() == "google.protobuf.Any" && self.print_any(message)? {
+            return Ok(());
+        }
+
+        let descriptor = message.descriptor();
+        let mut fields: Vec<&FieldDescriptor<'a>> =
+            descriptor.fields()
+                .iter()
+                .map(|f| &**f)
+                .chain(descriptor.find_extensions_in_pool())
+                .collect();
+        fields.sort_by_key(|f| f.number());
+
+        for field in fields {
+            self.print_field(message, field)?;
+        }
+
+        match message.unknown_fields() {
+            Some(fields) => self.print_unknown_fields(fields),
+            None => Ok(()),
+        }
+    }
+
+    /// Prints a field if it's set
+    fn print_field(&mut self, message: &(dyn AnyMessage<'a> + 'a), field: &FieldDescriptor<'a>) -> fmt::Result {
+        match field.accessor() {
+            Some(FieldAccessor::Single(accessor)) => {
+                match accessor.get(message) {
+                    Ok(Some(value)) if !is_implicit_default(field, value) => self.print_field_value(field, field.field_type(), value),
+                    _ => Ok(()),
+                }
+            }
+            Some(FieldAccessor::Repeated(accessor)) => {
+                let len = accessor.len(message).unwrap_or(0);
+                for index in 0..len {
+                    let value = accessor.get(message, index).ok().and_then(|v| v).expect(MISREPORTED_LEN);
+                    self.print_field_value(field, field.field_type(), value)?;
+                }
+                Ok(())
+            }
+            Some(FieldAccessor::Map(accessor)) => {
+                let (key_field, value_field) =
+                    match field.field_type() {
+                        FieldType::Message(m) => (&m.fields()[0], &m.fields()[1]),
+                        _ => unreachable!("map accessor requires field type of message")
+                    };
+                if let Ok(entries) = accessor.iter(message) {
+                    for (key, value) in entries {
+                        self.begin_field()?;
+                        write_field_name(self.output, field)?;
+                        self.begin_message()?;
+                        self.print_field_value(key_field, key_field.field_type(), key)?;
+                        self.print_field_value(value_field, value_field.field_type(), value)?;
+                        self.end_message()?;
+                        self.end_field()?;
+                    }
+                }
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn print_field_value(&mut self, field: &FieldDescriptor<'a>, field_type: &FieldType<'a>, value: &(dyn AnyValue<'a> + 'a)) -> fmt::Result {
+        self.begin_field()?;
+        write_field_name(self.output, field)?;
+        match field_type {
+            FieldType::Message(_) | FieldType::Group(_) => {
+                self.begin_message()?;
+                self.print_message(value.as_message().expect(INVALID_VALUE_TYPE))?;
+                self.end_message()?;
+            }
+            field_type => {
+                self.output.write_str(": ")?;
+                self.print_value(field_type, value)?;
+            }
+        }
+        self.end_field()
+    }
+
+    fn print_value(&mut self, field_type: &FieldType<'a>, value: &(dyn AnyValue<'a> + 'a)) -> fmt::Result {
+        match field_type {
+            FieldType::Bool => write!(self.output, "{}", value.downcast_ref::<bool>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Int32 | FieldType::Sint32 | FieldType::Sfixed32 => write!(self.output, "{}", value.downcast_ref::<i32>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Uint32 | FieldType::Fixed32 => write!(self.output, "{}", value.downcast_ref::<u32>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Int64 | FieldType::Sint64 | FieldType::Sfixed64 => write!(self.output, "{}", value.downcast_ref::<i64>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Uint64 | FieldType::Fixed64 => write!(self.output, "{}", value.downcast_ref::<u64>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Float => write_float(self.output, *value.downcast_ref::<f32>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Double => write_float(self.output, *value.downcast_ref::<f64>().expect(INVALID_VALUE_TYPE)),
+            FieldType::String => write_string(self.output, value.downcast_ref::<String>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Bytes => write_bytes(self.output, value.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE)),
+            FieldType::Enum(_) => {
+                let value = value.as_enum().expect(INVALID_VALUE_TYPE);
+                match value.get() {
+                    Some(defined) => self.output.write_str(defined.name()),
+                    None => write!(self.output, "{}", value.get_i32()),
+                }
+            }
+            FieldType::Message(_) | FieldType::Group(_) => unreachable!("messages are printed as fields"),
+        }
+    }
+
+    /// Prints the expanded form of an `Any` value, returning false if the type couldn't be resolved
+    fn print_any(&mut self, message: &(dyn AnyMessage<'a> + 'a)) -> Result<bool, fmt::Error> {
+        let type_url = get_single(message, "type_url").map_or("", |v| v.downcast_ref::<String>().expect(INVALID_VALUE_TYPE));
+        let value = get_single(message, "value").map_or(&[][..], |v| v.downcast_ref::<Vec<u8>>().expect(INVALID_VALUE_TYPE));
+
+        let pool = self.pool;
+        let descriptor =
+            match get_type_name(type_url).and_then(|name| pool.find_message_in_pool(name)) {
+                Some(descriptor) => descriptor,
+                None => return Ok(false),
+            };
+        let mut instance =
+            match descriptor.new_instance() {
+                Some(instance) => instance,
+                None => return Ok(false),
+            };
+        let instance = instance.as_message_mut().expect(INVALID_VALUE_TYPE);
+        if instance.merge_from_slice(value).is_err() {
+            return Ok(false);
+        }
+
+        self.begin_field()?;
+        write!(self.output, "[{}]", type_url)?;
+        self.begin_message()?;
+        self.print_message(instance)?;
+        self.end_message()?;
+        self.end_field()?;
+        Ok(true)
+    }
+
+    /// Prints the fields in the set as their field number and the value read from the wire
+    fn print_unknown_fields(&mut self, fields: &UnknownFieldSet) -> fmt::Result {
+        for (number, value) in fields.iter() {
+            self.begin_field()?;
+            write!(self.output, "{}", number)?;
+            match value {
+                UnknownField::Group(fields) => {
+                    self.begin_message()?;
+                    self.print_unknown_fields(fields)?;
+                    self.end_message()?;
+                }
+                value => write_value(self.output, value)?,
+            }
+            self.end_field()?;
+        }
+        Ok(())
+    }
+}
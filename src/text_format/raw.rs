@@ -0,0 +1,132 @@
+use super::print::{write_bytes, write_float, write_string};
+use crate::io::{CodedInput, InputResult};
+use crate::{UnknownField, UnknownFieldSet};
+use std::fmt;
+
+/// The maximum depth of length delimited values that are tried as nested messages
+const MAX_DEPTH: usize = CodedInput::DEFAULT_RECURSION_LIMIT as usize;
+
+/// Reads all the fields in the data as unknown fields
+pub(super) fn parse(data: &[u8]) -> InputResult<UnknownFieldSet> {
+    let mut input = CodedInput::from_slice(data);
+    let mut fields = UnknownFieldSet::new();
+    while let Some(tag) = input.read_tag()? {
+        fields.merge_from(tag, &mut input)?;
+    }
+    Ok(fields)
+}
+
+/// Returns true if the string doesn't contain any control characters other than whitespace
+fn is_text(value: &str) -> bool {
+    value.chars().all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t')
+}
+
+fn float_string<T: Into<f64> + fmt::Display + Copy>(value: T) -> String {
+    let mut output = String::new();
+    write_float(&mut output, value).expect("a String can't fail to be written to");
+    output
+}
+
+/// Writes the value of an unknown field that isn't a group, with length delimited values written as bytes
+pub(super) fn write_value(output: &mut dyn fmt::Write, value: &UnknownField) -> fmt::Result {
+    match value {
+        UnknownField::Varint(v) => write!(output, ": {}", v),
+        UnknownField::Bit64(v) => write!(output, ": 0x{:016x}", v),
+        UnknownField::Bit32(v) => write!(output, ": 0x{:08x}", v),
+        UnknownField::LengthDelimited(v) => {
+            output.write_str(": ")?;
+            write_bytes(output, v)
+        }
+        UnknownField::Group(_) => unreachable!("groups are printed as messages"),
+    }
+}
+
+/// Prints unknown fields with their possible interpretations in a format like `protoc --decode_raw`
+pub(super) struct RawPrinter<'w> {
+    output: &'w mut dyn fmt::Write,
+    indent: usize,
+}
+
+impl<'w> RawPrinter<'w> {
+    pub(super) fn new(output: &'w mut dyn fmt::Write) -> RawPrinter<'w> {
+        RawPrinter { output, indent: 0 }
+    }
+
+    /// Writes the other interpretations of a value as a comment after it
+    fn write_interpretations(&mut self, interpretations: &[String]) -> fmt::Result {
+        if !interpretations.is_empty() {
+            write!(self.output, "  # {}", interpretations.join(", "))?;
+        }
+        Ok(())
+    }
+
+    fn print_message(&mut self, fields: &UnknownFieldSet) -> fmt::Result {
+        self.output.write_str(" {\n")?;
+        self.indent += 1;
+        self.print_fields(fields)?;
+        self.indent -= 1;
+        for _ in 0..self.indent {
+            self.output.write_str("  ")?;
+        }
+        self.output.write_char('}')
+    }
+
+    pub(super) fn print_fields(&mut self, fields: &UnknownFieldSet) -> fmt::Result {
+        for (number, value) in fields.iter() {
+            for _ in 0..self.indent {
+                self.output.write_str("  ")?;
+            }
+            write!(self.output, "{}", number)?;
+            match value {
+                UnknownField::Varint(v) => {
+                    write_value(self.output, value)?;
+                    let mut interpretations = Vec::new();
+                    if (*v as i64) < 0 {
+                        interpretations.push(format!("int: {}", *v as i64));
+                    }
+                    let sint = (*v >> 1) as i64 ^ -((*v & 1) as i64);
+                    if sint as u64 != *v {
+                        interpretations.push(format!("sint: {}", sint));
+                    }
+                    self.write_interpretations(&interpretations)?;
+                }
+                UnknownField::Bit64(v) => {
+                    write_value(self.output, value)?;
+                    let mut interpretations = vec![format!("uint: {}", v)];
+                    if (*v as i64) < 0 {
+                        interpretations.push(format!("int: {}", *v as i64));
+                    }
+                    interpretations.push(format!("double: {}", float_string(f64::from_bits(*v))));
+                    self.write_interpretations(&interpretations)?;
+                }
+                UnknownField::Bit32(v) => {
+                    write_value(self.output, value)?;
+                    let mut interpretations = vec![format!("uint: {}", v)];
+                    if (*v as i32) < 0 {
+                        interpretations.push(format!("int: {}", *v as i32));
+                    }
+                    interpretations.push(format!("float: {}", float_string(f32::from_bits(*v))));
+                    self.write_interpretations(&interpretations)?;
+                }
+                UnknownField::LengthDelimited(v) => {
+                    // like protoc, anything that can be read as a message is printed as one, even if it's also valid text
+                    match parse(v) {
+                        Ok(ref fields) if !v.is_empty() && self.indent < MAX_DEPTH => self.print_message(fields)?,
+                        _ => {
+                            match std::str::from_utf8(v) {
+                                Ok(s) if is_text(s) => {
+                                    self.output.write_str(": ")?;
+                                    write_string(self.output, s)?;
+                                }
+                                _ => write_value(self.output, value)?,
+                            }
+                        }
+                    }
+                }
+                UnknownField::Group(fields) => self.print_message(fields)?,
+            }
+            self.output.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
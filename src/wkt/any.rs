@@ -1,6 +1,6 @@
-use crate::io::OutputError;
+use crate::io::{InputResult, OutputError};
 use crate::prelude::*;
-use crate::reflect::MessageDescriptor;
+use crate::reflect::{AnyMessage, AnyValue, DescriptorPool, MessageDescriptor};
 
 pub use crate::generated::google_protobuf_any_proto::*;
 
@@ -29,6 +29,62 @@ pub fn get_type_name(url: &str) -> Option<&str> {
     Some(&url[(pos + 1)..])
 }
 
+/// Resolves the message types named by the type urls of `Any` values,
+/// so values of types that aren't known at compile time can be unpacked
+pub trait TypeResolver<'a> {
+    /// Finds the message type named by the type url, or None if the type is unknown
+    fn resolve(&self, type_url: &str) -> Option<&'a MessageDescriptor<'a>>;
+}
+
+/// A type resolver that finds message types in one or more descriptor pools.
+///
+/// The pools are searched in the order they were added, including the pools they depend on.
+///
+/// # Examples
+/// ```
+/// use protrust::wkt::any::{PoolResolver, TypeResolver};
+///
+/// let resolver = PoolResolver::new().with_pool(protrust::generated::pool());
+///
+/// let descriptor = resolver.resolve("type.googleapis.com/google.protobuf.Timestamp").unwrap();
+/// assert_eq!(descriptor.full_name(), "google.protobuf.Timestamp");
+/// assert!(resolver.resolve("type.googleapis.com/foo.Bar").is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct PoolResolver<'a> {
+    pools: Vec<&'a DescriptorPool<'a>>,
+}
+
+impl<'a> PoolResolver<'a> {
+    /// Creates a new resolver without any pools
+    pub fn new() -> PoolResolver<'a> {
+        PoolResolver { pools: Vec::new() }
+    }
+
+    /// Adds a pool to the end of the pools searched by this resolver
+    pub fn with_pool(mut self, pool: &'a DescriptorPool<'a>) -> PoolResolver<'a> {
+        self.add_pool(pool);
+        self
+    }
+
+    /// Adds a pool to the end of the pools searched by this resolver
+    pub fn add_pool(&mut self, pool: &'a DescriptorPool<'a>) {
+        self.pools.push(pool);
+    }
+
+    /// Gets the pools searched by this resolver
+    pub fn pools(&self) -> &[&'a DescriptorPool<'a>] {
+        &self.pools
+    }
+}
+
+impl<'a> TypeResolver<'a> for PoolResolver<'a> {
+    fn resolve(&self, type_url: &str) -> Option<&'a MessageDescriptor<'a>> {
+        let name = get_type_name(type_url)?;
+        self.pools.iter().filter_map(|pool| pool.find_message_by_name(name)).next()
+    }
+}
+
 impl Any {
     /// Creates a new `Any` value from a message of type `T`.
     ///
@@ -153,6 +209,71 @@ impl Any {
     /// # }
     /// ```
     pub fn unpack_unchecked<T: Message>(&self) -> crate::io::InputResult<T> {
-        T::read_new(&mut self.value().as_slice())
+        T::read_new_from_slice(self.value())
+    }
+
+    /// Creates a new `Any` value from a message of any type, including dynamic messages.
+    ///
+    /// This uses the default prefix "type.googleapis.com". A different prefix can be used with `pack_dyn_with_prefix`
+    pub fn pack_dyn<'a>(message: &(dyn AnyMessage<'a> + 'a)) -> Result<Any, OutputError> {
+        Any::pack_dyn_with_prefix(message, DEFAULT_PREFIX)
+    }
+
+    /// Creates a new `Any` value from a message of any type with the specified url prefix, including dynamic messages
+    ///
+    /// # Examples
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::{any::Any, timestamp::Timestamp};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let time = Timestamp::new();
+    /// let any = Any::pack_dyn_with_prefix(&time, "example.com")?;
+    ///
+    /// assert_eq!(any, Any::pack_with_prefix(&time, "example.com")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pack_dyn_with_prefix<'a>(message: &(dyn AnyMessage<'a> + 'a), prefix: &str) -> Result<Any, OutputError> {
+        let mut value = Any::new();
+        *value.type_url_mut() = get_type_url(message.descriptor(), prefix);
+        *value.value_mut() = message.write_to_vec()?;
+
+        Ok(value)
+    }
+
+    /// Unpacks the message into a new instance of the type the resolver finds for the type url,
+    /// returning None if the resolver can't find the type.
+    ///
+    /// Types from pools built at runtime are unpacked into dynamic messages.
+    ///
+    /// # Examples
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::any::{Any, PoolResolver};
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut time = Timestamp::new();
+    /// *time.seconds_mut() = 5;
+    /// let any = Any::pack(&time)?;
+    ///
+    /// let resolver = PoolResolver::new().with_pool(protrust::generated::pool());
+    /// let value = any.unpack_with(&resolver).unwrap()?;
+    /// let message = value.as_message().unwrap();
+    ///
+    /// assert_eq!(message.descriptor().full_name(), "google.protobuf.Timestamp");
+    /// assert_eq!(Any::pack_dyn(message)?, any);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unpack_with<'a>(&self, resolver: &dyn TypeResolver<'a>) -> Option<InputResult<Box<dyn AnyValue<'a> + 'a>>> {
+        let descriptor = resolver.resolve(self.type_url())?;
+        let mut instance = descriptor.new_instance()?;
+        let result =
+            instance.as_message_mut()
+                .expect("message instances are messages")
+                .merge_from_slice(self.value());
+        Some(result.map(|()| instance))
     }
 }
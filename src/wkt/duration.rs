@@ -1 +1,304 @@
+use super::timestamp::{take_char, take_nanos, write_nanos, MAX_NANOS, NANOS_PER_SECOND};
+use crate::LiteMessage;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
 pub use crate::generated::google_protobuf_duration_proto::*;
+
+/// The seconds of the longest valid duration, roughly 10,000 years
+pub(crate) const MAX_SECONDS: i64 = 315_576_000_000;
+
+/// The error returned when converting to or from a `Duration` fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationError {
+    /// The duration was outside the range of ±315,576,000,000.999999999 seconds, its seconds and nanos had different signs,
+    /// or it couldn't be represented by the type it was converted to
+    OutOfRange,
+    /// The text wasn't a duration in the form of `-1.500s`
+    InvalidFormat,
+}
+
+impl Display for DurationError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            DurationError::OutOfRange => write!(fmt, "the duration was out of range"),
+            DurationError::InvalidFormat => write!(fmt, "the text wasn't a valid duration"),
+        }
+    }
+}
+
+impl Error for DurationError {}
+
+impl Duration {
+    fn from_parts(seconds: i64, nanos: i32) -> Duration {
+        let mut result = Duration::new();
+        *result.seconds_mut() = seconds;
+        *result.nanos_mut() = nanos;
+        result
+    }
+
+    /// Returns whether the duration is within the range of ±315,576,000,000.999999999 seconds,
+    /// its nanos are within the range of ±999,999,999, and its seconds and nanos have the same sign
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::duration::Duration;
+    ///
+    /// let mut duration = Duration::new();
+    /// *duration.seconds_mut() = -1;
+    /// assert!(duration.is_valid());
+    ///
+    /// *duration.nanos_mut() = 500_000_000;
+    /// assert!(!duration.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        is_valid(*self.seconds(), *self.nanos())
+    }
+
+    /// Normalizes the duration by moving whole seconds out of its nanos
+    /// and giving its seconds and nanos the same sign, without changing its length
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::duration::Duration;
+    ///
+    /// let mut duration = Duration::new();
+    /// *duration.seconds_mut() = -1;
+    /// *duration.nanos_mut() = 1_500_000_000;
+    /// duration.normalize();
+    ///
+    /// assert_eq!(*duration.seconds(), 0);
+    /// assert_eq!(*duration.nanos(), 500_000_000);
+    /// ```
+    pub fn normalize(&mut self) {
+        let seconds = self.seconds().saturating_add(i64::from(*self.nanos() / NANOS_PER_SECOND));
+        let (seconds, nanos) = normalize(seconds, *self.nanos() % NANOS_PER_SECOND);
+        *self.seconds_mut() = seconds;
+        *self.nanos_mut() = nanos;
+    }
+}
+
+/// Formats the duration in the form of `-1.500s`, with 0, 3, 6, or 9 digits of fractional seconds.
+/// The duration is normalized before it's formatted.
+impl Display for Duration {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let mut normalized = self.clone();
+        normalized.normalize();
+        write_duration(fmt, *normalized.seconds(), *normalized.nanos())
+    }
+}
+
+/// Parses a duration in the form of `-1.500s`, with up to 9 digits of fractional seconds
+///
+/// # Example
+///
+/// ```
+/// use protrust::wkt::duration::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let duration: Duration = "-1.5s".parse()?;
+/// assert_eq!(*duration.seconds(), -1);
+/// assert_eq!(*duration.nanos(), -500_000_000);
+/// assert_eq!(duration.to_string(), "-1.500s");
+/// # Ok(())
+/// # }
+/// ```
+impl FromStr for Duration {
+    type Err = DurationError;
+
+    fn from_str(text: &str) -> Result<Duration, DurationError> {
+        let (seconds, nanos) = parse_duration(text).ok_or(DurationError::InvalidFormat)?;
+        if !is_valid(seconds, nanos) {
+            return Err(DurationError::OutOfRange);
+        }
+        Ok(Duration::from_parts(seconds, nanos))
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    /// Adds the durations, returning a normalized duration
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting seconds overflow an `i64`
+    fn add(self, other: Duration) -> Duration {
+        let seconds =
+            self.seconds()
+                .checked_add(*other.seconds())
+                .and_then(|s| s.checked_add(i64::from(*self.nanos() / NANOS_PER_SECOND + *other.nanos() / NANOS_PER_SECOND)))
+                .expect("overflow when adding durations");
+        let nanos = *self.nanos() % NANOS_PER_SECOND + *other.nanos() % NANOS_PER_SECOND;
+        let seconds = seconds.checked_add(i64::from(nanos / NANOS_PER_SECOND)).expect("overflow when adding durations");
+        let (seconds, nanos) = normalize(seconds, nanos % NANOS_PER_SECOND);
+        Duration::from_parts(seconds, nanos)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    /// Subtracts the durations, returning a normalized duration
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting seconds overflow an `i64`
+    fn sub(self, other: Duration) -> Duration {
+        self + -other
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    /// Negates the duration
+    ///
+    /// # Panics
+    ///
+    /// This panics if the duration's seconds or nanos are the minimum value of their type
+    fn neg(self) -> Duration {
+        Duration::from_parts(-*self.seconds(), -*self.nanos())
+    }
+}
+
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: std::time::Duration) -> Result<Duration, DurationError> {
+        let seconds = i64::try_from(duration.as_secs()).map_err(|_| DurationError::OutOfRange)?;
+        let result = Duration::from_parts(seconds, duration.subsec_nanos() as i32);
+        if result.is_valid() {
+            Ok(result)
+        } else {
+            Err(DurationError::OutOfRange)
+        }
+    }
+}
+
+/// Converts a valid duration to a `std::time::Duration`, failing if the duration is negative
+impl TryFrom<&Duration> for std::time::Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: &Duration) -> Result<std::time::Duration, DurationError> {
+        if !duration.is_valid() || *duration.seconds() < 0 || *duration.nanos() < 0 {
+            return Err(DurationError::OutOfRange);
+        }
+        Ok(std::time::Duration::new(*duration.seconds() as u64, *duration.nanos() as u32))
+    }
+}
+
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: Duration) -> Result<std::time::Duration, DurationError> {
+        std::time::Duration::try_from(&duration)
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<chrono::Duration> for Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: chrono::Duration) -> Result<Duration, DurationError> {
+        let seconds = duration.num_seconds();
+        let nanos =
+            (duration - chrono::Duration::seconds(seconds))
+                .num_nanoseconds()
+                .expect("the nanos of a duration are less than a second");
+        let result = Duration::from_parts(seconds, nanos as i32);
+        if result.is_valid() {
+            Ok(result)
+        } else {
+            Err(DurationError::OutOfRange)
+        }
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<&Duration> for chrono::Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: &Duration) -> Result<chrono::Duration, DurationError> {
+        if !duration.is_valid() {
+            return Err(DurationError::OutOfRange);
+        }
+        Ok(chrono::Duration::seconds(*duration.seconds()) + chrono::Duration::nanoseconds(i64::from(*duration.nanos())))
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<Duration> for chrono::Duration {
+    type Error = DurationError;
+
+    fn try_from(duration: Duration) -> Result<chrono::Duration, DurationError> {
+        chrono::Duration::try_from(&duration)
+    }
+}
+
+pub(crate) fn is_valid(seconds: i64, nanos: i32) -> bool {
+    (-MAX_SECONDS..=MAX_SECONDS).contains(&seconds)
+        && (-MAX_NANOS..=MAX_NANOS).contains(&nanos)
+        && !(seconds < 0 && nanos > 0)
+        && !(seconds > 0 && nanos < 0)
+}
+
+/// Gives seconds and nanos of less than a second the same sign
+fn normalize(seconds: i64, nanos: i32) -> (i64, i32) {
+    if seconds > 0 && nanos < 0 {
+        (seconds - 1, nanos + NANOS_PER_SECOND)
+    } else if seconds < 0 && nanos > 0 {
+        (seconds + 1, nanos - NANOS_PER_SECOND)
+    } else {
+        (seconds, nanos)
+    }
+}
+
+/// Writes a duration whose seconds and nanos have the same sign in the form of "-1.500s"
+pub(crate) fn write_duration(output: &mut dyn fmt::Write, seconds: i64, nanos: i32) -> fmt::Result {
+    if seconds < 0 || nanos < 0 {
+        output.write_char('-')?;
+    }
+    write!(output, "{}", abs(seconds))?;
+    write_nanos(output, abs(i64::from(nanos)) as u32)?;
+    output.write_char('s')
+}
+
+/// Gets the absolute value of the number, without overflowing at `i64::min_value()`
+fn abs(value: i64) -> u64 {
+    if value < 0 {
+        (value as u64).wrapping_neg()
+    } else {
+        value as u64
+    }
+}
+
+/// Parses a duration in the form of "-1.500s" into seconds and nanos
+pub(crate) fn parse_duration(text: &str) -> Option<(i64, i32)> {
+    let (negative, text) =
+        match take_char(text, &['-']) {
+            Some(text) => (true, text),
+            None => (false, text),
+        };
+    let len = text.bytes().take_while(u8::is_ascii_digit).count();
+    if len == 0 {
+        return None;
+    }
+    let seconds = text[..len].parse::<i64>().ok()?;
+    let (nanos, text) = take_nanos(&text[len..])?;
+    if text != "s" {
+        return None;
+    }
+    if negative {
+        Some((-seconds, -nanos))
+    } else {
+        Some((seconds, nanos))
+    }
+}
@@ -1 +1,377 @@
+use crate::io::InvalidFieldPath;
+use crate::reflect::access::FieldAccessor;
+use crate::reflect::{AnyMessage, FieldLabel, FieldType, MessageDescriptor};
+use crate::LiteMessage;
+use std::collections::BTreeMap;
+
 pub use crate::generated::google_protobuf_field_mask_proto::*;
+
+static FAILED_ACCESS_ERROR: &str = "couldn't access field on message";
+static MISREPORTED_LEN: &str = "repeated field accessor misreported the field's length";
+
+/// Options for merging messages with [`merge_with_mask`](fn.merge_with_mask.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MergeOptions {
+    /// Replaces the values of repeated and map fields named by the mask with the source's values
+    /// instead of appending the source's values to them
+    pub replace_repeated_fields: bool,
+    /// Replaces message fields named by the mask with the source's message
+    /// instead of merging the source's message into them. A message field that isn't set in the source is cleared
+    pub replace_message_fields: bool,
+    /// Clears primitive fields named by the mask that aren't set in the source
+    /// instead of leaving them unchanged
+    pub replace_primitive_fields: bool,
+}
+
+impl FieldMask {
+    /// Creates a new field mask containing the specified paths
+    pub fn from_paths<S: Into<String>>(paths: impl IntoIterator<Item = S>) -> FieldMask {
+        let mut mask = FieldMask::new();
+        mask.paths_mut().extend(paths.into_iter().map(Into::into));
+        mask
+    }
+
+    /// Checks that every path in the mask names a field of the message type.
+    /// Every field in a path except for the last must be a singular message field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::Message;
+    /// use protrust::wkt::field_mask::FieldMask;
+    /// use protrust::wkt::r#type::Type;
+    ///
+    /// assert!(FieldMask::from_paths(vec!["name", "source_context.file_name"]).validate(Type::descriptor()).is_ok());
+    /// assert!(FieldMask::from_paths(vec!["fields.name"]).validate(Type::descriptor()).is_err());
+    /// ```
+    pub fn validate(&self, descriptor: &MessageDescriptor) -> Result<(), InvalidFieldPath> {
+        match self.paths().iter().find(|path| !is_valid_path(descriptor, path)) {
+            Some(path) => Err(InvalidFieldPath(path.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Normalizes the mask by sorting its paths and removing empty and duplicate paths,
+    /// along with paths that are included in another path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::wkt::field_mask::FieldMask;
+    ///
+    /// let mut mask = FieldMask::from_paths(vec!["foo.bar", "baz", "foo", "baz"]);
+    /// mask.normalize();
+    /// assert_eq!(mask.paths().as_slice(), &["baz", "foo"]);
+    /// ```
+    pub fn normalize(&mut self) {
+        let tree = PathTree::from_mask(self);
+        self.paths_mut().clear();
+        tree.collect_paths(String::new(), self);
+    }
+
+    /// Returns a normalized mask containing the paths included in either mask
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::wkt::field_mask::FieldMask;
+    ///
+    /// let a = FieldMask::from_paths(vec!["foo.bar", "baz"]);
+    /// let b = FieldMask::from_paths(vec!["foo", "qux"]);
+    /// assert_eq!(a.union(&b).paths().as_slice(), &["baz", "foo", "qux"]);
+    /// ```
+    pub fn union(&self, other: &FieldMask) -> FieldMask {
+        let mut tree = PathTree::from_mask(self);
+        for path in other.paths().iter() {
+            tree.add_path(path);
+        }
+        tree.into_mask()
+    }
+
+    /// Returns a normalized mask containing the paths included in both masks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::wkt::field_mask::FieldMask;
+    ///
+    /// let a = FieldMask::from_paths(vec!["foo.bar", "baz"]);
+    /// let b = FieldMask::from_paths(vec!["foo", "qux"]);
+    /// assert_eq!(a.intersection(&b).paths().as_slice(), &["foo.bar"]);
+    /// ```
+    pub fn intersection(&self, other: &FieldMask) -> FieldMask {
+        let tree = PathTree::from_mask(self);
+        let mut result = PathTree::default();
+        for path in other.paths().iter() {
+            tree.intersect_path(path, &mut result);
+        }
+        result.into_mask()
+    }
+}
+
+/// Merges the fields named by the mask from the source message into the destination message.
+///
+/// Fields named by a path are merged as a whole, while the fields before the last in a path
+/// are merged with only the nested fields named by the mask.
+///
+/// # Panics
+///
+/// This panics if the source and destination messages aren't of the same type
+///
+/// # Example
+///
+/// ```
+/// use protrust::LiteMessage;
+/// use protrust::wkt::field_mask::{self, FieldMask, MergeOptions};
+/// use protrust::wkt::r#type::Type;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut update = Type::new();
+/// update.name_mut().push_str("new name");
+/// update.oneofs_mut().push("new oneof".to_string());
+/// update.source_context_mut().file_name_mut().push_str("new.proto");
+///
+/// let mut value = Type::new();
+/// value.name_mut().push_str("old name");
+/// value.oneofs_mut().push("old oneof".to_string());
+///
+/// let mask = FieldMask::from_paths(vec!["name", "oneofs"]);
+/// field_mask::merge_with_mask(&update, &mut value, &mask, &MergeOptions::default())?;
+///
+/// assert_eq!(value.name(), "new name");
+/// assert_eq!(value.oneofs().as_slice(), &["old oneof", "new oneof"]);
+/// assert!(value.source_context().is_none());
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge_with_mask<'a>(
+    source: &(dyn AnyMessage<'a> + 'a),
+    destination: &mut (dyn AnyMessage<'a> + 'a),
+    mask: &FieldMask,
+    options: &MergeOptions,
+) -> Result<(), InvalidFieldPath> {
+    assert_eq!(
+        source.descriptor().full_name(),
+        destination.descriptor().full_name(),
+        "the source and destination messages must be of the same type");
+    mask.validate(source.descriptor())?;
+    PathTree::from_mask(mask).merge(source, destination, options);
+    Ok(())
+}
+
+/// Clears all the fields of the message that aren't named by the mask.
+/// Unknown fields and extensions aren't cleared.
+///
+/// # Example
+///
+/// ```
+/// use protrust::LiteMessage;
+/// use protrust::wkt::field_mask::{self, FieldMask};
+/// use protrust::wkt::r#type::Type;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut value = Type::new();
+/// value.name_mut().push_str("name");
+/// value.oneofs_mut().push("oneof".to_string());
+/// value.source_context_mut().file_name_mut().push_str("file.proto");
+///
+/// field_mask::trim(&mut value, &FieldMask::from_paths(vec!["source_context.file_name"]))?;
+///
+/// assert_eq!(value.name(), "");
+/// assert!(value.oneofs().is_empty());
+/// assert_eq!(value.source_context().map(|c| c.file_name().as_str()), Some("file.proto"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn trim<'a>(message: &mut (dyn AnyMessage<'a> + 'a), mask: &FieldMask) -> Result<(), InvalidFieldPath> {
+    mask.validate(message.descriptor())?;
+    PathTree::from_mask(mask).trim(message);
+    Ok(())
+}
+
+fn is_valid_path(descriptor: &MessageDescriptor, path: &str) -> bool {
+    let mut descriptor = descriptor;
+    let mut names = path.split('.').peekable();
+    while let Some(name) = names.next() {
+        let field =
+            match descriptor.find_field_by_name(name) {
+                Some(field) => field,
+                None => return false,
+            };
+        if names.peek().is_some() {
+            descriptor =
+                match (field.label(), field.field_type()) {
+                    (FieldLabel::Repeated, _) => return false,
+                    (_, FieldType::Message(message)) | (_, FieldType::Group(message)) => message,
+                    _ => return false,
+                };
+        }
+    }
+    true
+}
+
+/// A tree of the field names in a mask's paths.
+/// A node without children other than the root includes the whole field it names
+#[derive(Default)]
+struct PathTree {
+    children: BTreeMap<String, PathTree>,
+}
+
+impl PathTree {
+    fn from_mask(mask: &FieldMask) -> PathTree {
+        let mut tree = PathTree::default();
+        for path in mask.paths().iter() {
+            tree.add_path(path);
+        }
+        tree
+    }
+
+    fn into_mask(self) -> FieldMask {
+        let mut mask = FieldMask::new();
+        self.collect_paths(String::new(), &mut mask);
+        mask
+    }
+
+    fn add_path(&mut self, path: &str) {
+        if path.is_empty() {
+            return;
+        }
+        let mut node = self;
+        let mut new_branch = false;
+        for (index, name) in path.split('.').enumerate() {
+            if !new_branch && index != 0 && node.children.is_empty() {
+                // the path is included in a path that's already in the tree
+                return;
+            }
+            new_branch |= !node.children.contains_key(name);
+            node = node.children.entry(name.to_string()).or_default();
+        }
+        node.children.clear();
+    }
+
+    fn intersect_path(&self, path: &str, output: &mut PathTree) {
+        if path.is_empty() {
+            return;
+        }
+        let mut node = self;
+        let mut prefix = String::new();
+        for (index, name) in path.split('.').enumerate() {
+            if index != 0 && node.children.is_empty() {
+                output.add_path(path);
+                return;
+            }
+            match node.children.get(name) {
+                Some(child) => node = child,
+                None => return,
+            }
+            if index != 0 {
+                prefix.push('.');
+            }
+            prefix.push_str(name);
+        }
+        // the path includes everything in the tree under it
+        let mut mask = FieldMask::new();
+        node.collect_paths(prefix, &mut mask);
+        for path in mask.paths().iter() {
+            output.add_path(path);
+        }
+    }
+
+    fn collect_paths(&self, prefix: String, mask: &mut FieldMask) {
+        if self.children.is_empty() {
+            if !prefix.is_empty() {
+                mask.paths_mut().push(prefix);
+            }
+            return;
+        }
+        for (name, child) in &self.children {
+            let path = if prefix.is_empty() { name.clone() } else { format!("{}.{}", prefix, name) };
+            child.collect_paths(path, mask);
+        }
+    }
+
+    fn merge<'a>(&self, source: &(dyn AnyMessage<'a> + 'a), destination: &mut (dyn AnyMessage<'a> + 'a), options: &MergeOptions) {
+        let descriptor = source.descriptor();
+        for (name, child) in &self.children {
+            let field = descriptor.find_field_by_name(name).expect("the path was validated");
+            match field.accessor() {
+                Some(FieldAccessor::Single(accessor)) => {
+                    let value = accessor.get(source).expect(FAILED_ACCESS_ERROR);
+                    let is_message = field.field_type().is_message() || field.field_type().is_group();
+                    if !child.children.is_empty() {
+                        // only the fields of the nested message named by the mask are merged
+                        let destination_set = accessor.get(destination).expect(FAILED_ACCESS_ERROR).is_some();
+                        let empty;
+                        let source_message =
+                            match value {
+                                Some(value) => value,
+                                None if destination_set => {
+                                    empty = match field.field_type() {
+                                        FieldType::Message(message) | FieldType::Group(message) => message.new_instance().expect("message fields can't be map entries"),
+                                        _ => unreachable!("the path was validated"),
+                                    };
+                                    &*empty
+                                }
+                                None => continue,
+                            };
+                        let destination_message = accessor.get_mut(destination).expect(FAILED_ACCESS_ERROR);
+                        child.merge(
+                            source_message.as_message().expect("message fields contain messages"),
+                            destination_message.as_message_mut().expect("message fields contain messages"),
+                            options);
+                    } else if is_message && !options.replace_message_fields {
+                        if let Some(value) = value {
+                            accessor.get_mut(destination).expect(FAILED_ACCESS_ERROR)
+                                .as_message_mut().expect("message fields contain messages")
+                                .merge(value.as_message().expect("message fields contain messages"));
+                        }
+                    } else {
+                        match value {
+                            Some(value) => accessor.set(destination, value.clone()).expect(FAILED_ACCESS_ERROR),
+                            None if is_message || options.replace_primitive_fields => accessor.clear(destination).expect(FAILED_ACCESS_ERROR),
+                            None => {}
+                        }
+                    }
+                }
+                Some(FieldAccessor::Repeated(accessor)) => {
+                    if options.replace_repeated_fields {
+                        accessor.clear(destination).expect(FAILED_ACCESS_ERROR);
+                    }
+                    for index in 0..accessor.len(source).expect(FAILED_ACCESS_ERROR) {
+                        let value = accessor.get(source, index).expect(FAILED_ACCESS_ERROR).expect(MISREPORTED_LEN);
+                        accessor.push(destination, value.clone()).expect(FAILED_ACCESS_ERROR);
+                    }
+                }
+                Some(FieldAccessor::Map(accessor)) => {
+                    if options.replace_repeated_fields {
+                        accessor.clear(destination).expect(FAILED_ACCESS_ERROR);
+                    }
+                    for (key, value) in accessor.iter(source).expect(FAILED_ACCESS_ERROR) {
+                        accessor.insert(destination, key.clone(), value.clone()).expect(FAILED_ACCESS_ERROR);
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn trim<'a>(&self, message: &mut (dyn AnyMessage<'a> + 'a)) {
+        let descriptor = message.descriptor();
+        for field in descriptor.fields() {
+            let child = self.children.get(field.name());
+            match (field.accessor(), child) {
+                (_, Some(child)) if child.children.is_empty() => {}
+                (Some(FieldAccessor::Single(accessor)), Some(child)) => {
+                    if accessor.get(message).expect(FAILED_ACCESS_ERROR).is_some() {
+                        let nested = accessor.get_mut(message).expect(FAILED_ACCESS_ERROR);
+                        child.trim(nested.as_message_mut().expect("message fields contain messages"));
+                    }
+                }
+                (Some(FieldAccessor::Single(accessor)), None) => accessor.clear(message).expect(FAILED_ACCESS_ERROR),
+                (Some(FieldAccessor::Repeated(accessor)), _) => accessor.clear(message).expect(FAILED_ACCESS_ERROR),
+                (Some(FieldAccessor::Map(accessor)), _) => accessor.clear(message).expect(FAILED_ACCESS_ERROR),
+                (None, _) => {}
+            }
+        }
+    }
+}
@@ -23,12 +23,63 @@ pub mod any;
 pub use crate::generated::google_protobuf_api_proto as api;
 pub use crate::generated::google_protobuf_empty_proto as empty;
 /// Provides runtime support for the `Duration` type defined in `google/protobuf/duration.proto`
+///
+/// ### Conversions and arithmetic
+/// ```
+/// use protrust::wkt::duration::Duration;
+/// use std::convert::TryFrom;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let timeout = Duration::try_from(std::time::Duration::from_millis(1_500))?;
+/// let elapsed: Duration = "2s".parse()?;
+///
+/// let remaining = timeout - elapsed;
+/// assert_eq!(remaining.to_string(), "-0.500s");
+/// assert!(std::time::Duration::try_from(remaining).is_err());
+/// # Ok(())
+/// # }
+/// ```
 pub mod duration;
 /// Provides runtime support for the `FieldMask` type defined in `google/protobuf/field_mask.proto`
 pub mod field_mask;
 pub use crate::generated::google_protobuf_source_context_proto as source_context;
-pub use crate::generated::google_protobuf_struct_proto as r#struct;
+/// Provides runtime support for the `Struct`, `Value`, and `ListValue` types defined in `google/protobuf/struct.proto`
+///
+/// ### Building and reading values
+/// ```
+/// use protrust::wkt::r#struct::{ListValue, Struct, Value};
+///
+/// let mut metadata: Struct = vec![("region", "us-east")].into_iter().collect();
+/// metadata.insert("retries", 3);
+/// metadata.insert("zones", vec!["a", "b"]);
+///
+/// let value = Value::from(metadata);
+/// assert_eq!(value["region"].as_str(), Some("us-east"));
+/// assert_eq!(value["retries"].as_f64(), Some(3.0));
+/// assert_eq!(value.get("zones").and_then(Value::as_list).and_then(|zones| zones[1].as_str()), Some("b"));
+/// assert!(value.get("missing").is_none());
+///
+/// let list: ListValue = vec![Value::from(true), Value::null()].into();
+/// assert!(list[1].is_null());
+/// ```
+pub mod r#struct;
 /// Provides runtime support for the `Timestamp` type defined in `google/protobuf/timestamp.proto`
+///
+/// ### Conversions
+/// ```
+/// use protrust::wkt::timestamp::Timestamp;
+/// use std::convert::TryFrom;
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let system_time = UNIX_EPOCH + Duration::from_millis(1_500);
+/// let time = Timestamp::try_from(system_time)?;
+///
+/// assert_eq!(time, "1970-01-01T00:00:01.500Z".parse()?);
+/// assert_eq!(SystemTime::try_from(time)?, system_time);
+/// # Ok(())
+/// # }
+/// ```
 pub mod timestamp;
 pub use crate::generated::google_protobuf_type_proto as r#type;
 pub use crate::generated::google_protobuf_wrappers_proto as wrappers;
@@ -0,0 +1,310 @@
+use crate::{EnumValue, LiteMessage};
+use std::collections::{BTreeMap, HashMap};
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+pub use crate::generated::google_protobuf_struct_proto::*;
+
+impl Value {
+    /// Creates a new value containing null
+    pub fn null() -> Value {
+        Value::from(NullValue::NullValue)
+    }
+
+    /// Returns whether the value contains null
+    pub fn is_null(&self) -> bool {
+        self.has_null_value()
+    }
+
+    /// Gets the value as a bool, or None if the value doesn't contain a bool
+    pub fn as_bool(&self) -> Option<bool> {
+        self.bool_value().cloned()
+    }
+
+    /// Gets the value as a number, or None if the value doesn't contain a number
+    pub fn as_f64(&self) -> Option<f64> {
+        self.number_value().cloned()
+    }
+
+    /// Gets the value as a string, or None if the value doesn't contain a string
+    pub fn as_str(&self) -> Option<&str> {
+        self.string_value().map(String::as_str)
+    }
+
+    /// Gets the value as a struct, or None if the value doesn't contain a struct
+    pub fn as_struct(&self) -> Option<&Struct> {
+        self.struct_value()
+    }
+
+    /// Gets the value as a mutable struct, or None if the value doesn't contain a struct
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match self.kind_mut() {
+            value::Kind::StructValue(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a list, or None if the value doesn't contain a list
+    pub fn as_list(&self) -> Option<&ListValue> {
+        self.list_value()
+    }
+
+    /// Gets the value as a mutable list, or None if the value doesn't contain a list
+    pub fn as_list_mut(&mut self) -> Option<&mut ListValue> {
+        match self.kind_mut() {
+            value::Kind::ListValue(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of the field with the specified key, or None if the value isn't a struct or doesn't contain the field
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_struct().and_then(|value| value.get(key))
+    }
+
+    /// Gets the mutable value of the field with the specified key, or None if the value isn't a struct or doesn't contain the field
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.as_struct_mut().and_then(|value| value.get_mut(key))
+    }
+}
+
+/// Gets the value of the field with the specified key
+///
+/// # Panics
+///
+/// This panics if the value isn't a struct or doesn't contain the field
+impl Index<&str> for Value {
+    type Output = Value;
+
+    fn index(&self, key: &str) -> &Value {
+        match self.as_struct() {
+            Some(value) => &value[key],
+            None => panic!("the value isn't a struct"),
+        }
+    }
+}
+
+impl Struct {
+    /// Gets the value of the field with the specified key, or None if the struct doesn't contain the field
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields().get(key)
+    }
+
+    /// Gets the mutable value of the field with the specified key, or None if the struct doesn't contain the field
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.fields_mut().get_mut(key)
+    }
+
+    /// Sets the field with the specified key, returning the field's old value if it was already set
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Value>) -> Option<Value> {
+        self.fields_mut().insert(key.into(), value.into())
+    }
+
+    /// Removes the field with the specified key, returning its value if it was set
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.fields_mut().remove(key)
+    }
+}
+
+/// Gets the value of the field with the specified key
+///
+/// # Panics
+///
+/// This panics if the struct doesn't contain the field
+impl Index<&str> for Struct {
+    type Output = Value;
+
+    fn index(&self, key: &str) -> &Value {
+        self.get(key).unwrap_or_else(|| panic!("the struct doesn't contain the field '{}'", key))
+    }
+}
+
+impl ListValue {
+    /// Adds a value to the end of the list
+    pub fn push(&mut self, value: impl Into<Value>) {
+        self.values_mut().push(value.into());
+    }
+}
+
+impl Index<usize> for ListValue {
+    type Output = Value;
+
+    fn index(&self, index: usize) -> &Value {
+        &self.values()[index]
+    }
+}
+
+impl IndexMut<usize> for ListValue {
+    fn index_mut(&mut self, index: usize) -> &mut Value {
+        &mut self.values_mut()[index]
+    }
+}
+
+impl From<NullValue> for Value {
+    fn from(value: NullValue) -> Value {
+        let mut result = Value::new();
+        result.set_null_value(EnumValue::Defined(value));
+        result
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        let mut result = Value::new();
+        result.set_bool_value(value);
+        result
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        let mut result = Value::new();
+        result.set_number_value(value);
+        result
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Value {
+        Value::from(f64::from(value))
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Value {
+        Value::from(f64::from(value))
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Value {
+        Value::from(f64::from(value))
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        let mut result = Value::new();
+        result.set_string_value(value);
+        result
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::from(value.to_string())
+    }
+}
+
+impl From<Struct> for Value {
+    fn from(value: Struct) -> Value {
+        let mut result = Value::new();
+        result.set_struct_value(value);
+        result
+    }
+}
+
+impl From<ListValue> for Value {
+    fn from(value: ListValue) -> Value {
+        let mut result = Value::new();
+        result.set_list_value(value);
+        result
+    }
+}
+
+/// Converts the value, or null if the option is None
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Value {
+        match value {
+            Some(value) => value.into(),
+            None => Value::null(),
+        }
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Value {
+        Value::from(ListValue::from(value))
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<HashMap<K, V>> for Value {
+    fn from(value: HashMap<K, V>) -> Value {
+        Value::from(Struct::from(value))
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<BTreeMap<K, V>> for Value {
+    fn from(value: BTreeMap<K, V>) -> Value {
+        Value::from(Struct::from(value))
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for ListValue {
+    fn from(value: Vec<T>) -> ListValue {
+        value.into_iter().collect()
+    }
+}
+
+impl<T: Into<Value>> FromIterator<T> for ListValue {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ListValue {
+        let mut result = ListValue::new();
+        result.values_mut().extend(iter.into_iter().map(Into::into));
+        result
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<HashMap<K, V>> for Struct {
+    fn from(value: HashMap<K, V>) -> Struct {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<BTreeMap<K, V>> for Struct {
+    fn from(value: BTreeMap<K, V>) -> Struct {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for Struct {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Struct {
+        let mut result = Struct::new();
+        for (key, value) in iter {
+            result.insert(key, value);
+        }
+        result
+    }
+}
+
+/// Creates a [`Struct`](wkt/struct/struct.Struct.html) from a list of keys and values.
+///
+/// Keys can be anything that converts into a `String` and values anything that converts into a `Value`,
+/// including other structs created with this macro.
+///
+/// # Example
+///
+/// ```
+/// use protrust::struct_value;
+///
+/// let value = struct_value! {
+///     "name" => "protrust",
+///     "stars" => 5,
+///     "tags" => vec!["protobuf", "rust"],
+///     "owner" => struct_value! { "id" => 1 },
+///     "license" => None::<&str>,
+/// };
+///
+/// assert_eq!(value["name"].as_str(), Some("protrust"));
+/// assert_eq!(value["tags"].as_list().map(|list| list.values().len()), Some(2));
+/// assert_eq!(value["owner"]["id"].as_f64(), Some(1.0));
+/// assert!(value["license"].is_null());
+/// ```
+#[macro_export]
+macro_rules! struct_value {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut result = <$crate::wkt::r#struct::Struct as ::std::default::Default>::default();
+        $(result.insert($key, $value);)*
+        result
+    }};
+}
@@ -1 +1,376 @@
+use crate::LiteMessage;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 pub use crate::generated::google_protobuf_timestamp_proto::*;
+
+/// The seconds of the earliest valid timestamp, `0001-01-01T00:00:00Z`
+pub(crate) const MIN_SECONDS: i64 = -62_135_596_800;
+/// The seconds of the latest valid timestamp, `9999-12-31T23:59:59Z`
+pub(crate) const MAX_SECONDS: i64 = 253_402_300_799;
+pub(crate) const MAX_NANOS: i32 = 999_999_999;
+pub(crate) const NANOS_PER_SECOND: i32 = 1_000_000_000;
+
+/// The error returned when converting to or from a `Timestamp` fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampError {
+    /// The timestamp was outside the range `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z`,
+    /// its nanos were outside the range 0 to 999,999,999, or it couldn't be represented by the type it was converted to
+    OutOfRange,
+    /// The text wasn't a valid RFC 3339 date
+    InvalidFormat,
+}
+
+impl Display for TimestampError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            TimestampError::OutOfRange => write!(fmt, "the timestamp was out of range"),
+            TimestampError::InvalidFormat => write!(fmt, "the text wasn't a valid RFC 3339 date"),
+        }
+    }
+}
+
+impl Error for TimestampError {}
+
+impl Timestamp {
+    /// Creates a new timestamp with the current system time
+    ///
+    /// # Panics
+    ///
+    /// This panics if the system time is outside the range of a `Timestamp`
+    pub fn now() -> Timestamp {
+        Timestamp::try_from(SystemTime::now()).expect("the system time was out of the range of Timestamp")
+    }
+
+    /// Returns whether the timestamp is within the range `0001-01-01T00:00:00Z` to `9999-12-31T23:59:59.999999999Z`
+    /// and its nanos are within the range 0 to 999,999,999
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// let mut time = Timestamp::new();
+    /// assert!(time.is_valid());
+    ///
+    /// *time.nanos_mut() = -1;
+    /// assert!(!time.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        is_valid(*self.seconds(), *self.nanos())
+    }
+
+    /// Normalizes the timestamp by moving whole seconds out of its nanos,
+    /// so the nanos are within the range 0 to 999,999,999
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// let mut time = Timestamp::new();
+    /// *time.seconds_mut() = 10;
+    /// *time.nanos_mut() = -1_500_000_000;
+    /// time.normalize();
+    ///
+    /// assert_eq!(*time.seconds(), 8);
+    /// assert_eq!(*time.nanos(), 500_000_000);
+    /// ```
+    pub fn normalize(&mut self) {
+        let mut seconds = i64::from(*self.nanos() / NANOS_PER_SECOND);
+        let mut nanos = *self.nanos() % NANOS_PER_SECOND;
+        if nanos < 0 {
+            seconds -= 1;
+            nanos += NANOS_PER_SECOND;
+        }
+        *self.seconds_mut() = self.seconds().saturating_add(seconds);
+        *self.nanos_mut() = nanos;
+    }
+
+    /// Formats the timestamp as an RFC 3339 date in UTC, like `1972-01-01T10:00:20.021Z`.
+    /// The fractional seconds use 0, 3, 6, or 9 digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protrust::LiteMessage;
+    /// use protrust::wkt::timestamp::Timestamp;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut time = Timestamp::new();
+    /// *time.seconds_mut() = 1_000_000_000;
+    /// *time.nanos_mut() = 500_000_000;
+    ///
+    /// assert_eq!(time.to_rfc3339()?, "2001-09-09T01:46:40.500Z");
+    /// assert_eq!(time.to_rfc3339()?.parse::<Timestamp>()?, time);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_rfc3339(&self) -> Result<String, TimestampError> {
+        if !self.is_valid() {
+            return Err(TimestampError::OutOfRange);
+        }
+        let mut result = String::new();
+        write_rfc3339(&mut result, *self.seconds(), *self.nanos()).expect("writing to a String can't fail");
+        Ok(result)
+    }
+}
+
+/// Parses an RFC 3339 date with any UTC offset, like `1972-01-01T10:00:20.021-05:00`
+impl FromStr for Timestamp {
+    type Err = TimestampError;
+
+    fn from_str(text: &str) -> Result<Timestamp, TimestampError> {
+        let (seconds, nanos) = parse_rfc3339(text).ok_or(TimestampError::InvalidFormat)?;
+        if !is_valid(seconds, nanos) {
+            return Err(TimestampError::OutOfRange);
+        }
+        let mut result = Timestamp::new();
+        *result.seconds_mut() = seconds;
+        *result.nanos_mut() = nanos;
+        Ok(result)
+    }
+}
+
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(time: SystemTime) -> Result<Timestamp, TimestampError> {
+        let mut result = Timestamp::new();
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => {
+                *result.seconds_mut() = i64::try_from(since.as_secs()).map_err(|_| TimestampError::OutOfRange)?;
+                *result.nanos_mut() = since.subsec_nanos() as i32;
+            }
+            Err(e) => {
+                let before = e.duration();
+                *result.seconds_mut() = -i64::try_from(before.as_secs()).map_err(|_| TimestampError::OutOfRange)?;
+                *result.nanos_mut() = -(before.subsec_nanos() as i32);
+                result.normalize();
+            }
+        }
+        if result.is_valid() {
+            Ok(result)
+        } else {
+            Err(TimestampError::OutOfRange)
+        }
+    }
+}
+
+impl TryFrom<&Timestamp> for SystemTime {
+    type Error = TimestampError;
+
+    fn try_from(time: &Timestamp) -> Result<SystemTime, TimestampError> {
+        if !time.is_valid() {
+            return Err(TimestampError::OutOfRange);
+        }
+        let seconds = *time.seconds();
+        let nanos = std::time::Duration::from_nanos(*time.nanos() as u64);
+        let result =
+            if seconds >= 0 {
+                UNIX_EPOCH.checked_add(std::time::Duration::from_secs(seconds as u64))
+            } else {
+                UNIX_EPOCH.checked_sub(std::time::Duration::from_secs(seconds.wrapping_neg() as u64))
+            };
+        result.and_then(|t| t.checked_add(nanos)).ok_or(TimestampError::OutOfRange)
+    }
+}
+
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = TimestampError;
+
+    fn try_from(time: Timestamp) -> Result<SystemTime, TimestampError> {
+        SystemTime::try_from(&time)
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(time: chrono::DateTime<chrono::Utc>) -> Result<Timestamp, TimestampError> {
+        let mut result = Timestamp::new();
+        *result.seconds_mut() = time.timestamp();
+        // leap seconds are represented by chrono with nanos past the end of the second
+        *result.nanos_mut() = time.timestamp_subsec_nanos() as i32;
+        result.normalize();
+        if result.is_valid() {
+            Ok(result)
+        } else {
+            Err(TimestampError::OutOfRange)
+        }
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<&Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampError;
+
+    fn try_from(time: &Timestamp) -> Result<chrono::DateTime<chrono::Utc>, TimestampError> {
+        use chrono::TimeZone;
+
+        if !time.is_valid() {
+            return Err(TimestampError::OutOfRange);
+        }
+        chrono::Utc.timestamp_opt(*time.seconds(), *time.nanos() as u32).single().ok_or(TimestampError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "chrono-time")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampError;
+
+    fn try_from(time: Timestamp) -> Result<chrono::DateTime<chrono::Utc>, TimestampError> {
+        chrono::DateTime::try_from(&time)
+    }
+}
+
+pub(crate) fn is_valid(seconds: i64, nanos: i32) -> bool {
+    (MIN_SECONDS..=MAX_SECONDS).contains(&seconds) && (0..=MAX_NANOS).contains(&nanos)
+}
+
+/// Converts a count of days since the unix epoch to a (year, month, day) civil date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = (days - era * 146_097) as u64;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era as i64 + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Converts a count of days since the unix epoch from a (year, month, day) civil date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = (year - era * 400) as u64;
+    let month_index = if month > 2 { month - 3 } else { month + 9 } as u64;
+    let day_of_year = (153 * month_index + 2) / 5 + day as u64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era as i64 - 719_468
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Writes the fractional seconds of a timestamp or duration using 0, 3, 6, or 9 digits
+pub(crate) fn write_nanos(output: &mut dyn fmt::Write, nanos: u32) -> fmt::Result {
+    if nanos == 0 {
+        Ok(())
+    } else if nanos % 1_000_000 == 0 {
+        write!(output, ".{:03}", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        write!(output, ".{:06}", nanos / 1_000)
+    } else {
+        write!(output, ".{:09}", nanos)
+    }
+}
+
+/// Writes a valid timestamp's seconds and nanos as an RFC 3339 date in UTC
+pub(crate) fn write_rfc3339(output: &mut dyn fmt::Write, seconds: i64, nanos: i32) -> fmt::Result {
+    let (days, time) =
+        match (seconds / 86_400, seconds % 86_400) {
+            (days, time) if time < 0 => (days - 1, time + 86_400),
+            result => result,
+        };
+    let (year, month, day) = civil_from_days(days);
+    write!(
+        output,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year, month, day, time / 3600, time / 60 % 60, time % 60)?;
+    write_nanos(output, nanos as u32)?;
+    output.write_char('Z')
+}
+
+/// Parses a fixed number of ascii digits from the start of the text, returning the value and the remaining text
+fn take_digits(text: &str, count: usize) -> Option<(u32, &str)> {
+    if text.len() < count || !text.as_bytes()[..count].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some((text[..count].parse().ok()?, &text[count..]))
+}
+
+pub(crate) fn take_char<'t>(text: &'t str, expected: &[char]) -> Option<&'t str> {
+    match text.chars().next() {
+        Some(c) if expected.contains(&c) => Some(&text[c.len_utf8()..]),
+        _ => None,
+    }
+}
+
+/// Parses up to 9 digits of fractional seconds following a '.', returning the nanos and the remaining text
+pub(crate) fn take_nanos(text: &str) -> Option<(i32, &str)> {
+    match take_char(text, &['.']) {
+        Some(text) => {
+            let len = text.bytes().take_while(u8::is_ascii_digit).count();
+            if len == 0 || len > 9 {
+                return None;
+            }
+            let nanos = text[..len].parse::<i32>().ok()? * 10i32.pow(9 - len as u32);
+            Some((nanos, &text[len..]))
+        }
+        None => Some((0, text)),
+    }
+}
+
+/// Parses an RFC 3339 date into the seconds and nanos since the unix epoch
+pub(crate) fn parse_rfc3339(text: &str) -> Option<(i64, i32)> {
+    let (year, text) = take_digits(text, 4)?;
+    let text = take_char(text, &['-'])?;
+    let (month, text) = take_digits(text, 2)?;
+    let text = take_char(text, &['-'])?;
+    let (day, text) = take_digits(text, 2)?;
+    let text = take_char(text, &['T', 't'])?;
+    let (hour, text) = take_digits(text, 2)?;
+    let text = take_char(text, &[':'])?;
+    let (minute, text) = take_digits(text, 2)?;
+    let text = take_char(text, &[':'])?;
+    let (second, text) = take_digits(text, 2)?;
+    let (nanos, text) = take_nanos(text)?;
+    let offset =
+        if let Some(text) = take_char(text, &['Z', 'z']) {
+            if !text.is_empty() {
+                return None;
+            }
+            0
+        } else {
+            let sign = if take_char(text, &['-']).is_some() { -1 } else { 1 };
+            let text = take_char(text, &['+', '-'])?;
+            let (offset_hour, text) = take_digits(text, 2)?;
+            let text = take_char(text, &[':'])?;
+            let (offset_minute, text) = take_digits(text, 2)?;
+            if !text.is_empty() || offset_hour > 23 || offset_minute > 59 {
+                return None;
+            }
+            sign * i64::from(offset_hour * 3600 + offset_minute * 60)
+        };
+
+    let days_in_month =
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year(i64::from(year)) => 29,
+            2 => 28,
+            _ => return None,
+        };
+    if day == 0 || day > days_in_month || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let seconds =
+        days_from_civil(i64::from(year), month, day) * 86_400
+        + i64::from(hour * 3600 + minute * 60 + second)
+        - offset;
+    Some((seconds, nanos))
+}
@@ -0,0 +1,102 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::io::{DelimitedMessages, InputError, InputErrorKind};
+use protrust::prelude::*;
+
+use shared::gen::unittest_proto::TestAllTypes;
+
+fn messages() -> Vec<TestAllTypes> {
+    let mut small = TestAllTypes::new();
+    small.set_optional_int32(1);
+    vec![
+        TestAllTypes::new(),
+        TestAllTypes::new(),
+        TestAllTypes::new(),
+        small,
+        shared::util::make_test_all_types_proto2(),
+    ]
+}
+
+#[test]
+fn write_delimited_prefixes_length() -> shared::Result {
+    let mut value = TestAllTypes::new();
+    value.set_optional_int32(150);
+
+    let mut output = Vec::new();
+    value.write_delimited(&mut output)?;
+    assert_eq!(output, [3, 8, 150, 1]);
+    Ok(())
+}
+
+#[test]
+fn roundtrip_delimited_stream() -> shared::Result {
+    let messages = messages();
+    let mut output = Vec::new();
+    for message in &messages {
+        message.write_delimited(&mut output)?;
+    }
+
+    let mut input = output.as_slice();
+    for message in &messages {
+        assert_eq!(&TestAllTypes::read_delimited(&mut input)?, message);
+    }
+    assert!(input.is_empty());
+
+    let mut input = output.as_slice();
+    let read = DelimitedMessages::<TestAllTypes>::new(&mut input).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(read, messages);
+    Ok(())
+}
+
+#[test]
+fn delimited_stream_ends_at_clean_eof() {
+    let mut input: &[u8] = &[];
+    assert!(DelimitedMessages::<TestAllTypes>::new(&mut input).next().is_none());
+}
+
+#[test]
+fn delimited_stream_reports_truncated_record() -> shared::Result {
+    let mut output = Vec::new();
+    for message in messages() {
+        message.write_delimited(&mut output)?;
+    }
+
+    let truncated_length = [&output[..], &[0x80]].concat();
+    let truncated_message = &output[..output.len() - 1];
+    for &(data, complete) in &[(&truncated_length[..], 5), (truncated_message, 4)] {
+        let mut input = data;
+        let mut stream = DelimitedMessages::<TestAllTypes>::new(&mut input);
+        for _ in 0..complete {
+            stream.next().unwrap()?;
+        }
+        match stream.next().map(|r| r.map_err(InputError::into_kind)) {
+            Some(Err(InputErrorKind::IoError(ref e))) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            result => panic!("expected a truncated record error, got {:?}", result),
+        }
+        assert!(stream.next().is_none());
+    }
+    Ok(())
+}
+
+#[test]
+fn delimited_stream_limits_each_message() -> shared::Result {
+    let mut output = Vec::new();
+    let mut value = TestAllTypes::new();
+    value.set_optional_string("abcdefgh".to_string());
+    for _ in 0..10 {
+        value.write_delimited(&mut output)?;
+    }
+    let size = value.calculate_size() as usize + 1;
+
+    let mut input = output.as_slice();
+    let read = DelimitedMessages::<TestAllTypes>::new(&mut input).with_size_limit(size).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(read.len(), 10);
+
+    let mut input = output.as_slice();
+    match DelimitedMessages::<TestAllTypes>::new(&mut input).with_size_limit(size - 1).next().map(|r| r.map_err(InputError::into_kind)) {
+        Some(Err(InputErrorKind::SizeLimitExceeded)) => {}
+        result => panic!("expected the size limit to be exceeded, got {:?}", result),
+    }
+    Ok(())
+}
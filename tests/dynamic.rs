@@ -0,0 +1,166 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::prelude::*;
+use protrust::reflect::DescriptorPool;
+
+use shared::gen::unittest_proto::{
+    ForeignEnum,
+    TestAllTypes as TestAllTypes2,
+    TestHugeFieldNumbers,
+    TestPackedTypes,
+};
+use shared::gen::unittest_proto3_proto::TestAllTypes as TestAllTypes3;
+
+fn dynamic_pool() -> &'static DescriptorPool<'static> {
+    shared::util::dynamic_pool(&[
+        shared::gen::unittest_import_public_proto::file(),
+        shared::gen::unittest_import_proto::file(),
+        shared::gen::unittest_proto::file(),
+        shared::gen::unittest_proto3_proto::file(),
+    ])
+}
+
+/// Reads the message into a dynamic instance of the specified type and writes it back out
+fn dynamic_roundtrip(name: &str, message: &dyn CodedMessage) -> Result<(), Box<dyn std::error::Error>> {
+    let expected = message.write_to_vec()?;
+
+    let descriptor = dynamic_pool().find_message_by_name(name).expect("missing message");
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from_reader(&mut expected.as_slice())?;
+
+    #[cfg(not(checked_size))]
+    assert_eq!(instance.calculate_size(), expected.len() as i32);
+    #[cfg(checked_size)]
+    assert_eq!(instance.calculate_size(), Some(expected.len() as i32));
+
+    assert_eq!(instance.write_to_vec()?, expected);
+    Ok(())
+}
+
+#[test]
+fn dynamic_proto2_roundtrip() -> shared::Result {
+    let mut value = shared::util::make_test_all_types_proto2();
+    value.repeated_int32_mut().extend(&[1, -2, 3]);
+    value.repeated_string_mut().push("repeated".to_string());
+    value.repeatedgroup_mut().push({
+        let mut group = shared::gen::unittest_proto::test_all_types::RepeatedGroup::new();
+        group.set_a(30);
+        group
+    });
+    value.optional_nested_message_mut().set_bb(40);
+    value.set_optional_foreign_enum(Defined(ForeignEnum::ForeignBar));
+    value.set_default_string("not default".to_string());
+    value.set_oneof_string("oneof".to_string());
+
+    dynamic_roundtrip("protobuf_unittest.TestAllTypes", &value)?;
+    dynamic_roundtrip("protobuf_unittest.TestAllTypes", &TestAllTypes2::new())
+}
+
+#[test]
+fn dynamic_packed_roundtrip() -> shared::Result {
+    let mut value = TestPackedTypes::new();
+    value.packed_int32_mut().extend(&[1, -1, 300]);
+    value.packed_sint64_mut().extend(&[-5, 5]);
+    value.packed_fixed32_mut().extend(&[7, 8]);
+    value.packed_double_mut().extend(&[1.5, -2.5]);
+    value.packed_bool_mut().extend(&[true, false]);
+    value.packed_enum_mut().push(Defined(ForeignEnum::ForeignBaz));
+
+    dynamic_roundtrip("protobuf_unittest.TestPackedTypes", &value)
+}
+
+#[test]
+fn dynamic_huge_field_numbers_roundtrip() -> shared::Result {
+    let mut value = TestHugeFieldNumbers::new();
+    value.set_optional_int32(1);
+    value.repeated_int32_mut().extend(&[2, 3]);
+    value.packed_int32_mut().extend(&[4, 5]);
+    value.set_optional_string("huge".to_string());
+    value.optionalgroup_mut().set_group_a(6);
+    value.string_string_map_mut().insert("key".to_string(), "value".to_string());
+    value.set_oneof_uint32(7);
+
+    dynamic_roundtrip("protobuf_unittest.TestHugeFieldNumbers", &value)
+}
+
+#[test]
+fn dynamic_proto3_roundtrip() -> shared::Result {
+    let mut value = TestAllTypes3::new();
+    *value.optional_int32_mut() = 1;
+    *value.optional_string_mut() = "proto3".to_string();
+    value.repeated_int32_mut().extend(&[1, 2, 3]);
+    value.repeated_string_mut().push("unpacked".to_string());
+    *value.optional_nested_message_mut().bb_mut() = 2;
+    value.set_oneof_uint32(0);
+
+    dynamic_roundtrip("proto3_unittest.TestAllTypes", &value)?;
+    dynamic_roundtrip("proto3_unittest.TestAllTypes", &TestAllTypes3::new())
+}
+
+#[test]
+fn dynamic_discards_unknown_fields() -> shared::Result {
+    use protrust::io::CodedInput;
+
+    let mut value = TestAllTypes2::new();
+    value.set_optional_int32(1);
+    value.optionalgroup_mut().set_a(2);
+    value.repeated_string_mut().push("unknown".to_string());
+    let data = value.write_to_vec()?;
+
+    let descriptor = dynamic_pool().find_message_by_name("protobuf_unittest.TestEmptyMessage").expect("missing message");
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from(&mut CodedInput::from_slice(&data).with_discard_unknown_fields(true))?;
+    assert_eq!(instance.write_to_vec()?, []);
+
+    instance.merge_from(&mut CodedInput::from_slice(&data))?;
+    assert_eq!(instance.write_to_vec()?, data);
+    Ok(())
+}
+
+#[test]
+fn dynamic_deterministic_output_sorts_map_entries() -> shared::Result {
+    use protrust::io::CodedOutput;
+
+    let mut value = TestHugeFieldNumbers::new();
+    for i in 0..100 {
+        value.string_string_map_mut().insert(i.to_string(), i.to_string());
+    }
+
+    let mut expected = Vec::new();
+    value.write_to(&mut CodedOutput::new(&mut expected).with_deterministic(true))?;
+
+    let descriptor = dynamic_pool().find_message_by_name("protobuf_unittest.TestHugeFieldNumbers").expect("missing message");
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from_reader(&mut value.write_to_vec()?.as_slice())?;
+
+    let mut output = Vec::new();
+    instance.write_to(&mut CodedOutput::new(&mut output).with_deterministic(true))?;
+    assert_eq!(output, expected);
+    Ok(())
+}
+
+#[test]
+fn new_enum_value_matches_field_types() {
+    use shared::gen::unittest_proto::test_all_types::NestedEnum;
+
+    let generated = shared::gen::unittest_proto::file();
+    let repeated = generated.find_message_by_name("TestAllTypes").unwrap().find_field_by_name("repeated_nested_enum").unwrap();
+    let value = repeated.new_enum_value(2).expect("missing enum value");
+    assert_eq!(value.downcast_ref::<EnumValue<NestedEnum>>(), Some(&Defined(NestedEnum::Bar)));
+    assert!(repeated.new_enum_value(100).is_none());
+
+    let extension = generated.find_extension_by_name("repeated_nested_enum_extension").unwrap();
+    let value = extension.new_enum_value(3).expect("missing enum value");
+    assert_eq!(value.downcast_ref::<EnumValue<NestedEnum>>(), Some(&Defined(NestedEnum::Baz)));
+
+    let int32 = generated.find_message_by_name("TestAllTypes").unwrap().find_field_by_name("repeated_int32").unwrap();
+    assert!(int32.new_enum_value(1).is_none());
+
+    let dynamic = dynamic_pool().find_message_by_name("proto3_unittest.TestAllTypes").expect("missing message");
+    let value = dynamic.find_field_by_name("repeated_nested_enum").unwrap().new_enum_value(100).expect("missing enum value");
+    assert_eq!(value.as_enum().map(|e| e.get_i32()), Some(100));
+}
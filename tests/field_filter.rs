@@ -0,0 +1,116 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::io::{CodedInput, FieldFilter};
+use protrust::prelude::*;
+use protrust::wkt::field_mask::FieldMask;
+
+use shared::gen::unittest_proto::{TestAllTypes, TestHugeFieldNumbers};
+
+fn make_record() -> TestAllTypes {
+    let mut value = shared::util::make_test_all_types_proto2();
+    value.optional_nested_message_mut().set_bb(1);
+    value.optional_foreign_message_mut().set_c(2);
+    value.optional_foreign_message_mut().set_d(3);
+    value.repeated_string_mut().push("skipped".to_string());
+    value
+}
+
+fn make_filtered_record() -> TestAllTypes {
+    let mut value = TestAllTypes::new();
+    value.optional_string_mut().push_str("Hello world!");
+    value.optional_foreign_message_mut().set_c(2);
+    value.optionalgroup_mut().set_a(25);
+    value
+}
+
+const PATHS: &[&str] = &["optional_string", "optional_foreign_message.c", "optionalgroup"];
+
+#[test]
+fn filter_generated_message() -> shared::Result {
+    let data = make_record().write_to_vec()?;
+    let filter = FieldFilter::new(TestAllTypes::descriptor(), PATHS)?;
+
+    let value = TestAllTypes::read_new_from_input(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+    assert_eq!(value, make_filtered_record());
+    assert!(value.unknown_fields().is_empty());
+
+    let mut reader = data.as_slice();
+    let value = TestAllTypes::read_new_from_input(&mut CodedInput::new(&mut reader).with_field_filter(&filter))?;
+    assert_eq!(value, make_filtered_record());
+    Ok(())
+}
+
+#[test]
+fn filter_from_field_mask() -> shared::Result {
+    let mut mask = FieldMask::new();
+    mask.paths_mut().extend(PATHS.iter().map(|p| p.to_string()));
+    mask.paths_mut().push("optional_foreign_message".to_string());
+    let filter = FieldFilter::from_field_mask(TestAllTypes::descriptor(), &mask)?;
+
+    let data = make_record().write_to_vec()?;
+    let value = TestAllTypes::read_new_from_input(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+
+    let mut expected = make_filtered_record();
+    expected.optional_foreign_message_mut().set_d(3);
+    assert_eq!(value, expected);
+    Ok(())
+}
+
+#[test]
+fn filter_dynamic_message() -> shared::Result {
+    let pool = shared::util::dynamic_pool(&[
+        shared::gen::unittest_import_public_proto::file(),
+        shared::gen::unittest_import_proto::file(),
+        shared::gen::unittest_proto::file(),
+    ]);
+    let descriptor = pool.find_message_by_name("protobuf_unittest.TestAllTypes").expect("missing message");
+    let filter = FieldFilter::new(descriptor, PATHS)?;
+
+    let data = make_record().write_to_vec()?;
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+    assert_eq!(instance.write_to_vec()?, make_filtered_record().write_to_vec()?);
+    Ok(())
+}
+
+#[test]
+fn filter_map_fields() -> shared::Result {
+    let mut record = TestHugeFieldNumbers::new();
+    record.set_optional_int32(1);
+    record.string_string_map_mut().insert("a".to_string(), "1".to_string());
+    record.string_string_map_mut().insert("b".to_string(), "2".to_string());
+    let data = record.write_to_vec()?;
+
+    let mut expected = TestHugeFieldNumbers::new();
+    *expected.string_string_map_mut() = record.string_string_map().clone();
+
+    let filter = FieldFilter::new(TestHugeFieldNumbers::descriptor(), &["string_string_map"])?;
+    let value = TestHugeFieldNumbers::read_new_from_input(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+    assert_eq!(value, expected);
+
+    let pool = shared::util::dynamic_pool(&[
+        shared::gen::unittest_import_public_proto::file(),
+        shared::gen::unittest_import_proto::file(),
+        shared::gen::unittest_proto::file(),
+    ]);
+    let descriptor = pool.find_message_by_name("protobuf_unittest.TestHugeFieldNumbers").expect("missing message");
+    let filter = FieldFilter::new(descriptor, &["string_string_map"])?;
+
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from(&mut CodedInput::from_slice(&data).with_field_filter(&filter))?;
+    assert_eq!(TestHugeFieldNumbers::read_new_from_slice(&instance.write_to_vec()?)?, expected);
+    Ok(())
+}
+
+#[test]
+fn filter_rejects_invalid_paths() {
+    for path in &["", "missing", "optional_int32.a", "optional_nested_message.missing", "optional_nested_message..bb"] {
+        match FieldFilter::new(TestAllTypes::descriptor(), [path]) {
+            Err(e) => assert_eq!(e.path(), *path),
+            Ok(_) => panic!("path '{}' was accepted", path),
+        }
+    }
+}
@@ -0,0 +1,375 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::json::{self, JsonOptions, ParseErrorKind, PrintError};
+use protrust::prelude::*;
+use protrust::wkt::{
+    any::Any,
+    duration::Duration,
+    field_mask::FieldMask,
+    r#struct::{ListValue, NullValue, Struct, Value},
+    timestamp::Timestamp,
+    wrappers::{BytesValue, Int64Value, StringValue},
+};
+
+use shared::gen::unittest_proto::TestHugeFieldNumbers;
+use shared::gen::unittest_proto3_proto::{test_all_types::NestedEnum, TestAllTypes};
+
+#[test]
+fn print_proto3_fields() -> shared::Result {
+    assert_eq!(
+        json::to_string(&shared::util::make_test_all_types_proto3())?,
+        concat!(
+            r#"{"optionalInt32":1,"optionalInt64":"-2","optionalUint64":"3","optionalFloat":0.5,"optionalDouble":"-Infinity","#,
+            r#""optionalBool":true,"optionalString":"quote\" slash\\ newline\n tab\t ünïcode","optionalBytes":"AP9ieXRlcw==","#,
+            r#""optionalNestedMessage":{"bb":4},"optionalNestedEnum":"BAR","repeatedInt32":[1,2],"repeatedNestedEnum":["NEG",10],"#,
+            r#""oneofUint32":0}"#));
+
+    assert_eq!(json::to_string(&TestAllTypes::new())?, "{}");
+    Ok(())
+}
+
+#[test]
+fn print_proto2_fields() -> shared::Result {
+    assert_eq!(
+        json::to_string(&shared::util::make_test_all_types_proto2())?,
+        concat!(
+            r#"{"optionalInt32":1,"optionalInt64":"2","optionalUint32":3,"optionalUint64":"4","optionalSint32":5,"optionalSint64":"6","#,
+            r#""optionalFixed32":7,"optionalFixed64":"8","optionalSfixed32":9,"optionalSfixed64":"10","optionalFloat":11.11,"optionalDouble":12.12,"#,
+            r#""optionalBool":true,"optionalString":"Hello world!","optionalBytes":"SGVsbG8gd29ybGQh","optionalgroup":{"a":25}}"#));
+
+    let mut huge = TestHugeFieldNumbers::new();
+    huge.string_string_map_mut().insert("key".to_string(), "value".to_string());
+    assert_eq!(json::to_string(&huge)?, r#"{"stringStringMap":{"key":"value"}}"#);
+    Ok(())
+}
+
+#[test]
+fn print_dynamic_message() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+
+    let mut instance = shared::util::new_dynamic_test_all_types_proto3();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from_reader(&mut value.write_to_vec()?.as_slice())?;
+
+    assert_eq!(json::to_string(instance)?, json::to_string(&value)?);
+    Ok(())
+}
+
+#[test]
+fn print_timestamp() -> shared::Result {
+    let mut time = Timestamp::new();
+    assert_eq!(json::to_string(&time)?, r#""1970-01-01T00:00:00Z""#);
+
+    *time.seconds_mut() = -1;
+    *time.nanos_mut() = 1_000;
+    assert_eq!(json::to_string(&time)?, r#""1969-12-31T23:59:59.000001Z""#);
+
+    *time.seconds_mut() = 253_402_300_799;
+    *time.nanos_mut() = 999_999_999;
+    assert_eq!(json::to_string(&time)?, r#""9999-12-31T23:59:59.999999999Z""#);
+
+    *time.seconds_mut() = 253_402_300_800;
+    match json::to_string(&time) {
+        Err(PrintError::InvalidTimestamp) => Ok(()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn print_duration() -> shared::Result {
+    let mut duration = Duration::new();
+    *duration.seconds_mut() = 1;
+    *duration.nanos_mut() = 500_000_000;
+    assert_eq!(json::to_string(&duration)?, r#""1.500s""#);
+
+    *duration.seconds_mut() = 0;
+    *duration.nanos_mut() = -10;
+    assert_eq!(json::to_string(&duration)?, r#""-0.000000010s""#);
+
+    *duration.seconds_mut() = 1;
+    match json::to_string(&duration) {
+        Err(PrintError::InvalidDuration) => Ok(()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn print_field_mask() -> shared::Result {
+    let mut mask = FieldMask::new();
+    mask.paths_mut().push("foo_bar.baz".to_string());
+    mask.paths_mut().push("qux".to_string());
+    assert_eq!(json::to_string(&mask)?, r#""fooBar.baz,qux""#);
+
+    mask.paths_mut().push("fooBar".to_string());
+    match json::to_string(&mask) {
+        Err(PrintError::InvalidFieldMask(path)) => assert_eq!(path, "fooBar"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn print_struct() -> shared::Result {
+    let mut list = ListValue::new();
+    list.values_mut().push({
+        let mut value = Value::new();
+        value.set_null_value(Defined(NullValue::NullValue));
+        value
+    });
+    list.values_mut().push({
+        let mut value = Value::new();
+        value.set_bool_value(true);
+        value
+    });
+
+    let mut value = Value::new();
+    value.set_struct_value({
+        let mut inner = Struct::new();
+        inner.fields_mut().insert("list".to_string(), {
+            let mut value = Value::new();
+            value.set_list_value(list);
+            value
+        });
+        inner
+    });
+
+    let mut root = Struct::new();
+    root.fields_mut().insert("nested".to_string(), value);
+    assert_eq!(json::to_string(&root)?, r#"{"nested":{"list":[null,true]}}"#);
+
+    let mut number = Value::new();
+    number.set_number_value(1.5);
+    assert_eq!(json::to_string(&number)?, "1.5");
+
+    number.set_number_value(std::f64::NAN);
+    match json::to_string(&number) {
+        Err(PrintError::InvalidValue) => { },
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match json::to_string(&Value::new()) {
+        Err(PrintError::InvalidValue) => Ok(()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn print_wrappers() -> shared::Result {
+    let mut int = Int64Value::new();
+    *int.value_mut() = 5;
+    assert_eq!(json::to_string(&int)?, r#""5""#);
+    assert_eq!(json::to_string(&StringValue::new())?, r#""""#);
+
+    let mut bytes = BytesValue::new();
+    *bytes.value_mut() = vec![0xff];
+    assert_eq!(json::to_string(&bytes)?, r#""/w==""#);
+    Ok(())
+}
+
+#[test]
+fn print_any() -> shared::Result {
+    assert_eq!(json::to_string(&Any::new())?, "{}");
+
+    let mut time = Timestamp::new();
+    *time.seconds_mut() = 1;
+    assert_eq!(
+        json::to_string(&Any::pack(&time)?)?,
+        r#"{"@type":"type.googleapis.com/google.protobuf.Timestamp","value":"1970-01-01T00:00:01Z"}"#);
+
+    let mut any = Any::new();
+    *any.type_url_mut() = "type.googleapis.com/google.protobuf.Type".to_string();
+    *any.value_mut() = {
+        let mut value = protrust::wkt::r#type::Type::new();
+        *value.name_mut() = "name".to_string();
+        value.write_to_vec()?
+    };
+    assert_eq!(json::to_string(&any)?, r#"{"@type":"type.googleapis.com/google.protobuf.Type","name":"name"}"#);
+
+    *any.type_url_mut() = "type.googleapis.com/foo.Bar".to_string();
+    match json::to_string(&any) {
+        Err(PrintError::UnresolvedAny(url)) => assert_eq!(url, "type.googleapis.com/foo.Bar"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+
+#[test]
+fn parse_roundtrip() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+    assert_eq!(json::from_str::<TestAllTypes>(&json::to_string(&value)?)?, value);
+
+    let value = shared::util::make_test_all_types_proto2();
+    let parsed: shared::gen::unittest_proto::TestAllTypes = json::from_str(&json::to_string(&value)?)?;
+    assert_eq!(parsed, value);
+
+    let mut huge = TestHugeFieldNumbers::new();
+    huge.string_string_map_mut().insert("key".to_string(), "value".to_string());
+    assert_eq!(json::from_str::<TestHugeFieldNumbers>(&json::to_string(&huge)?)?, huge);
+    Ok(())
+}
+
+#[test]
+fn parse_alternate_forms() -> shared::Result {
+    let parsed: TestAllTypes = json::from_str(r#"
+        {
+            "optional_int32": "1e2",
+            "optionalInt64": 20,
+            "optional_uint64": "18446744073709551615",
+            "optionalFloat": "Infinity",
+            "optionalDouble": -1.5e-3,
+            "optionalBytes": "_w",
+            "optionalNestedEnum": 2,
+            "repeated_nested_enum": ["NEG", 10],
+            "optionalString": "\u00e9\ud83d\ude00",
+            "optionalNestedMessage": null
+        }"#)?;
+
+    let mut expected = TestAllTypes::new();
+    *expected.optional_int32_mut() = 100;
+    *expected.optional_int64_mut() = 20;
+    *expected.optional_uint64_mut() = std::u64::MAX;
+    *expected.optional_float_mut() = std::f32::INFINITY;
+    *expected.optional_double_mut() = -1.5e-3;
+    *expected.optional_bytes_mut() = vec![0xff];
+    *expected.optional_nested_enum_mut() = Defined(NestedEnum::Bar);
+    expected.repeated_nested_enum_mut().push(Defined(NestedEnum::Neg));
+    expected.repeated_nested_enum_mut().push(EnumValue::Undefined(10));
+    *expected.optional_string_mut() = "\u{e9}\u{1f600}".to_string();
+
+    assert_eq!(parsed, expected);
+    Ok(())
+}
+
+#[test]
+fn parse_dynamic_message() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+
+    let mut instance = shared::util::new_dynamic_test_all_types_proto3();
+    let instance = instance.as_message_mut().unwrap();
+    json::merge_from_str(instance, &json::to_string(&value)?)?;
+
+    assert_eq!(instance.write_to_vec()?, value.write_to_vec()?);
+    Ok(())
+}
+
+#[test]
+fn parse_well_known_types() -> shared::Result {
+    let time: Timestamp = json::from_str(r#""1969-12-31T19:59:59.000001-04:00""#)?;
+    assert_eq!((*time.seconds(), *time.nanos()), (-1, 1_000));
+
+    let duration: Duration = json::from_str(r#""-1.5s""#)?;
+    assert_eq!((*duration.seconds(), *duration.nanos()), (-1, -500_000_000));
+
+    let mask: FieldMask = json::from_str(r#""fooBar.baz,qux""#)?;
+    assert_eq!(mask.paths().as_slice(), &["foo_bar.baz".to_string(), "qux".to_string()]);
+
+    let json = r#"{"list":[null,true,1.5,"a",{}]}"#;
+    assert_eq!(json::to_string(&json::from_str::<Struct>(json)?)?, json);
+
+    let int: Int64Value = json::from_str(r#""5""#)?;
+    assert_eq!(*int.value(), 5);
+
+    let any: Any = json::from_str(r#"{"value":"1970-01-01T00:00:01Z","@type":"type.googleapis.com/google.protobuf.Timestamp"}"#)?;
+    let mut time = Timestamp::new();
+    *time.seconds_mut() = 1;
+    assert_eq!(any, Any::pack(&time)?);
+    Ok(())
+}
+
+#[test]
+fn parse_errors() {
+    fn error(json: &str) -> (ParseErrorKind, String) {
+        let error = json::from_str::<TestAllTypes>(json).unwrap_err();
+        (error.kind().clone(), error.path().to_string())
+    }
+
+    assert_eq!(error(r#"{"optionalInt32":1"#), (ParseErrorKind::UnexpectedEof, "$".to_string()));
+    assert_eq!(error(r#"{"repeatedInt32":[1,]}"#), (ParseErrorKind::UnexpectedChar(']'), "$.repeatedInt32[1]".to_string()));
+    assert_eq!(error(r#"{"repeatedInt32":[1,2.5]}"#), (ParseErrorKind::InvalidNumber, "$.repeatedInt32[1]".to_string()));
+    assert_eq!(error(r#"{"optionalInt32":2147483648}"#), (ParseErrorKind::InvalidNumber, "$.optionalInt32".to_string()));
+    assert_eq!(error(r#"{"optionalInt64":1e39}"#), (ParseErrorKind::InvalidNumber, "$.optionalInt64".to_string()));
+    assert_eq!(error(r#"{"optionalUint64":-1e300}"#), (ParseErrorKind::InvalidNumber, "$.optionalUint64".to_string()));
+    assert_eq!(error(r#"{"optionalNestedMessage":{"bb":"a"}}"#), (ParseErrorKind::InvalidNumber, "$.optionalNestedMessage.bb".to_string()));
+    assert_eq!(error(r#"{"unknown":1}"#), (ParseErrorKind::UnknownField("unknown".to_string()), "$.unknown".to_string()));
+    assert_eq!(error(r#"{"optionalInt32":1,"optional_int32":1}"#), (ParseErrorKind::DuplicateField("optional_int32".to_string()), "$.optional_int32".to_string()));
+    assert_eq!(error(r#"{"oneofUint32":1,"oneofString":""}"#), (ParseErrorKind::DuplicateField("oneofString".to_string()), "$.oneofString".to_string()));
+    assert_eq!(error(r#"{"optionalNestedEnum":"BAZZ"}"#), (ParseErrorKind::UnknownEnumValue("BAZZ".to_string()), "$.optionalNestedEnum".to_string()));
+    assert_eq!(error(r#"{"optionalBool":"true"}"#), (ParseErrorKind::UnexpectedValue, "$.optionalBool".to_string()));
+
+    let error = json::from_str::<Timestamp>(r#""2019-02-29T00:00:00Z""#).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::InvalidTimestamp);
+    let error = json::from_str::<Any>(r#"{"@type":"type.googleapis.com/foo.Bar"}"#).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::UnresolvedAny("type.googleapis.com/foo.Bar".to_string()));
+}
+
+#[test]
+fn parse_recursion_limit() -> shared::Result {
+    use shared::gen::unittest_proto::TestRecursiveMessage;
+
+    fn nested(open: &str, close: &str, depth: usize) -> String {
+        format!("{}{}", open.repeat(depth), close.repeat(depth))
+    }
+
+    let message = json::from_str::<TestRecursiveMessage>(&format!("{}{{}}{}", r#"{"a":"#.repeat(99), "}".repeat(99)))?;
+    assert!(message.a().is_some());
+
+    let error = json::from_str::<TestRecursiveMessage>(&format!("{}{{}}{}", r#"{"a":"#.repeat(100), "}".repeat(100))).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::RecursionLimitExceeded);
+
+    let error = json::from_str::<Value>(&nested("[", "]", 101)).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::RecursionLimitExceeded);
+
+    // each array is both a Value and a ListValue message
+    assert!(json::from_str::<Value>(&nested("[", "]", 50)).is_ok());
+    let error = json::from_str::<Value>(&nested("[", "]", 51)).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::RecursionLimitExceeded);
+    Ok(())
+}
+
+#[test]
+fn print_with_options() -> shared::Result {
+    let options = JsonOptions {
+        always_print_primitive_fields: true,
+        preserve_proto_field_names: true,
+        print_enums_as_ints: true,
+        ..Default::default()
+    };
+
+    let mut value = TestAllTypes::new();
+    *value.optional_nested_enum_mut() = Defined(NestedEnum::Bar);
+    value.repeated_nested_enum_mut().push(Defined(NestedEnum::Neg));
+    let printed = json::to_string_with_options(&value, &options)?;
+    assert!(printed.starts_with(r#"{"optional_int32":0,"optional_int64":"0","#));
+    assert!(printed.contains(r#""optional_string":"","#));
+    assert!(printed.contains(r#""optional_nested_enum":2,"#));
+    assert!(printed.contains(r#""repeated_int32":[],"#));
+    assert!(printed.contains(r#""repeated_nested_enum":[-1],"#));
+    assert!(!printed.contains("optional_nested_message"));
+    assert!(!printed.contains("oneof_uint32"));
+    assert_eq!(json::from_str::<TestAllTypes>(&printed)?, value);
+
+    let mut huge = TestHugeFieldNumbers::new();
+    assert!(json::to_string_with_options(&huge, &options)?.contains(r#""string_string_map":{}"#));
+    huge.string_string_map_mut().insert("key".to_string(), "value".to_string());
+    assert_eq!(
+        json::to_string_with_options(&huge, &JsonOptions { preserve_proto_field_names: true, ..Default::default() })?,
+        r#"{"string_string_map":{"key":"value"}}"#);
+    Ok(())
+}
+
+#[test]
+fn parse_with_options() -> shared::Result {
+    let options = JsonOptions { ignore_unknown_fields: true, ..Default::default() };
+    let json = r#"{"unknown":{"a":[1]},"optionalInt32":1,"optionalNestedEnum":"UNKNOWN","repeatedNestedEnum":["FOO","UNKNOWN"]}"#;
+
+    let mut expected = TestAllTypes::new();
+    *expected.optional_int32_mut() = 1;
+    expected.repeated_nested_enum_mut().push(Defined(NestedEnum::Foo));
+    assert_eq!(json::from_str_with_options::<TestAllTypes>(json, &options)?, expected);
+
+    match json::from_str::<TestAllTypes>(json) {
+        Err(ref e) if e.kind() == &ParseErrorKind::UnknownField("unknown".to_string()) => Ok(()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,178 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::io::{CodedInput, InputErrorKind};
+use protrust::prelude::*;
+
+use shared::gen::unittest_proto::{TestAllTypes, TestHugeFieldNumbers, TestOneof};
+
+#[test]
+fn lenient_skips_invalid_strings() -> shared::Result {
+    // optional_int32: 1, optional_string: "\xff", optional_int64: 7, repeated_string: ["a", "\xfe", "b"]
+    let data = [8u8, 1, 114, 1, 255, 16, 7, 226, 2, 1, b'a', 226, 2, 1, 254, 226, 2, 1, b'b'];
+
+    for reader in [false, true].iter() {
+        let mut value = TestAllTypes::new();
+        let mut slice = data.as_ref();
+        let diagnostics =
+            if *reader {
+                CodedInput::new(&mut slice).merge_lenient(&mut value)?
+            } else {
+                CodedInput::from_slice(&data).merge_lenient(&mut value)?
+            };
+
+        let mut expected = TestAllTypes::new();
+        expected.set_optional_int32(1);
+        expected.set_optional_int64(7);
+        expected.repeated_string_mut().push("a".to_string());
+        expected.repeated_string_mut().push("b".to_string());
+        assert_eq!(value, expected);
+
+        assert_eq!(diagnostics.len(), 2);
+        for e in &diagnostics {
+            match e.kind() {
+                InputErrorKind::InvalidString(_) => {}
+                kind => panic!("expected an invalid string, got {:?}", kind),
+            }
+        }
+        assert_eq!(diagnostics[0].offset(), Some(5));
+        assert_eq!(diagnostics[0].field_path_names(TestAllTypes::descriptor()), "optional_string");
+        assert_eq!(diagnostics[1].field_path_names(TestAllTypes::descriptor()), "repeated_string");
+    }
+    Ok(())
+}
+
+#[test]
+fn lenient_skips_invalid_strings_in_groups() -> shared::Result {
+    // foogroup { b: "\xff", a: 7 }
+    let data = [35u8, 50, 1, 255, 40, 7, 36];
+
+    let mut value = TestOneof::new();
+    let diagnostics = CodedInput::from_slice(&data).merge_lenient(&mut value)?;
+
+    let mut expected = TestOneof::new();
+    expected.foogroup_mut().set_a(7);
+    assert_eq!(value, expected);
+    assert!(value.unknown_fields().is_empty());
+
+    assert_eq!(diagnostics.len(), 1);
+    match diagnostics[0].kind() {
+        InputErrorKind::InvalidString(_) => {}
+        kind => panic!("expected an invalid string, got {:?}", kind),
+    }
+    assert_eq!(diagnostics[0].field_path_names(TestOneof::descriptor()), "foogroup.b");
+    Ok(())
+}
+
+#[test]
+fn lenient_drops_map_entries_with_invalid_keys() -> shared::Result {
+    let mut record = TestHugeFieldNumbers::new();
+    record.string_string_map_mut().insert("k".to_string(), "v".to_string());
+    record.set_optional_int32(4);
+    let mut data = record.write_to_vec()?;
+    let key = data.iter().position(|b| *b == b'k').unwrap();
+    data[key] = 255;
+
+    let mut expected = TestHugeFieldNumbers::new();
+    expected.set_optional_int32(4);
+
+    let mut value = TestHugeFieldNumbers::new();
+    let diagnostics = CodedInput::from_slice(&data).merge_lenient(&mut value)?;
+    assert_eq!(value, expected);
+    assert_eq!(diagnostics.len(), 1);
+
+    let pool = shared::util::dynamic_pool(&[
+        shared::gen::unittest_import_public_proto::file(),
+        shared::gen::unittest_import_proto::file(),
+        shared::gen::unittest_proto::file(),
+    ]);
+    let descriptor = pool.find_message_by_name("protobuf_unittest.TestHugeFieldNumbers").expect("missing message");
+
+    let mut instance = descriptor.new_instance().unwrap();
+    let instance = instance.as_message_mut().unwrap();
+    let diagnostics = CodedInput::from_slice(&data).merge_lenient(instance)?;
+    assert_eq!(TestHugeFieldNumbers::read_new_from_slice(&instance.write_to_vec()?)?, expected);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].field_path_names(descriptor), "string_string_map.key");
+    Ok(())
+}
+
+#[test]
+fn lenient_skips_the_rest_of_invalid_nested_messages() -> shared::Result {
+    // optional_nested_message { bb: 1, <tag with field number 0> }, optional_int32: 4
+    let data = [146u8, 1, 3, 8, 1, 0, 8, 4];
+
+    let mut value = TestAllTypes::new();
+    let diagnostics = CodedInput::from_slice(&data).merge_lenient(&mut value)?;
+
+    let mut expected = TestAllTypes::new();
+    expected.optional_nested_message_mut().set_bb(1);
+    expected.set_optional_int32(4);
+    assert_eq!(value, expected);
+
+    assert_eq!(diagnostics.len(), 1);
+    match diagnostics[0].kind() {
+        InputErrorKind::InvalidTag(0) => {}
+        kind => panic!("expected an invalid tag, got {:?}", kind),
+    }
+    assert_eq!(diagnostics[0].field_path_names(TestAllTypes::descriptor()), "optional_nested_message");
+
+    assert!(TestAllTypes::read_new_from_slice(&data).is_err());
+    Ok(())
+}
+
+#[test]
+fn lenient_keeps_fields_before_truncated_field() -> shared::Result {
+    // optional_int32: 1, optional_nested_message { bb: 2, <truncated> }
+    let data = [8u8, 1, 146, 1, 5, 8, 2];
+
+    let mut value = TestAllTypes::new();
+    let diagnostics = CodedInput::from_slice(&data).merge_lenient(&mut value)?;
+
+    let mut expected = TestAllTypes::new();
+    expected.set_optional_int32(1);
+    expected.optional_nested_message_mut().set_bb(2);
+    assert_eq!(value, expected);
+
+    assert_eq!(diagnostics.len(), 1);
+    match diagnostics[0].kind() {
+        InputErrorKind::IoError(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {}
+        kind => panic!("expected an unexpected eof, got {:?}", kind),
+    }
+    assert_eq!(diagnostics[0].field_path_names(TestAllTypes::descriptor()), "optional_nested_message");
+    Ok(())
+}
+
+#[test]
+fn lenient_recovers_from_exceeding_recursion_limit() -> shared::Result {
+    // optional_nested_message { bb: 1 }, optional_int32: 4
+    let data = [146u8, 1, 2, 8, 1, 8, 4];
+
+    let mut value = TestAllTypes::new();
+    let diagnostics = CodedInput::from_slice(&data).with_recursion_limit(0).merge_lenient(&mut value)?;
+
+    // the nested message is still set, but none of its fields were read
+    let mut expected = TestAllTypes::new();
+    expected.optional_nested_message_mut();
+    expected.set_optional_int32(4);
+    assert_eq!(value, expected);
+
+    assert_eq!(diagnostics.len(), 1);
+    match diagnostics[0].kind() {
+        InputErrorKind::RecursionLimitExceeded => {}
+        kind => panic!("expected the recursion limit to be exceeded, got {:?}", kind),
+    }
+    Ok(())
+}
+
+#[test]
+fn lenient_does_not_recover_from_exceeding_size_limit() {
+    let data = [8u8, 1, 16, 7];
+
+    let mut value = TestAllTypes::new();
+    let e = CodedInput::from_slice(&data).with_size_limit(3).merge_lenient(&mut value).unwrap_err();
+    match e.kind() {
+        InputErrorKind::SizeLimitExceeded => {}
+        kind => panic!("expected the size limit to be exceeded, got {:?}", kind),
+    }
+}
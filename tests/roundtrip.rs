@@ -11,3 +11,202 @@ fn roundtrip_proto2_test_all_types() -> shared::Result {
     assert_eq!(value, deserialized);
     Ok(())
 }
+
+#[test]
+fn roundtrip_proto2_test_all_types_from_slice() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto2();
+    let serialized = value.write_to_vec()?;
+    let deserialized = shared::gen::unittest_proto::TestAllTypes::read_new_from_slice(&serialized)?;
+    assert_eq!(value, deserialized);
+    Ok(())
+}
+
+#[test]
+fn roundtrip_deeply_nested_messages() -> shared::Result {
+    use shared::gen::unittest_proto::NestedTestAllTypes;
+
+    let mut value = NestedTestAllTypes::new();
+    let mut current = &mut value;
+    for depth in 0..64 {
+        *current.payload_mut().optional_int32_mut() = depth;
+        current.repeated_child_mut().push(NestedTestAllTypes::new());
+        *current.repeated_child_mut()[0].payload_mut().optional_string_mut() = depth.to_string();
+        current = current.child_mut();
+    }
+
+    let serialized = value.write_to_vec()?;
+    assert_eq!(serialized.len(), value.calculate_size() as usize);
+    let deserialized = NestedTestAllTypes::read_new(&mut serialized.as_slice())?;
+    assert_eq!(value, deserialized);
+    Ok(())
+}
+
+#[test]
+fn read_respects_recursion_limit() -> shared::Result {
+    use protrust::io::{CodedInput, InputError, InputErrorKind};
+    use shared::gen::unittest_proto::NestedTestAllTypes;
+
+    fn nested(depth: usize) -> NestedTestAllTypes {
+        let mut value = NestedTestAllTypes::new();
+        let mut current = &mut value;
+        for _ in 0..depth {
+            current = current.child_mut();
+        }
+        value
+    }
+
+    let value = nested(100);
+    let serialized = value.write_to_vec()?;
+    assert_eq!(NestedTestAllTypes::read_new(&mut serialized.as_slice())?, value);
+
+    let serialized = nested(101).write_to_vec()?;
+    match NestedTestAllTypes::read_new(&mut serialized.as_slice()).map_err(InputError::into_kind) {
+        Err(InputErrorKind::RecursionLimitExceeded) => {}
+        result => panic!("expected the recursion limit to be exceeded, got {:?}", result),
+    }
+
+    let mut value = NestedTestAllTypes::new();
+    value.merge_from(&mut CodedInput::from_slice(&serialized).with_recursion_limit(101))?;
+    assert_eq!(value, nested(101));
+    Ok(())
+}
+
+#[test]
+fn roundtrip_unknown_fields_in_order() -> shared::Result {
+    use shared::gen::unittest_proto::TestEmptyMessage;
+
+    let data = [
+        40u8, 1, // 5: 1
+        24, 2, // 3: 2
+        40, 3, // 5: 3
+        34, 1, 4, // 4: "\x04"
+        43, 8, 5, 44, // 5 { 1: 5 }
+        29, 6, 0, 0, 0, // 3: 0x00000006
+    ];
+
+    let value = TestEmptyMessage::read_new(&mut data.as_ref())?;
+    assert_eq!(value.write_to_vec()?, data);
+
+    let mut merged = value.clone();
+    merged.merge(&value);
+    assert_eq!(merged.write_to_vec()?, [&data[..], &data[..]].concat());
+    Ok(())
+}
+
+#[test]
+fn deterministic_output_sorts_map_entries() -> shared::Result {
+    use protrust::io::CodedOutput;
+    use shared::gen::unittest_proto::TestHugeFieldNumbers;
+
+    fn write_deterministic(value: &TestHugeFieldNumbers) -> Result<Vec<u8>, protrust::io::OutputError> {
+        let mut output = Vec::new();
+        value.write_to(&mut CodedOutput::new(&mut output).with_deterministic(true))?;
+        Ok(output)
+    }
+
+    let mut forward = TestHugeFieldNumbers::new();
+    let mut reverse = TestHugeFieldNumbers::new();
+    let mut expected = Vec::new();
+    for i in 0..100 {
+        forward.string_string_map_mut().insert(format!("{:02}", i), i.to_string());
+        reverse.string_string_map_mut().insert(format!("{:02}", 99 - i), (99 - i).to_string());
+
+        let mut entry = TestHugeFieldNumbers::new();
+        entry.string_string_map_mut().insert(format!("{:02}", i), i.to_string());
+        expected.extend(entry.write_to_vec()?);
+    }
+
+    assert_eq!(write_deterministic(&forward)?, expected);
+    assert_eq!(write_deterministic(&reverse)?, expected);
+    Ok(())
+}
+
+#[test]
+fn deterministic_output_sorts_unknown_fields() -> shared::Result {
+    use protrust::io::CodedOutput;
+    use shared::gen::unittest_proto::TestEmptyMessage;
+
+    let data = [
+        40u8, 1, // 5: 1
+        24, 2, // 3: 2
+        40, 3, // 5: 3
+        34, 1, 4, // 4: "\x04"
+        43, 16, 6, 8, 5, 44, // 5 { 2: 6, 1: 5 }
+        29, 6, 0, 0, 0, // 3: 0x00000006
+    ];
+
+    let value = TestEmptyMessage::read_new(&mut data.as_ref())?;
+    let mut output = Vec::new();
+    value.write_to(&mut CodedOutput::new(&mut output).with_deterministic(true))?;
+    assert_eq!(output, [
+        24, 2, // 3: 2
+        29, 6, 0, 0, 0, // 3: 0x00000006
+        34, 1, 4, // 4: "\x04"
+        40, 1, // 5: 1
+        40, 3, // 5: 3
+        43, 8, 5, 16, 6, 44, // 5 { 1: 5, 2: 6 }
+    ]);
+    Ok(())
+}
+
+#[test]
+fn read_errors_have_offset_and_field_path() {
+    use protrust::io::{FieldNumber, InputErrorKind};
+    use shared::gen::unittest_proto::TestAllTypes;
+
+    let numbers = |path: &[u32]| path.iter().map(|&n| FieldNumber::new(n).unwrap()).collect::<Vec<_>>();
+
+    // optional_int32: 1, optional_nested_message { bb: <truncated> }
+    let data = [8u8, 1, 146, 1, 2, 8, 128];
+    let e = TestAllTypes::read_new_from_slice(&data).unwrap_err();
+    match e.kind() {
+        InputErrorKind::IoError(inner) if inner.kind() == std::io::ErrorKind::UnexpectedEof => {}
+        kind => panic!("expected an unexpected eof, got {:?}", kind),
+    }
+    assert_eq!(e.offset(), Some(6));
+    assert_eq!(e.field_path(), numbers(&[18, 1]).as_slice());
+    assert_eq!(e.field_path_names(TestAllTypes::descriptor()), "optional_nested_message.bb");
+    assert!(e.to_string().ends_with(" at offset 6 in field 18.1"));
+
+    // optional_int32: 1, optional_string: "\xff"
+    let data = [8u8, 1, 114, 1, 255];
+    let e = TestAllTypes::read_new(&mut data.as_ref()).unwrap_err();
+    match e.kind() {
+        InputErrorKind::InvalidString(_) => {}
+        kind => panic!("expected an invalid string, got {:?}", kind),
+    }
+    assert_eq!(e.offset(), Some(5));
+    assert_eq!(e.field_path_names(TestAllTypes::descriptor()), "optional_string");
+
+    // optional_int32: 1, then a tag with field number 0
+    let data = [8u8, 1, 0];
+    let e = TestAllTypes::read_new_from_slice(&data).unwrap_err();
+    match e.kind() {
+        InputErrorKind::InvalidTag(0) => {}
+        kind => panic!("expected an invalid tag, got {:?}", kind),
+    }
+    assert_eq!(e.offset(), Some(3));
+    assert!(e.field_path().is_empty());
+}
+
+#[test]
+fn nested_lengths_stay_within_parent() {
+    use protrust::io::InputErrorKind;
+    use shared::gen::unittest_proto::TestRecursiveMessage;
+
+    // a { a <length past the end of the parent> }, i: 1, i: 2, i: 3
+    let data = [10u8, 2, 10, 5, 16, 1, 16, 2, 16, 3];
+    for reader in [false, true].iter() {
+        let e =
+            if *reader {
+                TestRecursiveMessage::read_new(&mut data.as_ref()).unwrap_err()
+            } else {
+                TestRecursiveMessage::read_new_from_slice(&data).unwrap_err()
+            };
+        match e.kind() {
+            InputErrorKind::IoError(inner) if inner.kind() == std::io::ErrorKind::UnexpectedEof => {}
+            kind => panic!("expected an unexpected eof, got {:?}", kind),
+        }
+        assert_eq!(e.field_path_names(TestRecursiveMessage::descriptor()), "a.a");
+    }
+}
@@ -2144,6 +2144,7 @@ pub mod unittest_proto {
         default_cord: ::std::option::Option<::std::string::String>,
         oneof_field: self::test_all_types::OneofField,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestAllTypes {
         fn merge_from(
@@ -2589,6 +2590,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::bytes(oneof_bytes);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -2937,6 +2939,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestAllTypes {
         fn merge(&mut self, other: &Self) {
@@ -3171,6 +3176,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestAllTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestAllTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -4998,6 +5011,7 @@ pub mod unittest_proto {
         pub struct NestedMessage {
             bb: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -5019,6 +5033,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*bb);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -5035,6 +5050,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -5044,6 +5062,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestAllTypes as ::protrust::Message>::descriptor().messages()[0]
@@ -5089,6 +5115,7 @@ pub mod unittest_proto {
         pub struct OptionalGroup {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::OptionalGroup {
             fn merge_from(
@@ -5111,6 +5138,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -5127,6 +5155,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::OptionalGroup {
             fn merge(&mut self, other: &Self) {
@@ -5136,6 +5167,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::OptionalGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::OptionalGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestAllTypes as ::protrust::Message>::descriptor().messages()[1]
@@ -5181,6 +5220,7 @@ pub mod unittest_proto {
         pub struct RepeatedGroup {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::RepeatedGroup {
             fn merge_from(
@@ -5203,6 +5243,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -5219,6 +5260,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::RepeatedGroup {
             fn merge(&mut self, other: &Self) {
@@ -5228,6 +5272,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::RepeatedGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::RepeatedGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestAllTypes as ::protrust::Message>::descriptor().messages()[2]
@@ -6033,6 +6085,7 @@ pub mod unittest_proto {
         payload: ::std::option::Option<::std::boxed::Box<self::TestAllTypes>>,
         repeated_child: ::protrust::collections::RepeatedField<self::NestedTestAllTypes>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::NestedTestAllTypes {
         fn merge_from(
@@ -6065,6 +6118,7 @@ pub mod unittest_proto {
                 .repeated_child()
                 .calculate_size(&self::nested_test_all_types::REPEATED_CHILD_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6100,6 +6154,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::NestedTestAllTypes {
         fn merge(&mut self, other: &Self) {
@@ -6113,6 +6170,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::NestedTestAllTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::NestedTestAllTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -6210,6 +6275,7 @@ pub mod unittest_proto {
         deprecated_int32: ::std::option::Option<i32>,
         oneof_fields: self::test_deprecated_fields::OneofFields,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestDeprecatedFields {
         fn merge_from(
@@ -6238,6 +6304,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*deprecated_int32_in_oneof);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6260,6 +6327,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestDeprecatedFields {
         fn merge(&mut self, other: &Self) {
@@ -6274,6 +6344,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestDeprecatedFields {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestDeprecatedFields {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -6412,6 +6490,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestDeprecatedMessage {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestDeprecatedMessage {
         fn merge_from(
@@ -6428,6 +6507,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6440,12 +6520,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestDeprecatedMessage {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestDeprecatedMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestDeprecatedMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -6457,6 +6548,7 @@ pub mod unittest_proto {
         c: ::std::option::Option<i32>,
         d: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::ForeignMessage {
         fn merge_from(
@@ -6483,6 +6575,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*d);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6503,6 +6596,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::ForeignMessage {
         fn merge(&mut self, other: &Self) {
@@ -6515,6 +6611,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::ForeignMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::ForeignMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -6593,6 +6697,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestReservedFields {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestReservedFields {
         fn merge_from(
@@ -6609,6 +6714,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6621,12 +6727,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestReservedFields {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestReservedFields {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestReservedFields {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -6636,6 +6753,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestAllExtensions {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestAllExtensions {
@@ -6658,6 +6776,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6671,6 +6790,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestAllExtensions {
         fn merge(&mut self, other: &Self) {
@@ -6678,6 +6800,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestAllExtensions {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestAllExtensions {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -6774,6 +6904,7 @@ pub mod unittest_proto {
     pub struct OptionalGroup_extension {
         a: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::OptionalGroup_extension {
         fn merge_from(
@@ -6796,6 +6927,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*a);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6812,6 +6944,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::OptionalGroup_extension {
         fn merge(&mut self, other: &Self) {
@@ -6821,6 +6956,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::OptionalGroup_extension {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::OptionalGroup_extension {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[7]
@@ -6867,6 +7010,7 @@ pub mod unittest_proto {
     pub struct RepeatedGroup_extension {
         a: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::RepeatedGroup_extension {
         fn merge_from(
@@ -6889,6 +7033,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*a);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -6905,6 +7050,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::RepeatedGroup_extension {
         fn merge(&mut self, other: &Self) {
@@ -6914,6 +7062,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::RepeatedGroup_extension {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::RepeatedGroup_extension {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[8]
@@ -6961,6 +7117,7 @@ pub mod unittest_proto {
         optionalgroup: ::std::option::Option<::std::boxed::Box<self::test_group::OptionalGroup>>,
         optional_foreign_enum: ::std::option::Option<::protrust::EnumValue<self::ForeignEnum>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestGroup {
         fn merge_from(
@@ -6990,6 +7147,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::enum_value(*optional_foreign_enum);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -7018,6 +7176,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestGroup {
         fn merge(&mut self, other: &Self) {
@@ -7032,6 +7193,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestGroup {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestGroup {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[9]
@@ -7102,6 +7271,7 @@ pub mod unittest_proto {
         pub struct OptionalGroup {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::OptionalGroup {
             fn merge_from(
@@ -7124,6 +7294,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -7140,6 +7311,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::OptionalGroup {
             fn merge(&mut self, other: &Self) {
@@ -7149,6 +7323,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::OptionalGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::OptionalGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestGroup as ::protrust::Message>::descriptor().messages()[0]
@@ -7216,6 +7398,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestGroupExtension {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestGroupExtension {
@@ -7238,6 +7421,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -7251,6 +7435,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestGroupExtension {
         fn merge(&mut self, other: &Self) {
@@ -7258,6 +7445,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestGroupExtension {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestGroupExtension {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -7353,6 +7548,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestNestedExtension {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestNestedExtension {
         fn merge_from(
@@ -7369,6 +7565,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -7381,12 +7578,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestNestedExtension {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestNestedExtension {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestNestedExtension {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[11]
@@ -7418,6 +7626,7 @@ pub mod unittest_proto {
         pub struct OptionalGroup_extension {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::OptionalGroup_extension {
             fn merge_from(
@@ -7440,6 +7649,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -7456,6 +7666,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::OptionalGroup_extension {
             fn merge(&mut self, other: &Self) {
@@ -7465,6 +7678,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::OptionalGroup_extension {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::OptionalGroup_extension {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestNestedExtension as ::protrust::Message>::descriptor().messages()
@@ -7546,6 +7767,7 @@ pub mod unittest_proto {
         dummy32: ::std::option::Option<i32>,
         c: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRequired {
         fn merge_from(
@@ -7727,6 +7949,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*c);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -7880,6 +8103,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRequired {
         fn merge(&mut self, other: &Self) {
@@ -7985,6 +8211,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRequired {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRequired {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[12]
@@ -9098,6 +9332,7 @@ pub mod unittest_proto {
         repeated_message: ::protrust::collections::RepeatedField<self::TestRequired>,
         dummy: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRequiredForeign {
         fn merge_from(
@@ -9130,6 +9365,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*dummy);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9160,6 +9396,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRequiredForeign {
         fn merge(&mut self, other: &Self) {
@@ -9173,6 +9412,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRequiredForeign {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRequiredForeign {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[13]
@@ -9273,6 +9520,7 @@ pub mod unittest_proto {
         repeated_message: ::protrust::collections::RepeatedField<self::TestRequired>,
         required_message: ::std::option::Option<::std::boxed::Box<self::TestRequired>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRequiredMessage {
         fn merge_from(
@@ -9305,6 +9553,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(required_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9342,6 +9591,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRequiredMessage {
         fn merge(&mut self, other: &Self) {
@@ -9355,6 +9607,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRequiredMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRequiredMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[14]
@@ -9452,6 +9712,7 @@ pub mod unittest_proto {
         foreign_nested:
             ::std::option::Option<::std::boxed::Box<self::test_all_types::NestedMessage>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestForeignNested {
         fn merge_from(
@@ -9473,6 +9734,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(foreign_nested);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9494,6 +9756,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestForeignNested {
         fn merge(&mut self, other: &Self) {
@@ -9503,6 +9768,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestForeignNested {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestForeignNested {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[15]
@@ -9550,6 +9823,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestEmptyMessage {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestEmptyMessage {
         fn merge_from(
@@ -9566,6 +9840,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9578,12 +9853,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestEmptyMessage {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestEmptyMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestEmptyMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[16]
@@ -9593,6 +9879,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestEmptyMessageWithExtensions {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestEmptyMessageWithExtensions {
@@ -9615,6 +9902,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9628,6 +9916,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestEmptyMessageWithExtensions {
         fn merge(&mut self, other: &Self) {
@@ -9635,6 +9926,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestEmptyMessageWithExtensions {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestEmptyMessageWithExtensions {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -9730,6 +10029,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestMultipleExtensionRanges {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestMultipleExtensionRanges {
@@ -9752,6 +10052,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9765,6 +10066,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestMultipleExtensionRanges {
         fn merge(&mut self, other: &Self) {
@@ -9772,6 +10076,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestMultipleExtensionRanges {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestMultipleExtensionRanges {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -9869,6 +10181,7 @@ pub mod unittest_proto {
         a: ::std::option::Option<i32>,
         bb: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestReallyLargeTagNumber {
         fn merge_from(
@@ -9895,6 +10208,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*bb);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -9915,6 +10229,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestReallyLargeTagNumber {
         fn merge(&mut self, other: &Self) {
@@ -9927,6 +10244,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestReallyLargeTagNumber {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestReallyLargeTagNumber {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[19]
@@ -10008,6 +10333,7 @@ pub mod unittest_proto {
         a: ::std::option::Option<::std::boxed::Box<self::TestRecursiveMessage>>,
         i: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRecursiveMessage {
         fn merge_from(
@@ -10034,6 +10360,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*i);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -10059,6 +10386,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRecursiveMessage {
         fn merge(&mut self, other: &Self) {
@@ -10071,6 +10401,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRecursiveMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRecursiveMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[20]
@@ -10147,6 +10485,7 @@ pub mod unittest_proto {
         bb: ::std::option::Option<::std::boxed::Box<self::TestMutualRecursionB>>,
         subgroup: ::std::option::Option<::std::boxed::Box<self::test_mutual_recursion_a::SubGroup>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestMutualRecursionA {
         fn merge_from(
@@ -10174,6 +10513,7 @@ pub mod unittest_proto {
                 size += 1;
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -10205,6 +10545,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestMutualRecursionA {
         fn merge(&mut self, other: &Self) {
@@ -10217,6 +10560,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestMutualRecursionA {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestMutualRecursionA {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[21]
@@ -10270,6 +10621,7 @@ pub mod unittest_proto {
         pub struct SubMessage {
             b: ::std::option::Option<::std::boxed::Box<self::super::TestMutualRecursionB>>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::SubMessage {
             fn merge_from(
@@ -10291,6 +10643,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::message(b);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -10312,6 +10665,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::SubMessage {
             fn merge(&mut self, other: &Self) {
@@ -10321,6 +10677,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::SubMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::SubMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestMutualRecursionA as ::protrust::Message>::descriptor().messages()
@@ -10366,6 +10730,7 @@ pub mod unittest_proto {
             sub_message: ::std::option::Option<::std::boxed::Box<self::SubMessage>>,
             not_in_this_scc: ::std::option::Option<::std::boxed::Box<self::super::TestAllTypes>>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::SubGroup {
             fn merge_from(
@@ -10393,6 +10758,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::message(not_in_this_scc);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -10423,6 +10789,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::SubGroup {
             fn merge(&mut self, other: &Self) {
@@ -10435,6 +10804,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::SubGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::SubGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestMutualRecursionA as ::protrust::Message>::descriptor().messages()
@@ -10538,6 +10915,7 @@ pub mod unittest_proto {
         a: ::std::option::Option<::std::boxed::Box<self::TestMutualRecursionA>>,
         optional_int32: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestMutualRecursionB {
         fn merge_from(
@@ -10564,6 +10942,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*optional_int32);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -10589,6 +10968,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestMutualRecursionB {
         fn merge(&mut self, other: &Self) {
@@ -10601,6 +10983,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestMutualRecursionB {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestMutualRecursionB {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[22]
@@ -10680,6 +11070,7 @@ pub mod unittest_proto {
         sub_message:
             ::std::option::Option<::std::boxed::Box<self::test_is_initialized::SubMessage>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestIsInitialized {
         fn merge_from(
@@ -10701,6 +11092,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(sub_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -10722,6 +11114,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestIsInitialized {
         fn merge(&mut self, other: &Self) {
@@ -10731,6 +11126,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestIsInitialized {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestIsInitialized {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[23]
@@ -10765,6 +11168,7 @@ pub mod unittest_proto {
         pub struct SubMessage {
             subgroup: ::std::option::Option<::std::boxed::Box<self::sub_message::SubGroup>>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::SubMessage {
             fn merge_from(
@@ -10787,6 +11191,7 @@ pub mod unittest_proto {
                     size += 1;
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -10809,6 +11214,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::SubMessage {
             fn merge(&mut self, other: &Self) {
@@ -10818,6 +11226,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::SubMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::SubMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestIsInitialized as ::protrust::Message>::descriptor().messages()[0]
@@ -10850,6 +11266,7 @@ pub mod unittest_proto {
             pub struct SubGroup {
                 i: ::std::option::Option<i32>,
                 unknown_fields: ::protrust::UnknownFieldSet,
+                cached_size: ::protrust::CachedSize,
             }
             impl ::protrust::CodedMessage for self::SubGroup {
                 fn merge_from(
@@ -10872,6 +11289,7 @@ pub mod unittest_proto {
                         size += ::protrust::io::sizes::int32(*i);
                     }
                     size += self.unknown_fields.calculate_size();
+                    self.cached_size.set(size);
                     size
                 }
                 fn write_to(
@@ -10891,6 +11309,9 @@ pub mod unittest_proto {
                     }
                     true
                 }
+                fn cached_size(&self) -> ::std::option::Option<i32> {
+                    ::std::option::Option::Some(self.cached_size.get())
+                }
             }
             impl ::protrust::LiteMessage for self::SubGroup {
                 fn merge(&mut self, other: &Self) {
@@ -10900,6 +11321,14 @@ pub mod unittest_proto {
                     self.unknown_fields.merge(&other.unknown_fields);
                 }
             }
+            impl ::protrust::UnknownFields for self::SubGroup {
+                fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                    &self.unknown_fields
+                }
+                fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                    &mut self.unknown_fields
+                }
+            }
             impl ::protrust::Message for self::SubGroup {
                 fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                     &<self::super::SubMessage as ::protrust::Message>::descriptor().messages()[0]
@@ -10971,6 +11400,7 @@ pub mod unittest_proto {
         foo: ::std::option::Option<::std::boxed::Box<self::test_dup_field_number::Foo>>,
         bar: ::std::option::Option<::std::boxed::Box<self::test_dup_field_number::Bar>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestDupFieldNumber {
         fn merge_from(
@@ -11004,6 +11434,7 @@ pub mod unittest_proto {
                 size += 1;
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -11040,6 +11471,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestDupFieldNumber {
         fn merge(&mut self, other: &Self) {
@@ -11055,6 +11489,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestDupFieldNumber {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestDupFieldNumber {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[24]
@@ -11130,6 +11572,7 @@ pub mod unittest_proto {
         pub struct Foo {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::Foo {
             fn merge_from(
@@ -11152,6 +11595,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -11168,6 +11612,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::Foo {
             fn merge(&mut self, other: &Self) {
@@ -11177,6 +11624,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::Foo {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::Foo {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestDupFieldNumber as ::protrust::Message>::descriptor().messages()
@@ -11223,6 +11678,7 @@ pub mod unittest_proto {
         pub struct Bar {
             a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::Bar {
             fn merge_from(
@@ -11245,6 +11701,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -11261,6 +11718,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::Bar {
             fn merge(&mut self, other: &Self) {
@@ -11270,6 +11730,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::Bar {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::Bar {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestDupFieldNumber as ::protrust::Message>::descriptor().messages()
@@ -11349,6 +11817,7 @@ pub mod unittest_proto {
     pub struct TestEagerMessage {
         sub_message: ::std::option::Option<::std::boxed::Box<self::TestAllTypes>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestEagerMessage {
         fn merge_from(
@@ -11370,6 +11839,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(sub_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -11391,6 +11861,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestEagerMessage {
         fn merge(&mut self, other: &Self) {
@@ -11400,6 +11873,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestEagerMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestEagerMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[25]
@@ -11444,6 +11925,7 @@ pub mod unittest_proto {
     pub struct TestLazyMessage {
         sub_message: ::std::option::Option<::std::boxed::Box<self::TestAllTypes>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestLazyMessage {
         fn merge_from(
@@ -11465,6 +11947,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(sub_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -11486,6 +11969,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestLazyMessage {
         fn merge(&mut self, other: &Self) {
@@ -11495,6 +11981,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestLazyMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestLazyMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[26]
@@ -11541,6 +12035,7 @@ pub mod unittest_proto {
             ::std::boxed::Box<self::test_nested_message_has_bits::NestedMessage>,
         >,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestNestedMessageHasBits {
         fn merge_from(
@@ -11564,6 +12059,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(optional_nested_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -11589,6 +12085,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestNestedMessageHasBits {
         fn merge(&mut self, other: &Self) {
@@ -11603,6 +12102,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestNestedMessageHasBits {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestNestedMessageHasBits {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[27]
@@ -11647,6 +12154,7 @@ pub mod unittest_proto {
             nestedmessage_repeated_foreignmessage:
                 ::protrust::collections::RepeatedField<self::super::ForeignMessage>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -11677,6 +12185,7 @@ pub mod unittest_proto {
                     &self::nested_message::NESTEDMESSAGE_REPEATED_FOREIGNMESSAGE_CODEC,
                 );
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -11700,6 +12209,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -11710,6 +12222,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestNestedMessageHasBits as ::protrust::Message>::descriptor()
@@ -11792,6 +12312,7 @@ pub mod unittest_proto {
         RepeatedStringPieceField: ::protrust::collections::RepeatedField<::std::string::String>,
         RepeatedCordField: ::protrust::collections::RepeatedField<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestCamelCaseFieldNames {
         fn merge_from(
@@ -11880,6 +12401,7 @@ pub mod unittest_proto {
                 .RepeatedCordField()
                 .calculate_size(&self::test_camel_case_field_names::REPEATEDCORDFIELD_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -11948,6 +12470,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestCamelCaseFieldNames {
         fn merge(&mut self, other: &Self) {
@@ -11980,6 +12505,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestCamelCaseFieldNames {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestCamelCaseFieldNames {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[28]
@@ -12348,6 +12881,7 @@ pub mod unittest_proto {
         optional_nested_message:
             ::std::option::Option<::std::boxed::Box<self::test_field_orderings::NestedMessage>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestFieldOrderings {
@@ -12392,6 +12926,7 @@ pub mod unittest_proto {
             }
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -12430,6 +12965,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestFieldOrderings {
         fn merge(&mut self, other: &Self) {
@@ -12454,6 +12992,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestFieldOrderings {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestFieldOrderings {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -12658,6 +13204,7 @@ pub mod unittest_proto {
             oo: ::std::option::Option<i64>,
             bb: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -12684,6 +13231,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*bb);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -12704,6 +13252,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -12716,6 +13267,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestFieldOrderings as ::protrust::Message>::descriptor().messages()
@@ -12835,6 +13394,7 @@ pub mod unittest_proto {
     pub struct TestExtensionOrderings1 {
         my_string: ::std::option::Option<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestExtensionOrderings1 {
         fn merge_from(
@@ -12856,6 +13416,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(my_string);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -12872,6 +13433,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestExtensionOrderings1 {
         fn merge(&mut self, other: &Self) {
@@ -12881,6 +13445,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestExtensionOrderings1 {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestExtensionOrderings1 {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[30]
@@ -12937,6 +13509,7 @@ pub mod unittest_proto {
     pub struct TestExtensionOrderings2 {
         my_string: ::std::option::Option<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestExtensionOrderings2 {
         fn merge_from(
@@ -12958,6 +13531,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(my_string);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -12974,6 +13548,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestExtensionOrderings2 {
         fn merge(&mut self, other: &Self) {
@@ -12983,6 +13560,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestExtensionOrderings2 {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestExtensionOrderings2 {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[31]
@@ -13027,6 +13612,7 @@ pub mod unittest_proto {
         pub struct TestExtensionOrderings3 {
             my_string: ::std::option::Option<::std::string::String>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::TestExtensionOrderings3 {
             fn merge_from(
@@ -13048,6 +13634,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::string(my_string);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -13064,6 +13651,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::TestExtensionOrderings3 {
             fn merge(&mut self, other: &Self) {
@@ -13073,6 +13663,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::TestExtensionOrderings3 {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::TestExtensionOrderings3 {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestExtensionOrderings2 as ::protrust::Message>::descriptor()
@@ -13168,6 +13766,7 @@ pub mod unittest_proto {
         cord_with_zero: ::std::option::Option<::std::string::String>,
         replacement_string: ::std::option::Option<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestExtremeDefaultValues {
         fn merge_from(
@@ -13331,6 +13930,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(replacement_string);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -13463,6 +14063,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestExtremeDefaultValues {
         fn merge(&mut self, other: &Self) {
@@ -13562,6 +14165,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestExtremeDefaultValues {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestExtremeDefaultValues {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[32]
@@ -14564,6 +15175,7 @@ pub mod unittest_proto {
     pub struct SparseEnumMessage {
         sparse_enum: ::std::option::Option<::protrust::EnumValue<self::TestSparseEnum>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::SparseEnumMessage {
         fn merge_from(
@@ -14585,6 +15197,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::enum_value(*sparse_enum);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -14601,6 +15214,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::SparseEnumMessage {
         fn merge(&mut self, other: &Self) {
@@ -14610,6 +15226,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::SparseEnumMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::SparseEnumMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[33]
@@ -14663,6 +15287,7 @@ pub mod unittest_proto {
     pub struct OneString {
         data: ::std::option::Option<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::OneString {
         fn merge_from(
@@ -14684,6 +15309,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -14700,6 +15326,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::OneString {
         fn merge(&mut self, other: &Self) {
@@ -14709,6 +15338,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::OneString {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::OneString {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[34]
@@ -14760,6 +15397,7 @@ pub mod unittest_proto {
     pub struct MoreString {
         data: ::protrust::collections::RepeatedField<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::MoreString {
         fn merge_from(
@@ -14780,6 +15418,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.data().calculate_size(&self::more_string::DATA_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -14794,6 +15433,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::MoreString {
         fn merge(&mut self, other: &Self) {
@@ -14801,6 +15443,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::MoreString {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::MoreString {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[35]
@@ -14833,6 +15483,7 @@ pub mod unittest_proto {
     pub struct OneBytes {
         data: ::std::option::Option<::std::vec::Vec<u8>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::OneBytes {
         fn merge_from(
@@ -14854,6 +15505,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::bytes(data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -14870,6 +15522,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::OneBytes {
         fn merge(&mut self, other: &Self) {
@@ -14879,6 +15534,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::OneBytes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::OneBytes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[36]
@@ -14930,6 +15593,7 @@ pub mod unittest_proto {
     pub struct MoreBytes {
         data: ::protrust::collections::RepeatedField<::std::vec::Vec<u8>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::MoreBytes {
         fn merge_from(
@@ -14950,6 +15614,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.data().calculate_size(&self::more_bytes::DATA_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -14964,6 +15629,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::MoreBytes {
         fn merge(&mut self, other: &Self) {
@@ -14971,6 +15639,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::MoreBytes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::MoreBytes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[37]
@@ -15003,6 +15679,7 @@ pub mod unittest_proto {
     pub struct Int32Message {
         data: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::Int32Message {
         fn merge_from(
@@ -15024,6 +15701,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15040,6 +15718,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::Int32Message {
         fn merge(&mut self, other: &Self) {
@@ -15049,6 +15730,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::Int32Message {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::Int32Message {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[38]
@@ -15095,6 +15784,7 @@ pub mod unittest_proto {
     pub struct Uint32Message {
         data: ::std::option::Option<u32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::Uint32Message {
         fn merge_from(
@@ -15116,6 +15806,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::uint32(*data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15132,6 +15823,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::Uint32Message {
         fn merge(&mut self, other: &Self) {
@@ -15141,6 +15835,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::Uint32Message {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::Uint32Message {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[39]
@@ -15187,6 +15889,7 @@ pub mod unittest_proto {
     pub struct Int64Message {
         data: ::std::option::Option<i64>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::Int64Message {
         fn merge_from(
@@ -15208,6 +15911,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int64(*data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15224,6 +15928,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::Int64Message {
         fn merge(&mut self, other: &Self) {
@@ -15233,6 +15940,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::Int64Message {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::Int64Message {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[40]
@@ -15279,6 +15994,7 @@ pub mod unittest_proto {
     pub struct Uint64Message {
         data: ::std::option::Option<u64>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::Uint64Message {
         fn merge_from(
@@ -15300,6 +16016,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::uint64(*data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15316,6 +16033,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::Uint64Message {
         fn merge(&mut self, other: &Self) {
@@ -15325,6 +16045,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::Uint64Message {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::Uint64Message {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[41]
@@ -15371,6 +16099,7 @@ pub mod unittest_proto {
     pub struct BoolMessage {
         data: ::std::option::Option<bool>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::BoolMessage {
         fn merge_from(
@@ -15392,6 +16121,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::bool(*data);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15408,6 +16138,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::BoolMessage {
         fn merge(&mut self, other: &Self) {
@@ -15417,6 +16150,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::BoolMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::BoolMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[42]
@@ -15463,6 +16204,7 @@ pub mod unittest_proto {
     pub struct TestOneof {
         foo: self::test_oneof::Foo,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestOneof {
         fn merge_from(
@@ -15500,6 +16242,7 @@ pub mod unittest_proto {
                 size += 1;
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -15539,6 +16282,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestOneof {
         fn merge(&mut self, other: &Self) {
@@ -15557,6 +16303,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestOneof {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestOneof {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[43]
@@ -15767,6 +16521,7 @@ pub mod unittest_proto {
             a: ::std::option::Option<i32>,
             b: ::std::option::Option<::std::string::String>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::FooGroup {
             fn merge_from(
@@ -15794,6 +16549,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::string(b);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -15814,6 +16570,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::FooGroup {
             fn merge(&mut self, other: &Self) {
@@ -15826,6 +16585,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::FooGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::FooGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestOneof as ::protrust::Message>::descriptor().messages()[0]
@@ -15956,6 +16723,7 @@ pub mod unittest_proto {
             ::std::boxed::Box<self::test_oneof_backwards_compatible::FooGroup>,
         >,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestOneofBackwardsCompatible {
         fn merge_from(
@@ -15993,6 +16761,7 @@ pub mod unittest_proto {
                 size += 1;
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -16032,6 +16801,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestOneofBackwardsCompatible {
         fn merge(&mut self, other: &Self) {
@@ -16050,6 +16822,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestOneofBackwardsCompatible {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestOneofBackwardsCompatible {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[44]
@@ -16158,6 +16938,7 @@ pub mod unittest_proto {
             a: ::std::option::Option<i32>,
             b: ::std::option::Option<::std::string::String>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::FooGroup {
             fn merge_from(
@@ -16185,6 +16966,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::string(b);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -16205,6 +16987,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::FooGroup {
             fn merge(&mut self, other: &Self) {
@@ -16217,6 +17002,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::FooGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::FooGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestOneofBackwardsCompatible as ::protrust::Message>::descriptor()
@@ -16348,6 +17141,7 @@ pub mod unittest_proto {
         foo: self::test_oneof2::Foo,
         bar: self::test_oneof2::Bar,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestOneof2 {
         fn merge_from(
@@ -16450,6 +17244,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(baz_string);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -16546,6 +17341,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestOneof2 {
         fn merge(&mut self, other: &Self) {
@@ -16603,6 +17401,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestOneof2 {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestOneof2 {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[45]
@@ -17449,6 +18255,7 @@ pub mod unittest_proto {
             a: ::std::option::Option<i32>,
             b: ::std::option::Option<::std::string::String>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::FooGroup {
             fn merge_from(
@@ -17476,6 +18283,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::string(b);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -17496,6 +18304,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::FooGroup {
             fn merge(&mut self, other: &Self) {
@@ -17508,6 +18319,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::FooGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::FooGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestOneof2 as ::protrust::Message>::descriptor().messages()[0]
@@ -17591,6 +18410,7 @@ pub mod unittest_proto {
             qux_int: ::std::option::Option<i64>,
             corge_int: ::protrust::collections::RepeatedField<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -17618,6 +18438,7 @@ pub mod unittest_proto {
                     .corge_int()
                     .calculate_size(&self::nested_message::CORGE_INT_CODEC);
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -17636,6 +18457,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -17646,6 +18470,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestOneof2 as ::protrust::Message>::descriptor().messages()[1]
@@ -17891,6 +18723,7 @@ pub mod unittest_proto {
     pub struct TestRequiredOneof {
         foo: self::test_required_oneof::Foo,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRequiredOneof {
         fn merge_from(
@@ -17922,6 +18755,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::message(foo_message);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -17951,6 +18785,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRequiredOneof {
         fn merge(&mut self, other: &Self) {
@@ -17966,6 +18803,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRequiredOneof {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRequiredOneof {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[46]
@@ -18146,6 +18991,7 @@ pub mod unittest_proto {
         pub struct NestedMessage {
             required_double: ::std::option::Option<f64>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -18168,6 +19014,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::double(*required_double);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -18188,6 +19035,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -18198,6 +19048,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestRequiredOneof as ::protrust::Message>::descriptor().messages()[0]
@@ -18292,6 +19150,7 @@ pub mod unittest_proto {
         packed_enum:
             ::protrust::collections::RepeatedField<::protrust::EnumValue<self::ForeignEnum>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestPackedTypes {
         fn merge_from(
@@ -18392,6 +19251,7 @@ pub mod unittest_proto {
                 .packed_enum()
                 .calculate_size(&self::test_packed_types::PACKED_ENUM_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -18432,6 +19292,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestPackedTypes {
         fn merge(&mut self, other: &Self) {
@@ -18452,6 +19315,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestPackedTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestPackedTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[47]
@@ -18725,6 +19596,7 @@ pub mod unittest_proto {
         unpacked_enum:
             ::protrust::collections::RepeatedField<::protrust::EnumValue<self::ForeignEnum>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestUnpackedTypes {
         fn merge_from(
@@ -18825,6 +19697,7 @@ pub mod unittest_proto {
                 .unpacked_enum()
                 .calculate_size(&self::test_unpacked_types::UNPACKED_ENUM_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -18865,6 +19738,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestUnpackedTypes {
         fn merge(&mut self, other: &Self) {
@@ -18885,6 +19761,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestUnpackedTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestUnpackedTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[48]
@@ -19148,6 +20032,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestPackedExtensions {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestPackedExtensions {
@@ -19170,6 +20055,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -19183,6 +20069,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestPackedExtensions {
         fn merge(&mut self, other: &Self) {
@@ -19190,6 +20079,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestPackedExtensions {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestPackedExtensions {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -19285,6 +20182,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestUnpackedExtensions {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestUnpackedExtensions {
@@ -19307,6 +20205,7 @@ pub mod unittest_proto {
             let mut size = 0i32;
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -19320,6 +20219,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestUnpackedExtensions {
         fn merge(&mut self, other: &Self) {
@@ -19327,6 +20229,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestUnpackedExtensions {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestUnpackedExtensions {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -19433,6 +20343,7 @@ pub mod unittest_proto {
         repeated_extension: ::protrust::collections::RepeatedField<::std::string::String>,
         packed_extension: ::protrust::collections::RepeatedField<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestDynamicExtensions {
         fn merge_from(
@@ -19492,6 +20403,7 @@ pub mod unittest_proto {
                 .packed_extension()
                 .calculate_size(&self::test_dynamic_extensions::PACKED_EXTENSION_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -19548,6 +20460,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestDynamicExtensions {
         fn merge(&mut self, other: &Self) {
@@ -19578,6 +20493,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestDynamicExtensions {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestDynamicExtensions {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[51]
@@ -19791,6 +20714,7 @@ pub mod unittest_proto {
         pub struct DynamicMessageType {
             dynamic_field: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::DynamicMessageType {
             fn merge_from(
@@ -19812,6 +20736,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*dynamic_field);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -19828,6 +20753,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::DynamicMessageType {
             fn merge(&mut self, other: &Self) {
@@ -19837,6 +20765,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::DynamicMessageType {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::DynamicMessageType {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestDynamicExtensions as ::protrust::Message>::descriptor()
@@ -19968,6 +20904,7 @@ pub mod unittest_proto {
         repeated_float: ::protrust::collections::RepeatedField<f32>,
         repeated_uint64: ::protrust::collections::RepeatedField<u64>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestRepeatedScalarDifferentTagSizes {
         fn merge_from(
@@ -20026,6 +20963,7 @@ pub mod unittest_proto {
                 &self::test_repeated_scalar_different_tag_sizes::REPEATED_UINT64_CODEC,
             );
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -20062,6 +21000,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestRepeatedScalarDifferentTagSizes {
         fn merge(&mut self, other: &Self) {
@@ -20074,6 +21015,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestRepeatedScalarDifferentTagSizes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestRepeatedScalarDifferentTagSizes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[52]
@@ -20195,6 +21144,7 @@ pub mod unittest_proto {
         repeatedgroup:
             ::protrust::collections::RepeatedField<self::test_parsing_merge::RepeatedGroup>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestParsingMerge {
@@ -20245,6 +21195,7 @@ pub mod unittest_proto {
                 .calculate_size(&self::test_parsing_merge::REPEATEDGROUP_CODEC);
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -20295,6 +21246,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestParsingMerge {
         fn merge(&mut self, other: &Self) {
@@ -20313,6 +21267,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestParsingMerge {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestParsingMerge {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -20514,6 +21476,7 @@ pub mod unittest_proto {
             ext1: ::protrust::collections::RepeatedField<self::super::TestAllTypes>,
             ext2: ::protrust::collections::RepeatedField<self::super::TestAllTypes>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::RepeatedFieldsGenerator {
             fn merge_from(
@@ -20572,6 +21535,7 @@ pub mod unittest_proto {
                     .ext2()
                     .calculate_size(&self::repeated_fields_generator::EXT2_CODEC);
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -20613,6 +21577,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::RepeatedFieldsGenerator {
             fn merge(&mut self, other: &Self) {
@@ -20626,6 +21593,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::RepeatedFieldsGenerator {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::RepeatedFieldsGenerator {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestParsingMerge as ::protrust::Message>::descriptor().messages()[0]
@@ -20724,6 +21699,7 @@ pub mod unittest_proto {
             pub struct Group1 {
                 field1: ::std::option::Option<::std::boxed::Box<self::super::super::TestAllTypes>>,
                 unknown_fields: ::protrust::UnknownFieldSet,
+                cached_size: ::protrust::CachedSize,
             }
             impl ::protrust::CodedMessage for self::Group1 {
                 fn merge_from(
@@ -20746,6 +21722,7 @@ pub mod unittest_proto {
                         size += ::protrust::io::sizes::message(field1);
                     }
                     size += self.unknown_fields.calculate_size();
+                    self.cached_size.set(size);
                     size
                 }
                 fn write_to(
@@ -20767,6 +21744,9 @@ pub mod unittest_proto {
                     }
                     true
                 }
+                fn cached_size(&self) -> ::std::option::Option<i32> {
+                    ::std::option::Option::Some(self.cached_size.get())
+                }
             }
             impl ::protrust::LiteMessage for self::Group1 {
                 fn merge(&mut self, other: &Self) {
@@ -20776,6 +21756,14 @@ pub mod unittest_proto {
                     self.unknown_fields.merge(&other.unknown_fields);
                 }
             }
+            impl ::protrust::UnknownFields for self::Group1 {
+                fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                    &self.unknown_fields
+                }
+                fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                    &mut self.unknown_fields
+                }
+            }
             impl ::protrust::Message for self::Group1 {
                 fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                     &<self::super::RepeatedFieldsGenerator as ::protrust::Message>::descriptor()
@@ -20823,6 +21811,7 @@ pub mod unittest_proto {
             pub struct Group2 {
                 field1: ::std::option::Option<::std::boxed::Box<self::super::super::TestAllTypes>>,
                 unknown_fields: ::protrust::UnknownFieldSet,
+                cached_size: ::protrust::CachedSize,
             }
             impl ::protrust::CodedMessage for self::Group2 {
                 fn merge_from(
@@ -20845,6 +21834,7 @@ pub mod unittest_proto {
                         size += ::protrust::io::sizes::message(field1);
                     }
                     size += self.unknown_fields.calculate_size();
+                    self.cached_size.set(size);
                     size
                 }
                 fn write_to(
@@ -20866,6 +21856,9 @@ pub mod unittest_proto {
                     }
                     true
                 }
+                fn cached_size(&self) -> ::std::option::Option<i32> {
+                    ::std::option::Option::Some(self.cached_size.get())
+                }
             }
             impl ::protrust::LiteMessage for self::Group2 {
                 fn merge(&mut self, other: &Self) {
@@ -20875,6 +21868,14 @@ pub mod unittest_proto {
                     self.unknown_fields.merge(&other.unknown_fields);
                 }
             }
+            impl ::protrust::UnknownFields for self::Group2 {
+                fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                    &self.unknown_fields
+                }
+                fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                    &mut self.unknown_fields
+                }
+            }
             impl ::protrust::Message for self::Group2 {
                 fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                     &<self::super::RepeatedFieldsGenerator as ::protrust::Message>::descriptor()
@@ -20994,6 +21995,7 @@ pub mod unittest_proto {
             optional_group_all_types:
                 ::std::option::Option<::std::boxed::Box<self::super::TestAllTypes>>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::OptionalGroup {
             fn merge_from(
@@ -21018,6 +22020,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::message(optional_group_all_types);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -21043,6 +22046,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::OptionalGroup {
             fn merge(&mut self, other: &Self) {
@@ -21057,6 +22063,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::OptionalGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::OptionalGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestParsingMerge as ::protrust::Message>::descriptor().messages()[1]
@@ -21107,6 +22121,7 @@ pub mod unittest_proto {
             repeated_group_all_types:
                 ::std::option::Option<::std::boxed::Box<self::super::TestAllTypes>>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::RepeatedGroup {
             fn merge_from(
@@ -21131,6 +22146,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::message(repeated_group_all_types);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -21156,6 +22172,9 @@ pub mod unittest_proto {
                 }
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::RepeatedGroup {
             fn merge(&mut self, other: &Self) {
@@ -21170,6 +22189,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::RepeatedGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::RepeatedGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestParsingMerge as ::protrust::Message>::descriptor().messages()[2]
@@ -21273,6 +22300,7 @@ pub mod unittest_proto {
     pub struct TestCommentInjectionMessage {
         a: ::std::option::Option<::std::string::String>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestCommentInjectionMessage {
         fn merge_from(
@@ -21294,6 +22322,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::string(a);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21310,6 +22339,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestCommentInjectionMessage {
         fn merge(&mut self, other: &Self) {
@@ -21319,6 +22351,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestCommentInjectionMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestCommentInjectionMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[54]
@@ -21367,6 +22407,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct FooRequest {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::FooRequest {
         fn merge_from(
@@ -21383,6 +22424,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21395,12 +22437,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::FooRequest {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::FooRequest {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::FooRequest {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[55]
@@ -21410,6 +22463,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct FooResponse {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::FooResponse {
         fn merge_from(
@@ -21426,6 +22480,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21438,12 +22493,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::FooResponse {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::FooResponse {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::FooResponse {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[56]
@@ -21453,6 +22519,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct FooClientMessage {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::FooClientMessage {
         fn merge_from(
@@ -21469,6 +22536,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21481,12 +22549,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::FooClientMessage {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::FooClientMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::FooClientMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[57]
@@ -21496,6 +22575,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct FooServerMessage {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::FooServerMessage {
         fn merge_from(
@@ -21512,6 +22592,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21524,12 +22605,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::FooServerMessage {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::FooServerMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::FooServerMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[58]
@@ -21539,6 +22631,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct BarRequest {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::BarRequest {
         fn merge_from(
@@ -21555,6 +22648,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21567,12 +22661,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::BarRequest {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::BarRequest {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::BarRequest {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[59]
@@ -21582,6 +22687,7 @@ pub mod unittest_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct BarResponse {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::BarResponse {
         fn merge_from(
@@ -21598,6 +22704,7 @@ pub mod unittest_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21610,12 +22717,23 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::BarResponse {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::BarResponse {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::BarResponse {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[60]
@@ -21631,6 +22749,7 @@ pub mod unittest_proto {
         FIELD_NAME5: ::std::option::Option<i32>,
         field_name6: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestJsonName {
         fn merge_from(
@@ -21677,6 +22796,7 @@ pub mod unittest_proto {
                 size += ::protrust::io::sizes::int32(*field_name6);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -21713,6 +22833,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestJsonName {
         fn merge(&mut self, other: &Self) {
@@ -21737,6 +22860,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestJsonName {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestJsonName {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[61]
@@ -21961,6 +23092,7 @@ pub mod unittest_proto {
             ::protrust::collections::MapField<::std::string::String, ::std::string::String>,
         oneof_field: self::test_huge_field_numbers::OneofField,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestHugeFieldNumbers {
@@ -22058,6 +23190,7 @@ pub mod unittest_proto {
             }
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -22139,6 +23272,9 @@ pub mod unittest_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestHugeFieldNumbers {
         fn merge(&mut self, other: &Self) {
@@ -22186,6 +23322,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestHugeFieldNumbers {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestHugeFieldNumbers {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -22754,6 +23898,7 @@ pub mod unittest_proto {
         pub struct OptionalGroup {
             group_a: ::std::option::Option<i32>,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::OptionalGroup {
             fn merge_from(
@@ -22776,6 +23921,7 @@ pub mod unittest_proto {
                     size += ::protrust::io::sizes::int32(*group_a);
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -22792,6 +23938,9 @@ pub mod unittest_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::OptionalGroup {
             fn merge(&mut self, other: &Self) {
@@ -22801,6 +23950,14 @@ pub mod unittest_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::OptionalGroup {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::OptionalGroup {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestHugeFieldNumbers as ::protrust::Message>::descriptor().messages()
@@ -23014,6 +24171,7 @@ pub mod unittest_proto {
         field9: ::std::option::Option<i32>,
         field10: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
         extensions: ::protrust::ExtensionSet<Self>,
     }
     impl ::protrust::CodedMessage for self::TestExtensionInsideTable {
@@ -23081,6 +24239,7 @@ pub mod unittest_proto {
             }
             size += self.extensions.calculate_size();
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -23130,6 +24289,9 @@ pub mod unittest_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestExtensionInsideTable {
         fn merge(&mut self, other: &Self) {
@@ -23164,6 +24326,14 @@ pub mod unittest_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestExtensionInsideTable {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::ExtendableMessage for self::TestExtensionInsideTable {
         fn registry(&self) -> ::std::option::Option<&'static ::protrust::ExtensionRegistry> {
             self.extensions.registry()
@@ -24000,6 +25170,7 @@ pub mod unittest_proto3_proto {
             ::protrust::collections::RepeatedField<self::test_all_types::NestedMessage>,
         oneof_field: self::test_all_types::OneofField,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestAllTypes {
         fn merge_from(
@@ -24311,6 +25482,7 @@ pub mod unittest_proto3_proto {
                 size += ::protrust::io::sizes::bytes(oneof_bytes);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -24556,6 +25728,9 @@ pub mod unittest_proto3_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestAllTypes {
         fn merge(&mut self, other: &Self) {
@@ -24713,6 +25888,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestAllTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestAllTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -25569,6 +26752,7 @@ pub mod unittest_proto3_proto {
         pub struct NestedMessage {
             bb: i32,
             unknown_fields: ::protrust::UnknownFieldSet,
+            cached_size: ::protrust::CachedSize,
         }
         impl ::protrust::CodedMessage for self::NestedMessage {
             fn merge_from(
@@ -25590,6 +26774,7 @@ pub mod unittest_proto3_proto {
                     size += ::protrust::io::sizes::int32(*self.bb());
                 }
                 size += self.unknown_fields.calculate_size();
+                self.cached_size.set(size);
                 size
             }
             fn write_to(
@@ -25606,6 +26791,9 @@ pub mod unittest_proto3_proto {
             fn is_initialized(&self) -> bool {
                 true
             }
+            fn cached_size(&self) -> ::std::option::Option<i32> {
+                ::std::option::Option::Some(self.cached_size.get())
+            }
         }
         impl ::protrust::LiteMessage for self::NestedMessage {
             fn merge(&mut self, other: &Self) {
@@ -25615,6 +26803,14 @@ pub mod unittest_proto3_proto {
                 self.unknown_fields.merge(&other.unknown_fields);
             }
         }
+        impl ::protrust::UnknownFields for self::NestedMessage {
+            fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+                &self.unknown_fields
+            }
+            fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+                &mut self.unknown_fields
+            }
+        }
         impl ::protrust::Message for self::NestedMessage {
             fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
                 &<self::super::TestAllTypes as ::protrust::Message>::descriptor().messages()[0]
@@ -26129,6 +27325,7 @@ pub mod unittest_proto3_proto {
         packed_enum:
             ::protrust::collections::RepeatedField<::protrust::EnumValue<self::ForeignEnum>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestPackedTypes {
         fn merge_from(
@@ -26229,6 +27426,7 @@ pub mod unittest_proto3_proto {
                 .packed_enum()
                 .calculate_size(&self::test_packed_types::PACKED_ENUM_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -26269,6 +27467,9 @@ pub mod unittest_proto3_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestPackedTypes {
         fn merge(&mut self, other: &Self) {
@@ -26289,6 +27490,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestPackedTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestPackedTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[1]
@@ -26563,6 +27772,7 @@ pub mod unittest_proto3_proto {
             ::protrust::EnumValue<self::test_all_types::NestedEnum>,
         >,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestUnpackedTypes {
         fn merge_from(
@@ -26664,6 +27874,7 @@ pub mod unittest_proto3_proto {
                 .repeated_nested_enum()
                 .calculate_size(&self::test_unpacked_types::REPEATED_NESTED_ENUM_CODEC);
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -26706,6 +27917,9 @@ pub mod unittest_proto3_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestUnpackedTypes {
         fn merge(&mut self, other: &Self) {
@@ -26726,6 +27940,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestUnpackedTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestUnpackedTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[2]
@@ -26993,6 +28215,7 @@ pub mod unittest_proto3_proto {
         child: ::std::option::Option<::std::boxed::Box<self::NestedTestAllTypes>>,
         payload: ::std::option::Option<::std::boxed::Box<self::TestAllTypes>>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::NestedTestAllTypes {
         fn merge_from(
@@ -27019,6 +28242,7 @@ pub mod unittest_proto3_proto {
                 size += ::protrust::io::sizes::message(payload);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27049,6 +28273,9 @@ pub mod unittest_proto3_proto {
             }
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::NestedTestAllTypes {
         fn merge(&mut self, other: &Self) {
@@ -27061,6 +28288,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::NestedTestAllTypes {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::NestedTestAllTypes {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[3]
@@ -27136,6 +28371,7 @@ pub mod unittest_proto3_proto {
     pub struct ForeignMessage {
         c: i32,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::ForeignMessage {
         fn merge_from(
@@ -27157,6 +28393,7 @@ pub mod unittest_proto3_proto {
                 size += ::protrust::io::sizes::int32(*self.c());
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27173,6 +28410,9 @@ pub mod unittest_proto3_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::ForeignMessage {
         fn merge(&mut self, other: &Self) {
@@ -27182,6 +28422,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::ForeignMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::ForeignMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[4]
@@ -27209,6 +28457,7 @@ pub mod unittest_proto3_proto {
     #[derive(Clone, Debug, PartialEq, Default)]
     pub struct TestEmptyMessage {
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestEmptyMessage {
         fn merge_from(
@@ -27225,6 +28474,7 @@ pub mod unittest_proto3_proto {
         fn calculate_size(&self) -> i32 {
             let mut size = 0i32;
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27237,12 +28487,23 @@ pub mod unittest_proto3_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestEmptyMessage {
         fn merge(&mut self, other: &Self) {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestEmptyMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestEmptyMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[5]
@@ -27253,6 +28514,7 @@ pub mod unittest_proto3_proto {
     pub struct TestOneof2 {
         foo: self::test_oneof2::Foo,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::TestOneof2 {
         fn merge_from(
@@ -27274,6 +28536,7 @@ pub mod unittest_proto3_proto {
                 size += ::protrust::io::sizes::enum_value(*foo_enum);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27290,6 +28553,9 @@ pub mod unittest_proto3_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::TestOneof2 {
         fn merge(&mut self, other: &Self) {
@@ -27299,6 +28565,14 @@ pub mod unittest_proto3_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::TestOneof2 {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::TestOneof2 {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[6]
@@ -27486,6 +28760,7 @@ pub mod unittest_import_proto {
     pub struct ImportMessage {
         d: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::ImportMessage {
         fn merge_from(
@@ -27507,6 +28782,7 @@ pub mod unittest_import_proto {
                 size += ::protrust::io::sizes::int32(*d);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27523,6 +28799,9 @@ pub mod unittest_import_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::ImportMessage {
         fn merge(&mut self, other: &Self) {
@@ -27532,6 +28811,14 @@ pub mod unittest_import_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::ImportMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::ImportMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
@@ -27657,6 +28944,7 @@ pub mod unittest_import_public_proto {
     pub struct PublicImportMessage {
         e: ::std::option::Option<i32>,
         unknown_fields: ::protrust::UnknownFieldSet,
+        cached_size: ::protrust::CachedSize,
     }
     impl ::protrust::CodedMessage for self::PublicImportMessage {
         fn merge_from(
@@ -27678,6 +28966,7 @@ pub mod unittest_import_public_proto {
                 size += ::protrust::io::sizes::int32(*e);
             }
             size += self.unknown_fields.calculate_size();
+            self.cached_size.set(size);
             size
         }
         fn write_to(
@@ -27694,6 +28983,9 @@ pub mod unittest_import_public_proto {
         fn is_initialized(&self) -> bool {
             true
         }
+        fn cached_size(&self) -> ::std::option::Option<i32> {
+            ::std::option::Option::Some(self.cached_size.get())
+        }
     }
     impl ::protrust::LiteMessage for self::PublicImportMessage {
         fn merge(&mut self, other: &Self) {
@@ -27703,6 +28995,14 @@ pub mod unittest_import_public_proto {
             self.unknown_fields.merge(&other.unknown_fields);
         }
     }
+    impl ::protrust::UnknownFields for self::PublicImportMessage {
+        fn unknown_fields(&self) -> &::protrust::UnknownFieldSet {
+            &self.unknown_fields
+        }
+        fn unknown_fields_mut(&mut self) -> &mut ::protrust::UnknownFieldSet {
+            &mut self.unknown_fields
+        }
+    }
     impl ::protrust::Message for self::PublicImportMessage {
         fn descriptor() -> &'static ::protrust::reflect::MessageDescriptor<'static> {
             &self::file().messages()[0]
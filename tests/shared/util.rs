@@ -2,6 +2,7 @@
 
 use super::gen;
 use protrust::prelude::*;
+use protrust::reflect::{DescriptorPool, FileDescriptor};
 
 pub fn make_test_all_types_proto2() -> gen::unittest_proto::TestAllTypes {
     let mut msg = gen::unittest_proto::TestAllTypes::new();
@@ -24,3 +25,43 @@ pub fn make_test_all_types_proto2() -> gen::unittest_proto::TestAllTypes {
 
     msg
 }
+
+pub fn make_test_all_types_proto3() -> gen::unittest_proto3_proto::TestAllTypes {
+    use gen::unittest_proto3_proto::test_all_types::NestedEnum;
+
+    let mut msg = gen::unittest_proto3_proto::TestAllTypes::new();
+    *msg.optional_int32_mut() = 1;
+    *msg.optional_int64_mut() = -2;
+    *msg.optional_uint64_mut() = 3;
+    *msg.optional_float_mut() = 0.5;
+    *msg.optional_double_mut() = std::f64::NEG_INFINITY;
+    *msg.optional_bool_mut() = true;
+    *msg.optional_string_mut() = "quote\" slash\\ newline\n tab\t ünïcode".to_string();
+    *msg.optional_bytes_mut() = b"\x00\xffbytes".to_vec();
+    *msg.optional_nested_message_mut().bb_mut() = 4;
+    *msg.optional_nested_enum_mut() = Defined(NestedEnum::Bar);
+    msg.repeated_int32_mut().extend(&[1, 2]);
+    msg.repeated_nested_enum_mut().push(Defined(NestedEnum::Neg));
+    msg.repeated_nested_enum_mut().push(EnumValue::Undefined(10));
+    msg.set_oneof_uint32(0);
+
+    msg
+}
+
+/// Creates an empty dynamic instance of the proto3 `TestAllTypes` message, built from a pool separate from the generated code
+pub fn new_dynamic_test_all_types_proto3() -> Box<dyn protrust::reflect::AnyValue<'static>> {
+    let pool = dynamic_pool(&[
+        gen::unittest_import_public_proto::file(),
+        gen::unittest_import_proto::file(),
+        gen::unittest_proto3_proto::file(),
+    ]);
+    let descriptor = pool.find_message_by_name("proto3_unittest.TestAllTypes").expect("missing message");
+    descriptor.new_instance().unwrap()
+}
+
+/// Builds a pool of dynamic messages from the files, which must include every dependency of the files.
+/// The pool is leaked so it can be used for the rest of the test
+pub fn dynamic_pool(files: &[&FileDescriptor<'static>]) -> &'static DescriptorPool<'static> {
+    let files: Vec<_> = files.iter().map(|file| file.proto().clone()).collect();
+    Box::leak(Box::new(DescriptorPool::build_from_files(Box::leak(files.into_boxed_slice()))))
+}
@@ -0,0 +1,306 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::prelude::*;
+use protrust::text_format::{self, ParseErrorKind};
+use protrust::io::CodedInput;
+use protrust::wkt::{any::Any, duration::Duration};
+
+use shared::gen::unittest_proto::{self, TestAllExtensions, TestEmptyMessage, TestHugeFieldNumbers};
+use shared::gen::unittest_proto3_proto::{test_all_types::NestedEnum, TestAllTypes};
+
+#[test]
+fn print_proto3_fields() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+    assert_eq!(
+        text_format::to_string(&value)?,
+        concat!(
+            "optional_int32: 1\n",
+            "optional_int64: -2\n",
+            "optional_uint64: 3\n",
+            "optional_float: 0.5\n",
+            "optional_double: -inf\n",
+            "optional_bool: true\n",
+            "optional_string: \"quote\\\" slash\\\\ newline\\n tab\\t ünïcode\"\n",
+            "optional_bytes: \"\\000\\377bytes\"\n",
+            "optional_nested_message {\n",
+            "  bb: 4\n",
+            "}\n",
+            "optional_nested_enum: BAR\n",
+            "repeated_int32: 1\n",
+            "repeated_int32: 2\n",
+            "repeated_nested_enum: NEG\n",
+            "repeated_nested_enum: 10\n",
+            "oneof_uint32: 0\n"));
+    assert_eq!(
+        text_format::to_single_line_string(&value)?,
+        concat!(
+            "optional_int32: 1 optional_int64: -2 optional_uint64: 3 optional_float: 0.5 optional_double: -inf optional_bool: true ",
+            "optional_string: \"quote\\\" slash\\\\ newline\\n tab\\t ünïcode\" optional_bytes: \"\\000\\377bytes\" ",
+            "optional_nested_message { bb: 4 } optional_nested_enum: BAR repeated_int32: 1 repeated_int32: 2 ",
+            "repeated_nested_enum: NEG repeated_nested_enum: 10 oneof_uint32: 0"));
+
+    assert_eq!(text_format::to_string(&TestAllTypes::new())?, "");
+    Ok(())
+}
+
+#[test]
+fn print_proto2_fields() -> shared::Result {
+    let mut value = unittest_proto::TestAllTypes::new();
+    value.set_optional_int32(0);
+    value.optionalgroup_mut().set_a(25);
+    value.optional_nested_message_mut();
+
+    assert_eq!(
+        text_format::to_string(&value)?,
+        "optional_int32: 0\nOptionalGroup {\n  a: 25\n}\noptional_nested_message {\n}\n");
+    assert_eq!(
+        text_format::to_single_line_string(&value)?,
+        "optional_int32: 0 OptionalGroup { a: 25 } optional_nested_message { }");
+    Ok(())
+}
+
+#[test]
+fn print_map() -> shared::Result {
+    let mut value = TestHugeFieldNumbers::new();
+    value.string_string_map_mut().insert("key".to_string(), "value".to_string());
+
+    assert_eq!(
+        text_format::to_string(&value)?,
+        concat!(
+            "string_string_map {\n",
+            "  key: \"key\"\n",
+            "  value: \"value\"\n",
+            "}\n"));
+    Ok(())
+}
+
+#[test]
+fn print_extensions() -> shared::Result {
+    // optional_int32_extension = 5, repeated_string_extension = ["a"]
+    let data = [0x08, 0x05, 0xE2, 0x02, 0x01, b'a'];
+    let mut value = TestAllExtensions::with_registry(Some(shared::gen::extensions()));
+    value.merge_from(&mut CodedInput::new(&mut &data[..]).with_registry(Some(shared::gen::extensions())))?;
+
+    assert_eq!(
+        text_format::to_single_line_string(&value)?,
+        "[protobuf_unittest.optional_int32_extension]: 5 [protobuf_unittest.repeated_string_extension]: \"a\"");
+    Ok(())
+}
+
+#[test]
+fn print_any() -> shared::Result {
+    let mut duration = Duration::new();
+    *duration.seconds_mut() = 1;
+    let any = Any::pack(&duration)?;
+
+    assert_eq!(
+        text_format::to_string(&any)?,
+        "[type.googleapis.com/google.protobuf.Duration] {\n  seconds: 1\n}\n");
+
+    // types that can't be resolved are printed as a normal message
+    let mut unresolved = Any::new();
+    *unresolved.type_url_mut() = "type.googleapis.com/unknown.Type".to_string();
+    assert_eq!(
+        text_format::to_single_line_string(&unresolved)?,
+        "type_url: \"type.googleapis.com/unknown.Type\"");
+    Ok(())
+}
+
+#[test]
+fn print_dynamic_message() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+
+    let mut instance = shared::util::new_dynamic_test_all_types_proto3();
+    let instance = instance.as_message_mut().unwrap();
+    instance.merge_from_reader(&mut value.write_to_vec()?.as_slice())?;
+
+    assert_eq!(text_format::to_string(instance)?, text_format::to_string(&value)?);
+    Ok(())
+}
+
+#[test]
+fn print_unknown_fields() -> shared::Result {
+    let mut value = unittest_proto::TestAllTypes::new();
+    value.set_optional_int32(1);
+    value.set_optional_fixed32(7);
+    value.set_optional_fixed64(8);
+    value.optional_string_mut().push_str("a\n");
+    value.optionalgroup_mut().set_a(25);
+
+    let empty = TestEmptyMessage::read_new(&mut value.write_to_vec()?.as_slice())?;
+    assert_eq!(
+        text_format::to_single_line_string(&empty)?,
+        "1: 1 7: 0x00000007 8: 0x0000000000000008 14: \"a\\n\" 16 { 17: 25 }");
+    Ok(())
+}
+
+#[test]
+fn parse_roundtrip() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+    assert_eq!(text_format::from_str::<TestAllTypes>(&text_format::to_string(&value)?)?, value);
+    assert_eq!(text_format::from_str::<TestAllTypes>(&text_format::to_single_line_string(&value)?)?, value);
+
+    let value = shared::util::make_test_all_types_proto2();
+    assert_eq!(text_format::from_str::<unittest_proto::TestAllTypes>(&text_format::to_string(&value)?)?, value);
+    Ok(())
+}
+
+#[test]
+fn parse_alternate_forms() -> shared::Result {
+    let parsed: TestAllTypes = text_format::from_str(concat!(
+        "# a comment\n",
+        "optional_int32: 0x10, optional_int64: -010;\n",
+        "optional_float: 1.5f optional_double: -Infinity\n",
+        "optional_string: 'single' \"\\x41\\101\\u00e9\"\n",
+        "optional_nested_message: < bb: 4 >\n",
+        "optional_nested_enum: 2\n",
+        "repeated_int32: [1, 2] repeated_int32: 3\n",
+        "repeated_nested_message [{ bb: 1 }, < bb: 2 >]\n",
+        "repeated_nested_enum: [FOO, BAZ]\n"))?;
+
+    let mut expected = TestAllTypes::new();
+    *expected.optional_int32_mut() = 16;
+    *expected.optional_int64_mut() = -8;
+    *expected.optional_float_mut() = 1.5;
+    *expected.optional_double_mut() = std::f64::NEG_INFINITY;
+    *expected.optional_string_mut() = "singleAAé".to_string();
+    *expected.optional_nested_message_mut().bb_mut() = 4;
+    *expected.optional_nested_enum_mut() = Defined(NestedEnum::Bar);
+    expected.repeated_int32_mut().extend(&[1, 2, 3]);
+    for bb in 1..=2 {
+        let mut nested = shared::gen::unittest_proto3_proto::test_all_types::NestedMessage::new();
+        *nested.bb_mut() = bb;
+        expected.repeated_nested_message_mut().push(nested);
+    }
+    expected.repeated_nested_enum_mut().push(Defined(NestedEnum::Foo));
+    expected.repeated_nested_enum_mut().push(Defined(NestedEnum::Baz));
+
+    assert_eq!(parsed, expected);
+    Ok(())
+}
+
+#[test]
+fn parse_maps_extensions_and_any() -> shared::Result {
+    let parsed: TestHugeFieldNumbers = text_format::from_str(r#"string_string_map { key: "a" value: "b" } string_string_map [{ key: "c" }]"#)?;
+    let mut expected = TestHugeFieldNumbers::new();
+    expected.string_string_map_mut().insert("a".to_string(), "b".to_string());
+    expected.string_string_map_mut().insert("c".to_string(), String::new());
+    assert_eq!(parsed, expected);
+
+    let mut extensions = TestAllExtensions::with_registry(Some(shared::gen::extensions()));
+    text_format::merge_from_str(
+        &mut extensions,
+        r#"[protobuf_unittest.optional_int32_extension]: 5 [protobuf_unittest.repeated_string_extension]: ["a", "b"]"#)?;
+    assert_eq!(
+        text_format::to_single_line_string(&extensions)?,
+        r#"[protobuf_unittest.optional_int32_extension]: 5 [protobuf_unittest.repeated_string_extension]: "a" [protobuf_unittest.repeated_string_extension]: "b""#);
+
+    let any: Any = text_format::from_str("[type.googleapis.com/google.protobuf.Duration] { seconds: 1 }")?;
+    let mut duration = Duration::new();
+    *duration.seconds_mut() = 1;
+    assert_eq!(any, Any::pack(&duration)?);
+    Ok(())
+}
+
+#[test]
+fn parse_repeated_enum_extension() -> shared::Result {
+    let mut extensions = TestAllExtensions::with_registry(Some(shared::gen::extensions()));
+    text_format::merge_from_str(&mut extensions, "[protobuf_unittest.repeated_nested_enum_extension]: [FOO, 3]")?;
+    assert_eq!(
+        text_format::to_single_line_string(&extensions)?,
+        "[protobuf_unittest.repeated_nested_enum_extension]: FOO [protobuf_unittest.repeated_nested_enum_extension]: BAZ");
+    Ok(())
+}
+
+#[test]
+fn parse_dynamic_message() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+
+    let mut instance = shared::util::new_dynamic_test_all_types_proto3();
+    let instance = instance.as_message_mut().unwrap();
+    text_format::merge_from_str(instance, &text_format::to_string(&value)?)?;
+
+    assert_eq!(instance.write_to_vec()?, value.write_to_vec()?);
+    Ok(())
+}
+
+#[test]
+fn parse_errors() {
+    fn error(input: &str) -> (ParseErrorKind, usize, usize) {
+        let error = text_format::from_str::<TestAllTypes>(input).unwrap_err();
+        (error.kind().clone(), error.line(), error.column())
+    }
+
+    assert_eq!(error("optional_int32: 1\nunknown: 2"), (ParseErrorKind::UnknownField("unknown".to_string()), 2, 1));
+    assert_eq!(error("optional_int32: 1\n  optional_int32: 2"), (ParseErrorKind::DuplicateField("optional_int32".to_string()), 2, 3));
+    assert_eq!(error("oneof_uint32: 1 oneof_string: \"a\""), (ParseErrorKind::DuplicateField("oneof_string".to_string()), 1, 17));
+    assert_eq!(error("optional_int32: 3000000000"), (ParseErrorKind::InvalidNumber, 1, 17));
+    assert_eq!(error("optional_bool: yes"), (ParseErrorKind::InvalidBool("yes".to_string()), 1, 16));
+    assert_eq!(error("optional_nested_enum: QUX"), (ParseErrorKind::UnknownEnumValue("QUX".to_string()), 1, 23));
+    assert_eq!(error("optional_string: \"\\q\""), (ParseErrorKind::InvalidString, 1, 18));
+    assert_eq!(error("optional_nested_message { bb: 1"), (ParseErrorKind::UnexpectedEof, 1, 32));
+    assert_eq!(error("optional_int32 1"), (ParseErrorKind::UnexpectedChar('1'), 1, 16));
+    assert_eq!(
+        text_format::from_str::<Any>("[type.googleapis.com/unknown.Type] {}").unwrap_err().kind(),
+        &ParseErrorKind::UnresolvedAny("type.googleapis.com/unknown.Type".to_string()));
+}
+
+#[test]
+fn parse_recursion_limit() -> shared::Result {
+    use shared::gen::unittest_proto::TestRecursiveMessage;
+
+    let message = text_format::from_str::<TestRecursiveMessage>(&format!("{}{}", "a { ".repeat(99), "}".repeat(99)))?;
+    assert!(message.a().is_some());
+
+    let error = text_format::from_str::<TestRecursiveMessage>(&format!("{}{}", "a { ".repeat(100), "}".repeat(100))).unwrap_err();
+    assert_eq!(error.kind(), &ParseErrorKind::RecursionLimitExceeded);
+    Ok(())
+}
+
+#[test]
+fn decode_raw_guesses_values() -> shared::Result {
+    let value = shared::util::make_test_all_types_proto3();
+    assert_eq!(text_format::decode_raw(&value.write_to_vec()?)?, r#"1: 1  # sint: -1
+2: 18446744073709551614  # int: -2, sint: 9223372036854775807
+4: 3  # sint: -2
+11: 0x3f000000  # uint: 1056964608, float: 0.5
+12: 0xfff0000000000000  # uint: 18442240474082181120, int: -4503599627370496, double: -inf
+13: 1  # sint: -1
+14: "quote\" slash\\ newline\n tab\t ünïcode"
+15: "\000\377bytes"
+18 {
+  1: 4  # sint: 2
+}
+21: 2  # sint: 1
+31: "\001\002"
+51: "\377\377\377\377\377\377\377\377\377\001\n"
+111: 0
+"#);
+    Ok(())
+}
+
+#[test]
+fn decode_raw_prints_groups_and_rejects_invalid_data() -> shared::Result {
+    let data = [11, 16, 1, 27, 29, 255, 255, 255, 255, 28, 12];
+    assert_eq!(text_format::decode_raw(&data)?, "\
+1 {
+  2: 1  # sint: -1
+  3 {
+    3: 0xffffffff  # uint: 4294967295, int: -1, float: nan
+  }
+}
+");
+
+    assert_eq!(text_format::decode_raw(&[10, 2, b'h', b'i', 18, 0])?, "\
+1 {
+  13: 105  # sint: -53
+}
+2: \"\"
+");
+
+    assert!(text_format::decode_raw(&[8]).is_err());
+    assert!(text_format::decode_raw(&[12]).is_err());
+    assert!(text_format::decode_raw(&[18, 5, 1]).is_err());
+    Ok(())
+}
@@ -0,0 +1,102 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::io::FieldNumber;
+use protrust::prelude::*;
+use protrust::UnknownField;
+
+use shared::gen::unittest_proto::{TestAllTypes, TestEmptyMessage};
+
+fn number(n: u32) -> FieldNumber {
+    FieldNumber::new(n).unwrap()
+}
+
+#[test]
+fn inspect_unknown_fields() -> shared::Result {
+    let mut value = TestAllTypes::new();
+    value.set_optional_int32(1);
+    value.set_optional_fixed64(8);
+    value.optional_string_mut().push('a');
+    value.optionalgroup_mut().set_a(25);
+    value.set_optional_fixed32(7);
+    value.repeated_int32_mut().push(2);
+    value.repeated_int32_mut().push(3);
+
+    let empty = TestEmptyMessage::read_new_from_slice(&value.write_to_vec()?)?;
+    let fields = empty.unknown_fields();
+    assert_eq!(fields.len(), 7);
+    assert!(fields.contains(number(14)));
+    assert!(!fields.contains(number(2)));
+
+    let numbers: Vec<_> = fields.iter().map(|(n, _)| n.get()).collect();
+    assert_eq!(numbers, [1, 7, 8, 14, 16, 31, 31]);
+
+    assert_eq!(fields.get(number(1)).collect::<Vec<_>>(), [&UnknownField::Varint(1)]);
+    assert_eq!(fields.get(number(7)).collect::<Vec<_>>(), [&UnknownField::Bit32(7)]);
+    assert_eq!(fields.get(number(8)).collect::<Vec<_>>(), [&UnknownField::Bit64(8)]);
+    assert_eq!(fields.get(number(14)).collect::<Vec<_>>(), [&UnknownField::LengthDelimited(b"a".to_vec())]);
+    assert_eq!(fields.get(number(31)).collect::<Vec<_>>(), [&UnknownField::Varint(2), &UnknownField::Varint(3)]);
+
+    match fields.get(number(16)).next() {
+        Some(UnknownField::Group(group)) => {
+            assert_eq!(group.iter().collect::<Vec<_>>(), [(number(17), &UnknownField::Varint(25))]);
+        }
+        other => panic!("expected a group, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn modify_unknown_fields() -> shared::Result {
+    let mut empty = TestEmptyMessage::new();
+    let fields = empty.unknown_fields_mut();
+    fields.add(number(1), UnknownField::Varint(150));
+    fields.add(number(2), UnknownField::LengthDelimited(b"hi".to_vec()));
+    fields.add(number(1), UnknownField::Varint(1));
+
+    let mut group = protrust::UnknownFieldSet::new();
+    group.add(number(4), UnknownField::Bit32(5));
+    fields.add(number(3), UnknownField::Group(group));
+    assert_eq!(empty.write_to_vec()?, [8, 150, 1, 18, 2, b'h', b'i', 8, 1, 27, 37, 5, 0, 0, 0, 28]);
+
+    assert_eq!(TestEmptyMessage::read_new_from_slice(&empty.write_to_vec()?)?, empty);
+
+    let fields = empty.unknown_fields_mut();
+    assert!(fields.remove(number(1)));
+    assert!(!fields.remove(number(1)));
+    fields.retain(|n, _| n.get() != 3);
+    assert_eq!(empty.write_to_vec()?, [18, 2, b'h', b'i']);
+
+    empty.unknown_fields_mut().clear();
+    assert!(empty.unknown_fields().is_empty());
+    assert_eq!(empty.write_to_vec()?, []);
+    Ok(())
+}
+
+#[test]
+fn discard_unknown_fields() -> shared::Result {
+    use protrust::io::CodedInput;
+    use shared::gen::unittest_proto::NestedTestAllTypes;
+
+    let mut value = NestedTestAllTypes::new();
+    value.payload_mut().set_optional_int32(1);
+    value.child_mut().payload_mut().set_optional_int32(2);
+    let data = value.write_to_vec()?;
+
+    let mut empty = TestEmptyMessage::new();
+    empty.merge_from(&mut CodedInput::from_slice(&data).with_discard_unknown_fields(true))?;
+    assert!(empty.unknown_fields().is_empty());
+    assert_eq!(empty.write_to_vec()?, []);
+
+    // unknown fields in nested messages are discarded as well
+    let mut value = TestAllTypes::new();
+    value.optional_nested_message_mut().set_bb(3);
+    value.optional_nested_message_mut().unknown_fields_mut().add(number(2), UnknownField::Varint(4));
+    let data = value.write_to_vec()?;
+
+    let mut read = TestAllTypes::new();
+    read.merge_from(&mut CodedInput::from_slice(&data).with_discard_unknown_fields(true))?;
+    assert_eq!(read.optional_nested_message().map(|m| m.bb()), Some(3));
+    assert!(read.optional_nested_message().unwrap().unknown_fields().is_empty());
+    Ok(())
+}
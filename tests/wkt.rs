@@ -0,0 +1,453 @@
+mod shared;
+
+use pretty_assertions::assert_eq;
+use protrust::prelude::*;
+use protrust::reflect::DescriptorPool;
+use protrust::wkt::any::{Any, PoolResolver, TypeResolver};
+use protrust::wkt::duration::{Duration as ProtoDuration, DurationError};
+use protrust::wkt::field_mask::{self, FieldMask, MergeOptions};
+use protrust::wkt::r#struct::{value::Kind, ListValue, NullValue, Struct, Value};
+use protrust::wkt::timestamp::{Timestamp, TimestampError};
+use protrust::struct_value;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use shared::gen::unittest_proto::{TestAllTypes, TestHugeFieldNumbers};
+
+fn timestamp(seconds: i64, nanos: i32) -> Timestamp {
+    let mut value = Timestamp::new();
+    *value.seconds_mut() = seconds;
+    *value.nanos_mut() = nanos;
+    value
+}
+
+fn duration(seconds: i64, nanos: i32) -> ProtoDuration {
+    let mut value = ProtoDuration::new();
+    *value.seconds_mut() = seconds;
+    *value.nanos_mut() = nanos;
+    value
+}
+
+#[test]
+fn timestamp_system_time_conversions() -> shared::Result {
+    let before_epoch = UNIX_EPOCH - Duration::new(1, 250_000_000);
+    assert_eq!(Timestamp::try_from(before_epoch)?, timestamp(-2, 750_000_000));
+    assert_eq!(SystemTime::try_from(timestamp(-2, 750_000_000))?, before_epoch);
+
+    let after_epoch = UNIX_EPOCH + Duration::new(1_000_000_000, 1);
+    assert_eq!(Timestamp::try_from(after_epoch)?, timestamp(1_000_000_000, 1));
+    assert_eq!(SystemTime::try_from(&timestamp(1_000_000_000, 1))?, after_epoch);
+
+    assert_eq!(SystemTime::try_from(timestamp(0, -1)), Err(TimestampError::OutOfRange));
+    assert!(Timestamp::now().is_valid());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "chrono-time")]
+fn timestamp_chrono_conversions() -> shared::Result {
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    let date = Utc.from_utc_datetime(&NaiveDate::from_ymd_opt(1969, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 500).unwrap());
+    assert_eq!(Timestamp::try_from(date)?, timestamp(-1, 500_000_000));
+    assert_eq!(DateTime::<Utc>::try_from(timestamp(-1, 500_000_000))?, date);
+
+    // chrono represents leap seconds with nanos past the end of the second
+    let leap = Utc.from_utc_datetime(&NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap());
+    assert_eq!(Timestamp::try_from(leap)?, timestamp(1_483_228_800, 500_000_000));
+
+    let far_future = Utc.from_utc_datetime(&NaiveDate::from_ymd_opt(10_000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(Timestamp::try_from(far_future), Err(TimestampError::OutOfRange));
+    Ok(())
+}
+
+#[test]
+fn timestamp_rfc3339() -> shared::Result {
+    assert_eq!(timestamp(0, 0).to_rfc3339()?, "1970-01-01T00:00:00Z");
+    assert_eq!(timestamp(-1, 10_000).to_rfc3339()?, "1969-12-31T23:59:59.000010Z");
+    assert_eq!(timestamp(253_402_300_799, 999_999_999).to_rfc3339()?, "9999-12-31T23:59:59.999999999Z");
+    assert_eq!(timestamp(253_402_300_800, 0).to_rfc3339(), Err(TimestampError::OutOfRange));
+
+    assert_eq!("0001-01-01T00:00:00Z".parse::<Timestamp>()?, timestamp(-62_135_596_800, 0));
+    assert_eq!("1970-01-01T05:30:00.5+05:30".parse::<Timestamp>()?, timestamp(0, 500_000_000));
+    assert_eq!("1970-01-01t00:00:00-01:00".parse::<Timestamp>()?, timestamp(3600, 0));
+    assert_eq!("0001-01-01T00:00:00+00:01".parse::<Timestamp>(), Err(TimestampError::OutOfRange));
+    assert_eq!("1970-02-30T00:00:00Z".parse::<Timestamp>(), Err(TimestampError::InvalidFormat));
+    assert_eq!("1970-01-01 00:00:00Z".parse::<Timestamp>(), Err(TimestampError::InvalidFormat));
+    Ok(())
+}
+
+#[test]
+fn timestamp_normalization() {
+    let mut value = timestamp(5, 2_000_000_001);
+    assert!(!value.is_valid());
+    value.normalize();
+    assert_eq!(value, timestamp(7, 1));
+
+    let mut value = timestamp(0, -1);
+    value.normalize();
+    assert_eq!(value, timestamp(-1, 999_999_999));
+    assert!(value.is_valid());
+
+    assert!(!timestamp(-62_135_596_801, 0).is_valid());
+}
+
+#[test]
+fn duration_std_conversions() -> shared::Result {
+    assert_eq!(ProtoDuration::try_from(Duration::new(5, 1))?, duration(5, 1));
+    assert_eq!(Duration::try_from(duration(5, 1))?, Duration::new(5, 1));
+    assert_eq!(Duration::try_from(&duration(0, 0))?, Duration::new(0, 0));
+
+    assert_eq!(Duration::try_from(duration(-5, 0)), Err(DurationError::OutOfRange));
+    assert_eq!(Duration::try_from(duration(0, -1)), Err(DurationError::OutOfRange));
+    assert_eq!(ProtoDuration::try_from(Duration::from_secs(315_576_000_001)), Err(DurationError::OutOfRange));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "chrono-time")]
+fn duration_chrono_conversions() -> shared::Result {
+    let negative = chrono::Duration::milliseconds(-1_500);
+    assert_eq!(ProtoDuration::try_from(negative)?, duration(-1, -500_000_000));
+    assert_eq!(chrono::Duration::try_from(duration(-1, -500_000_000))?, negative);
+
+    let positive = chrono::Duration::nanoseconds(2_000_000_001);
+    assert_eq!(ProtoDuration::try_from(positive)?, duration(2, 1));
+    assert_eq!(chrono::Duration::try_from(&duration(2, 1))?, positive);
+
+    assert_eq!(chrono::Duration::try_from(duration(-1, 1)), Err(DurationError::OutOfRange));
+    assert_eq!(ProtoDuration::try_from(chrono::Duration::days(3_660_000)), Err(DurationError::OutOfRange));
+    Ok(())
+}
+
+#[test]
+fn duration_arithmetic() {
+    assert_eq!(duration(1, 600_000_000) + duration(2, 600_000_000), duration(4, 200_000_000));
+    assert_eq!(duration(1, 0) + duration(-2, -500_000_000), duration(-1, -500_000_000));
+    assert_eq!(duration(1, 0) - duration(0, 1), duration(0, 999_999_999));
+    assert_eq!(duration(0, 500_000_000) - duration(1, 0), duration(0, -500_000_000));
+    assert_eq!(-duration(3, 1), duration(-3, -1));
+
+    // unnormalized operands still give normalized results
+    assert_eq!(duration(-1, 1_999_999_999) + duration(0, 1_999_999_999), duration(2, 999_999_998));
+}
+
+#[test]
+fn duration_string_form() -> shared::Result {
+    assert_eq!(duration(1, 500_000_000).to_string(), "1.500s");
+    assert_eq!(duration(0, -1_000).to_string(), "-0.000001s");
+    assert_eq!(duration(-3, 0).to_string(), "-3s");
+    assert_eq!(duration(2, -1).to_string(), "1.999999999s");
+
+    assert_eq!("0.000000001s".parse::<ProtoDuration>()?, duration(0, 1));
+    assert_eq!("-315576000000.999999999s".parse::<ProtoDuration>()?, duration(-315_576_000_000, -999_999_999));
+    assert_eq!("315576000001s".parse::<ProtoDuration>(), Err(DurationError::OutOfRange));
+    assert_eq!("1.5".parse::<ProtoDuration>(), Err(DurationError::InvalidFormat));
+    assert_eq!("1.0000000001s".parse::<ProtoDuration>(), Err(DurationError::InvalidFormat));
+    assert_eq!("+1s".parse::<ProtoDuration>(), Err(DurationError::InvalidFormat));
+    Ok(())
+}
+
+#[test]
+fn duration_normalization() {
+    let mut value = duration(1, -1_500_000_000);
+    assert!(!value.is_valid());
+    value.normalize();
+    assert_eq!(value, duration(0, -500_000_000));
+    assert!(value.is_valid());
+
+    assert!(!duration(315_576_000_001, 0).is_valid());
+}
+
+#[test]
+fn field_mask_set_operations() {
+    let mut mask = FieldMask::from_paths(vec!["a.b.c", "", "d", "a.b.c", "d.e", "a.x"]);
+    mask.normalize();
+    assert_eq!(mask, FieldMask::from_paths(vec!["a.b.c", "a.x", "d"]));
+
+    let other = FieldMask::from_paths(vec!["a.b", "d.e.f", "g"]);
+    assert_eq!(mask.union(&other), FieldMask::from_paths(vec!["a.b", "a.x", "d", "g"]));
+    assert_eq!(mask.intersection(&other), FieldMask::from_paths(vec!["a.b.c", "d.e.f"]));
+    assert_eq!(other.intersection(&mask), FieldMask::from_paths(vec!["a.b.c", "d.e.f"]));
+    assert_eq!(mask.intersection(&FieldMask::new()), FieldMask::new());
+}
+
+#[test]
+fn field_mask_validation() {
+    let descriptor = TestAllTypes::descriptor();
+    let valid = FieldMask::from_paths(vec!["optional_int32", "optional_nested_message.bb", "optionalgroup.a", "repeated_nested_message"]);
+    assert!(valid.validate(descriptor).is_ok());
+
+    for path in &["", "missing", "optional_int32.a", "repeated_nested_message.bb", "optional_nested_message..bb"] {
+        match FieldMask::from_paths(vec![*path]).validate(descriptor) {
+            Err(e) => assert_eq!(e.path(), *path),
+            Ok(()) => panic!("path '{}' was accepted", path),
+        }
+    }
+    assert!(FieldMask::from_paths(vec!["string_string_map"]).validate(TestHugeFieldNumbers::descriptor()).is_ok());
+    assert!(FieldMask::from_paths(vec!["string_string_map.key"]).validate(TestHugeFieldNumbers::descriptor()).is_err());
+}
+
+fn make_merge_source() -> TestAllTypes {
+    let mut value = TestAllTypes::new();
+    value.set_optional_int32(1);
+    value.optional_nested_message_mut().set_bb(2);
+    value.repeated_int32_mut().push(3);
+    value.repeated_string_mut().push("source".to_string());
+    value
+}
+
+fn make_merge_destination() -> TestAllTypes {
+    let mut value = TestAllTypes::new();
+    value.set_optional_int64(10);
+    value.set_optional_uint32(11);
+    value.optional_foreign_message_mut().set_c(12);
+    value.optional_foreign_message_mut().set_d(13);
+    value.repeated_int32_mut().push(14);
+    value.repeated_string_mut().push("destination".to_string());
+    value
+}
+
+const MERGE_PATHS: &[&str] = &["optional_int32", "optional_int64", "optional_nested_message", "optional_foreign_message.c", "repeated_int32", "repeated_string"];
+
+#[test]
+fn field_mask_merge_default_options() -> shared::Result {
+    let mut value = make_merge_destination();
+    field_mask::merge_with_mask(&make_merge_source(), &mut value, &FieldMask::from_paths(MERGE_PATHS.iter().cloned()), &MergeOptions::default())?;
+
+    let mut expected = make_merge_destination();
+    expected.set_optional_int32(1);
+    expected.optional_nested_message_mut().set_bb(2);
+    expected.repeated_int32_mut().push(3);
+    expected.repeated_string_mut().push("source".to_string());
+    assert_eq!(value, expected);
+    Ok(())
+}
+
+#[test]
+fn field_mask_merge_replace_options() -> shared::Result {
+    let options = MergeOptions {
+        replace_repeated_fields: true,
+        replace_message_fields: true,
+        replace_primitive_fields: true,
+    };
+    let mut mask = FieldMask::from_paths(MERGE_PATHS.iter().cloned());
+    mask.paths_mut().push("optional_foreign_message.d".to_string());
+
+    let mut value = make_merge_destination();
+    value.optional_nested_message_mut().set_bb(20);
+    field_mask::merge_with_mask(&make_merge_source(), &mut value, &mask, &options)?;
+
+    let mut expected = make_merge_source();
+    expected.set_optional_uint32(11);
+    expected.optional_foreign_message_mut();
+    assert_eq!(value, expected);
+    Ok(())
+}
+
+#[test]
+fn field_mask_merge_maps() -> shared::Result {
+    let mut source = TestHugeFieldNumbers::new();
+    source.string_string_map_mut().insert("a".to_string(), "source".to_string());
+    source.string_string_map_mut().insert("b".to_string(), "source".to_string());
+    let mut value = TestHugeFieldNumbers::new();
+    value.string_string_map_mut().insert("b".to_string(), "destination".to_string());
+    value.string_string_map_mut().insert("c".to_string(), "destination".to_string());
+    let mask = FieldMask::from_paths(vec!["string_string_map"]);
+
+    let mut merged = value.clone();
+    field_mask::merge_with_mask(&source, &mut merged, &mask, &MergeOptions::default())?;
+    let mut expected = source.clone();
+    expected.string_string_map_mut().insert("c".to_string(), "destination".to_string());
+    assert_eq!(merged, expected);
+
+    let options = MergeOptions { replace_repeated_fields: true, ..Default::default() };
+    field_mask::merge_with_mask(&source, &mut value, &mask, &options)?;
+    assert_eq!(value, source);
+    Ok(())
+}
+
+#[test]
+fn field_mask_trim() -> shared::Result {
+    let mut value = shared::util::make_test_all_types_proto2();
+    value.optional_nested_message_mut().set_bb(1);
+    value.optional_foreign_message_mut().set_c(2);
+    value.optional_foreign_message_mut().set_d(3);
+    value.repeated_int32_mut().extend(vec![4, 5]);
+    value.repeated_string_mut().push("trimmed".to_string());
+    let mask = FieldMask::from_paths(vec!["optional_string", "optional_nested_message.bb", "optional_foreign_message.c", "repeated_int32"]);
+    field_mask::trim(&mut value, &mask)?;
+
+    let mut expected = TestAllTypes::new();
+    expected.optional_string_mut().push_str("Hello world!");
+    expected.optional_nested_message_mut().set_bb(1);
+    expected.optional_foreign_message_mut().set_c(2);
+    expected.repeated_int32_mut().extend(vec![4, 5]);
+    assert_eq!(value, expected);
+
+    assert!(field_mask::trim(&mut value, &FieldMask::from_paths(vec!["missing"])).is_err());
+    Ok(())
+}
+
+fn dynamic_pool() -> &'static DescriptorPool<'static> {
+    shared::util::dynamic_pool(&[
+        shared::gen::unittest_import_public_proto::file(),
+        shared::gen::unittest_import_proto::file(),
+        shared::gen::unittest_proto::file(),
+    ])
+}
+
+#[test]
+fn field_mask_dynamic_messages() -> shared::Result {
+    let pool = dynamic_pool();
+    let descriptor = pool.find_message_by_name("protobuf_unittest.TestAllTypes").expect("missing message");
+    let mask = FieldMask::from_paths(MERGE_PATHS.iter().cloned());
+
+    let mut source = descriptor.new_instance().unwrap();
+    let source = source.as_message_mut().unwrap();
+    source.merge_from_reader(&mut make_merge_source().write_to_vec()?.as_slice())?;
+    let mut value = descriptor.new_instance().unwrap();
+    let value = value.as_message_mut().unwrap();
+    value.merge_from_reader(&mut make_merge_destination().write_to_vec()?.as_slice())?;
+
+    field_mask::merge_with_mask(source, value, &mask, &MergeOptions::default())?;
+    let mut expected = make_merge_destination();
+    field_mask::merge_with_mask(&make_merge_source(), &mut expected, &mask, &MergeOptions::default())?;
+    assert_eq!(value.write_to_vec()?, expected.write_to_vec()?);
+
+    field_mask::trim(value, &mask)?;
+    field_mask::trim(&mut expected, &mask)?;
+    assert_eq!(value.write_to_vec()?, expected.write_to_vec()?);
+    Ok(())
+}
+
+#[test]
+fn any_unpack_generated_into_dynamic() -> shared::Result {
+    let resolver = PoolResolver::new().with_pool(dynamic_pool());
+    let expected = shared::util::make_test_all_types_proto2();
+    let any = Any::pack(&expected)?;
+
+    let value = any.unpack_with(&resolver).expect("unresolved type")?;
+    let message = value.as_message().unwrap();
+    assert_eq!(message.descriptor().full_name(), "protobuf_unittest.TestAllTypes");
+    assert!(message.downcast_ref::<TestAllTypes>().is_none());
+    assert_eq!(message.write_to_vec()?, expected.write_to_vec()?);
+
+    assert_eq!(Any::pack_dyn(message)?, any);
+    Ok(())
+}
+
+#[test]
+fn any_pack_dynamic_into_generated() -> shared::Result {
+    let pool = dynamic_pool();
+    let descriptor = pool.find_message_by_name("protobuf_unittest.TestAllTypes").expect("missing message");
+    let expected = shared::util::make_test_all_types_proto2();
+
+    let mut value = descriptor.new_instance().unwrap();
+    let value = value.as_message_mut().unwrap();
+    value.merge_from_reader(&mut expected.write_to_vec()?.as_slice())?;
+
+    let any = Any::pack_dyn_with_prefix(value, "example.com/types")?;
+    assert_eq!(any.type_url(), "example.com/types/protobuf_unittest.TestAllTypes");
+    assert_eq!(any.unpack::<TestAllTypes>().expect("mismatched type")?, expected);
+    Ok(())
+}
+
+#[test]
+fn any_resolver_searches_pools_in_order() -> shared::Result {
+    let resolver = PoolResolver::new().with_pool(protrust::generated::pool()).with_pool(dynamic_pool());
+
+    let well_known = resolver.resolve("type.googleapis.com/google.protobuf.Timestamp").expect("unresolved type");
+    assert!(std::ptr::eq(well_known, Timestamp::descriptor()));
+    let all_types = resolver.resolve("type.googleapis.com/protobuf_unittest.TestAllTypes").expect("unresolved type");
+    assert_eq!(all_types.full_name(), "protobuf_unittest.TestAllTypes");
+    assert!(!std::ptr::eq(all_types, TestAllTypes::descriptor()));
+
+    assert!(resolver.resolve("type.googleapis.com/protobuf_unittest.Missing").is_none());
+    assert!(resolver.resolve("protobuf_unittest.TestAllTypes").is_none());
+
+    let mut missing = Any::new();
+    *missing.type_url_mut() = "type.googleapis.com/protobuf_unittest.Missing".to_string();
+    assert!(missing.unpack_with(&resolver).is_none());
+    assert!(Any::pack(&timestamp(5, 0))?.unpack_with(&PoolResolver::new()).is_none());
+    Ok(())
+}
+
+#[test]
+fn struct_value_conversions() {
+    assert_eq!(Value::from(true).kind(), &Kind::BoolValue(true));
+    assert_eq!(Value::from(2).kind(), &Kind::NumberValue(2.0));
+    assert_eq!(Value::from(0.5f32).kind(), &Kind::NumberValue(0.5));
+    assert_eq!(Value::from("text").kind(), &Kind::StringValue("text".to_string()));
+    assert_eq!(Value::from(None::<bool>), Value::from(NullValue::NullValue));
+    assert_eq!(Value::from(Some("text")), Value::from("text".to_string()));
+
+    let list = Value::from(vec![1, 2, 3]);
+    assert_eq!(list.as_list().map(|list| list.values().len()), Some(3));
+    assert_eq!(list.as_list().unwrap()[2].as_f64(), Some(3.0));
+
+    let mut map = HashMap::new();
+    map.insert("a", vec!["x"]);
+    let from_hash_map = Value::from(map);
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), vec!["x"]);
+    assert_eq!(from_hash_map, Value::from(map));
+    assert_eq!(from_hash_map["a"], Value::from(ListValue::from(vec!["x"])));
+}
+
+#[test]
+fn struct_value_accessors() {
+    let mut value = Value::from(struct_value! {
+        "flag" => false,
+        "items" => vec![Value::from("a"), Value::null()],
+    });
+
+    assert!(value.as_bool().is_none());
+    assert!(value.as_list().is_none());
+    assert_eq!(value["flag"].as_bool(), Some(false));
+    assert_eq!(value["flag"].as_str(), None);
+    assert!(value["items"].as_list().unwrap()[1].is_null());
+    assert!(value.get("missing").is_none());
+    assert!(value["flag"].get("nested").is_none());
+
+    *value.get_mut("flag").unwrap() = Value::from(true);
+    value.get_mut("items").and_then(Value::as_list_mut).unwrap().push(4);
+    value.as_struct_mut().unwrap().insert("name", "value");
+    let list = value["items"].as_list().unwrap();
+    assert_eq!(list[2].as_f64(), Some(4.0));
+    assert_eq!(value["flag"].as_bool(), Some(true));
+    assert_eq!(value["name"].as_str(), Some("value"));
+
+    let removed = value.as_struct_mut().unwrap().remove("name");
+    assert_eq!(removed, Some(Value::from("value")));
+    assert!(value.get("name").is_none());
+}
+
+#[test]
+#[should_panic]
+fn struct_index_missing_field() {
+    let value = struct_value! { "a" => 1 };
+    let _ = &value["b"];
+}
+
+#[test]
+fn struct_value_macro() -> shared::Result {
+    assert_eq!(struct_value! {}, Struct::new());
+
+    let value = struct_value! {
+        "name" => "protrust",
+        String::from("count") => 2,
+        "nested" => struct_value! { "empty" => ListValue::new() },
+    };
+    let mut expected = Struct::new();
+    expected.fields_mut().insert("name".to_string(), Value::from("protrust"));
+    expected.fields_mut().insert("count".to_string(), Value::from(2.0));
+    let mut nested = Struct::new();
+    nested.fields_mut().insert("empty".to_string(), Value::from(ListValue::new()));
+    expected.fields_mut().insert("nested".to_string(), Value::from(nested));
+    assert_eq!(value, expected);
+
+    assert_eq!(Struct::read_new_from_slice(&value.write_to_vec()?)?, value);
+    Ok(())
+}